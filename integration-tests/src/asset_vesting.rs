@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+use frame_support::{assert_noop, assert_ok};
+use hydradx_runtime::{AssetVesting, Tokens};
+use orml_traits::MultiCurrency;
+use pallet_asset_vesting::{VestingKind, VestingSchedule, VESTING_LOCK_ID};
+use sp_runtime::DispatchError::BadOrigin;
+use xcm_emulator::TestExt;
+
+const VESTED_AMOUNT: Balance = 1_000 * UNITS;
+
+fn fund_vesting_account() {
+	assert_ok!(Currencies::deposit(DAI, &vesting_account(), 10 * VESTED_AMOUNT));
+}
+
+fn cliff() -> VestingKind<BlockNumber> {
+	VestingKind::Cliff { duration: 1_000_000 }
+}
+
+#[test]
+fn vested_transfer_should_lock_non_native_asset_when_sent_from_root() {
+	Hydra::execute_with(|| {
+		// Arrange
+		fund_vesting_account();
+		let bob_balance_before = Currencies::free_balance(DAI, &AccountId::from(BOB));
+
+		// Act
+		assert_ok!(AssetVesting::vested_transfer(
+			RawOrigin::Root.into(),
+			BOB.into(),
+			DAI,
+			0,
+			cliff(),
+			VESTED_AMOUNT,
+		));
+
+		// Assert
+		assert_eq!(
+			Currencies::free_balance(DAI, &AccountId::from(BOB)),
+			bob_balance_before + VESTED_AMOUNT
+		);
+		assert_eq!(
+			Tokens::locks(AccountId::from(BOB), DAI)
+				.iter()
+				.find(|lock| lock.id == VESTING_LOCK_ID)
+				.map(|lock| lock.amount),
+			Some(VESTED_AMOUNT)
+		);
+		assert_eq!(
+			AssetVesting::vesting_schedules(AccountId::from(BOB), DAI).into_inner(),
+			vec![VestingSchedule {
+				grantor: vesting_account(),
+				start: 0,
+				kind: cliff(),
+				total: VESTED_AMOUNT,
+			}]
+		);
+		assert_eq!(AssetVesting::locked_balance(&AccountId::from(BOB), DAI), VESTED_AMOUNT);
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail_when_signed_by_any_account() {
+	Hydra::execute_with(|| {
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RawOrigin::Signed(ALICE.into()).into(),
+				BOB.into(),
+				DAI,
+				0,
+				cliff(),
+				VESTED_AMOUNT,
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn cancel_schedule_should_return_unvested_amount_to_vesting_account() {
+	Hydra::execute_with(|| {
+		// Arrange
+		fund_vesting_account();
+		assert_ok!(AssetVesting::vested_transfer(
+			RawOrigin::Root.into(),
+			BOB.into(),
+			DAI,
+			0,
+			cliff(),
+			VESTED_AMOUNT,
+		));
+		let vesting_account_balance_before = Currencies::free_balance(DAI, &vesting_account());
+
+		// Act
+		assert_ok!(AssetVesting::cancel_schedule(
+			RawOrigin::Root.into(),
+			BOB.into(),
+			DAI,
+			0
+		));
+
+		// Assert
+		assert_eq!(
+			Currencies::free_balance(DAI, &vesting_account()),
+			vesting_account_balance_before + VESTED_AMOUNT
+		);
+		assert!(AssetVesting::vesting_schedules(AccountId::from(BOB), DAI).is_empty());
+		assert!(Tokens::locks(AccountId::from(BOB), DAI).is_empty());
+	});
+}
@@ -360,3 +360,98 @@ fn router_set_route_should_be_filtered_out() {
 		assert!(!hydradx_runtime::CallFilter::contains(&call));
 	});
 }
+
+#[test]
+fn asset_scoped_pause_should_filter_only_trades_of_paused_asset() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		assert_ok!(hydradx_runtime::TransactionPause::pause(
+			hydradx_runtime::RuntimeOrigin::root(),
+			pallet_transaction_pause::PauseTarget::AssetTransaction(
+				b"Omnipool".to_vec().try_into().unwrap(),
+				b"sell".to_vec().try_into().unwrap(),
+				DOT,
+			),
+			Some(10),
+		));
+
+		let paused_call = hydradx_runtime::RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+			asset_in: DOT,
+			asset_out: HDX,
+			amount: UNITS,
+			min_buy_amount: 0,
+		});
+		let successful_call = hydradx_runtime::RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+			asset_in: DAI,
+			asset_out: HDX,
+			amount: UNITS,
+			min_buy_amount: 0,
+		});
+
+		// Act & Assert
+		assert!(!hydradx_runtime::CallFilter::contains(&paused_call));
+		assert!(hydradx_runtime::CallFilter::contains(&successful_call));
+
+		// pause expires
+		hydradx_run_to_block(hydradx_runtime::System::block_number() + 10);
+		assert!(hydradx_runtime::CallFilter::contains(&paused_call));
+	});
+}
+
+#[test]
+fn asset_scoped_pause_should_filter_router_trades_routed_through_paused_asset() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// Arrange
+		assert_ok!(hydradx_runtime::TransactionPause::pause(
+			hydradx_runtime::RuntimeOrigin::root(),
+			pallet_transaction_pause::PauseTarget::AssetTransaction(
+				b"Router".to_vec().try_into().unwrap(),
+				b"sell".to_vec().try_into().unwrap(),
+				LRNA,
+			),
+			None,
+		));
+
+		let paused_call = hydradx_runtime::RuntimeCall::Router(pallet_route_executor::Call::sell {
+			asset_in: DOT,
+			asset_out: HDX,
+			amount_in: UNITS,
+			min_amount_out: 0,
+			route: vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: DOT,
+					asset_out: LRNA,
+				},
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: LRNA,
+					asset_out: HDX,
+				},
+			]
+			.try_into()
+			.unwrap(),
+		});
+		let successful_call = hydradx_runtime::RuntimeCall::Router(pallet_route_executor::Call::sell {
+			asset_in: DOT,
+			asset_out: HDX,
+			amount_in: UNITS,
+			min_amount_out: 0,
+			route: vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DOT,
+				asset_out: HDX,
+			}]
+			.try_into()
+			.unwrap(),
+		});
+
+		// Act & Assert
+		assert!(!hydradx_runtime::CallFilter::contains(&paused_call));
+		assert!(hydradx_runtime::CallFilter::contains(&successful_call));
+	});
+}
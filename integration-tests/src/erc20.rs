@@ -309,6 +309,74 @@ fn native_evm_account_should_have_free_balance() {
 	});
 }
 
+#[test]
+fn erc20_balance_of_derived_evm_address_should_not_be_accessible_when_account_is_bound_with_signature() {
+	use crate::utils::accounts::{alith_evm_address, alith_secret_key};
+	use libsecp256k1::{sign, Message, SecretKey};
+	use sp_core::{sr25519, Pair};
+
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		// Arrange
+		let contract = deploy_token_contract();
+		let asset = bind_erc20(contract);
+
+		let account_pair = sr25519::Pair::from_seed(&[5; 32]);
+		let account: AccountId = account_pair.public().into();
+		let derived_evm_address = EVMAccounts::evm_address(&account);
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			account.clone(),
+			HDX,
+			1_000 * UNITS as i128,
+		));
+		assert_ok!(<Erc20Currency<Runtime> as ERC20>::transfer(
+			CallContext {
+				contract,
+				sender: deployer(),
+				origin: deployer()
+			},
+			derived_evm_address,
+			100
+		));
+		assert_eq!(Currencies::free_balance(asset, &account), 100);
+
+		let deadline = hydradx_runtime::System::block_number() + 10;
+		let (rs, v) = sign(
+			&Message::parse(&EVMAccounts::binding_message(&account, 0, deadline)),
+			&SecretKey::parse(&alith_secret_key()).unwrap(),
+		);
+		let mut signature = [0u8; 65];
+		signature[..64].copy_from_slice(&rs.serialize());
+		signature[64] = v.serialize();
+
+		// Act
+		assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+			RuntimeOrigin::none(),
+			account.clone(),
+			alith_evm_address(),
+			deadline,
+			signature,
+			account_pair
+				.sign(&EVMAccounts::account_binding_message(alith_evm_address(), 0, deadline))
+				.into(),
+		));
+
+		// Assert
+		assert_eq!(EVMAccounts::evm_address(&account), alith_evm_address());
+		assert_eq!(Currencies::free_balance(asset, &account), 0);
+		assert_eq!(
+			Erc20Currency::<Runtime>::balance_of(CallContext::new_view(contract), derived_evm_address),
+			100
+		);
+
+		assert_ok!(EVMAccounts::unbind_evm_address(RuntimeOrigin::signed(account.clone())));
+
+		assert_eq!(EVMAccounts::evm_address(&account), derived_evm_address);
+		assert_eq!(Currencies::free_balance(asset, &account), 100);
+	});
+}
+
 #[test]
 fn account_should_receive_tokens() {
 	TestNet::reset();
@@ -14,6 +14,7 @@ use hydradx_runtime::evm::precompiles::DISPATCH_ADDR;
 use hydradx_runtime::evm::EvmAddress;
 use hydradx_runtime::evm::ExtendedAddressMapping;
 use hydradx_runtime::evm::Function;
+use hydradx_runtime::evm::WrapRunnerError;
 use hydradx_runtime::XYK;
 use hydradx_runtime::{
 	evm::precompiles::{
@@ -94,6 +95,67 @@ mod account_conversion {
 		});
 	}
 
+	#[test]
+	fn eth_address_should_convert_to_full_address_when_bound_with_signature() {
+		use crate::utils::accounts::{alith_evm_address, alith_secret_key};
+		use frame_support::pallet_prelude::{TransactionSource, ValidateUnsigned};
+		use frame_support::traits::Get;
+		use libsecp256k1::{sign, Message, SecretKey};
+		use sp_core::{sr25519, Pair};
+
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			// Arrange
+			let account_pair = sr25519::Pair::from_seed(&[5; 32]);
+			let substrate_address: AccountId = account_pair.public().into();
+			let evm_address = alith_evm_address();
+			let deadline = hydradx_runtime::System::block_number() + 10;
+			Balances::set_balance(&substrate_address, 1000 * UNITS);
+
+			let message = EVMAccounts::binding_message(&substrate_address, 0, deadline);
+			let (rs, v) = sign(
+				&Message::parse(&message),
+				&SecretKey::parse(&alith_secret_key()).unwrap(),
+			);
+			let mut signature = [0u8; 65];
+			signature[..64].copy_from_slice(&rs.serialize());
+			signature[64] = v.serialize();
+
+			let account_signature: sp_runtime::MultiSignature = account_pair
+				.sign(&EVMAccounts::account_binding_message(evm_address, 0, deadline))
+				.into();
+
+			let call = pallet_evm_accounts::Call::<hydradx_runtime::Runtime>::bind_evm_address_with_signature {
+				account: substrate_address.clone(),
+				evm_address,
+				deadline,
+				signature,
+				account_signature: account_signature.clone(),
+			};
+			assert!(EVMAccounts::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+			// Act
+			assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+				hydradx_runtime::RuntimeOrigin::none(),
+				substrate_address.clone(),
+				evm_address,
+				deadline,
+				signature,
+				account_signature,
+			));
+
+			// Assert
+			assert_eq!(ExtendedAddressMapping::into_account_id(evm_address), substrate_address);
+			assert_eq!(EVMAccounts::account_id(evm_address), substrate_address);
+			assert_eq!(EVMAccounts::evm_address(&substrate_address), evm_address);
+			assert_eq!(
+				Balances::reserved_balance(&substrate_address),
+				hydradx_runtime::evm::EvmAccountsBindingDeposit::get()
+			);
+		});
+	}
+
 	#[test]
 	fn bind_address_should_fail_when_nonce_is_not_zero() {
 		use pallet_evm_accounts::EvmNonceProvider;
@@ -400,7 +462,7 @@ mod standard_precompiles {
 	fn evm_runner_call(
 		to: EvmAddress,
 		data: Vec<u8>,
-	) -> Result<CallInfo, RunnerError<pallet_evm::Error<hydradx_runtime::Runtime>>> {
+	) -> Result<CallInfo, RunnerError<WrapRunnerError<pallet_evm::Error<hydradx_runtime::Runtime>>>> {
 		assert_ok!(Tokens::set_balance(
 			RawOrigin::Root.into(),
 			evm_account(),
@@ -2075,6 +2137,69 @@ mod contract_deployment {
 			));
 		});
 	}
+
+	#[test]
+	fn create_contract_should_be_accepted_if_code_hash_is_approved() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			let init = vec![0, 1, 1, 0];
+			assert_ok!(EVMAccounts::approve_contract_code(
+				hydradx_runtime::RuntimeOrigin::root(),
+				H256::from(sp_io::hashing::keccak_256(&init)),
+				b"audit".to_vec().try_into().unwrap(),
+			));
+
+			assert_ok!(hydradx_runtime::Runtime::create(
+				evm_address(),
+				init,
+				U256::zero(),
+				U256::from(100000u64),
+				None,
+				None,
+				None,
+				false,
+				None,
+			));
+		});
+	}
+
+	#[test]
+	fn create_contract_should_be_rejected_by_runner_if_code_hash_is_not_approved() {
+		use pallet_evm::Runner;
+
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			let init = vec![0, 1, 1, 0];
+			assert_ok!(EVMAccounts::approve_contract_code(
+				hydradx_runtime::RuntimeOrigin::root(),
+				H256::from(sp_io::hashing::keccak_256(&[1, 0, 0, 1])),
+				b"audit".to_vec().try_into().unwrap(),
+			));
+
+			let result = <hydradx_runtime::Runtime as pallet_evm::Config>::Runner::create(
+				evm_address(),
+				init,
+				U256::zero(),
+				100_000,
+				None,
+				None,
+				None,
+				vec![],
+				false,
+				false,
+				None,
+				None,
+				<hydradx_runtime::Runtime as pallet_evm::Config>::config(),
+			);
+
+			assert_eq!(
+				result.map(|_| ()).map_err(|e| DispatchError::from(e.error)),
+				Err(pallet_evm_accounts::Error::<hydradx_runtime::Runtime>::AddressNotWhitelisted.into())
+			);
+		});
+	}
 }
 
 #[test]
@@ -1409,6 +1409,84 @@ fn banned_asset_should_not_be_transferable_to_existing_account() {
 	});
 }
 
+#[test]
+fn frozen_account_should_not_transfer_asset() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		let update_origin = hydradx_runtime::OriginCaller::Origins(Origin::GeneralAdmin);
+		//Arrange
+		let sht1: AssetId = register_external_asset(0_u128);
+		assert_ok!(Tokens::set_balance(
+			RawOrigin::Root.into(),
+			BOB.into(),
+			sht1,
+			100_000_000 * UNITS,
+			0,
+		));
+		assert_ok!(Tokens::set_balance(
+			RawOrigin::Root.into(),
+			ALICE.into(),
+			sht1,
+			100_000_000 * UNITS,
+			0,
+		));
+
+		assert_ok!(Registry::freeze_account(update_origin.into(), sht1, BOB.into()));
+
+		//Act & assert
+		assert_noop!(
+			Tokens::transfer(hydra_origin::signed(BOB.into()), ALICE.into(), sht1, 1_000_000 * UNITS),
+			pallet_asset_registry::Error::<hydradx_runtime::Runtime>::AccountFrozen
+		);
+		assert_noop!(
+			Currencies::transfer(hydra_origin::signed(BOB.into()), ALICE.into(), sht1, 1_000_000 * UNITS),
+			pallet_currencies::Error::<hydradx_runtime::Runtime>::AccountFrozen
+		);
+		assert_noop!(
+			Currencies::withdraw(sht1, &BOB.into(), 1_000_000 * UNITS),
+			pallet_currencies::Error::<hydradx_runtime::Runtime>::AccountFrozen
+		);
+
+		//asset keeps working for other accounts and frozen account can receive it
+		assert_ok!(Currencies::transfer(
+			hydra_origin::signed(ALICE.into()),
+			BOB.into(),
+			sht1,
+			1_000_000 * UNITS
+		));
+	});
+}
+
+#[test]
+fn frozen_account_should_not_sell_asset_in_omnipool() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		let update_origin = hydradx_runtime::OriginCaller::Origins(Origin::GeneralAdmin);
+		//Arrange
+		crate::dca::init_omnipool_with_oracle_for_block_10();
+		assert_ok!(Registry::freeze_account(
+			update_origin.clone().into(),
+			DOT,
+			ALICE.into()
+		));
+
+		//Act & assert
+		assert_noop!(
+			Omnipool::sell(hydra_origin::signed(ALICE.into()), DOT, HDX, 10 * UNITS, u128::MIN),
+			pallet_currencies::Error::<hydradx_runtime::Runtime>::AccountFrozen
+		);
+
+		assert_ok!(Registry::unfreeze_account(update_origin.into(), DOT, ALICE.into()));
+		assert_ok!(Omnipool::sell(
+			hydra_origin::signed(ALICE.into()),
+			DOT,
+			HDX,
+			10 * UNITS,
+			u128::MIN
+		));
+	});
+}
+
 #[test]
 fn ed_should_be_paid_in_insufficient_asset_through_dot() {
 	TestNet::reset();
@@ -3,6 +3,7 @@
 #![cfg(not(feature = "runtime-benchmarks"))]
 mod aave_router;
 mod asset_registry;
+mod asset_vesting;
 mod bonds;
 mod call_filter;
 mod circuit_breaker;
@@ -776,6 +776,7 @@ pub fn hydradx_run_to_next_block() {
 	hydradx_runtime::Ethereum::on_initialize(b + 1);
 	hydradx_runtime::AuraExt::on_initialize(b + 1);
 	hydradx_runtime::MultiTransactionPayment::on_initialize(b + 1);
+	hydradx_runtime::TransactionPause::on_initialize(b + 1);
 	hydradx_runtime::CircuitBreaker::on_initialize(b + 1);
 	hydradx_runtime::DynamicEvmFee::on_initialize(b + 1);
 	hydradx_runtime::DCA::on_initialize(b + 1);
@@ -1,6 +1,7 @@
 use codec::{Decode, Encode};
 use cumulus_primitives_core::BlockT;
 use ethabi::ethereum_types::U256;
+use fc_rpc::StorageOverride;
 use fp_rpc::EthereumRuntimeRPCApi;
 use fp_self_contained::SelfContainedCall;
 use frame_support::{BoundedVec, __private::sp_tracing::tracing};
@@ -24,12 +25,20 @@ use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::{RuntimeDebug, H160};
 use sp_offchain::OffchainWorkerApi;
-use sp_runtime::{traits::Header, transaction_validity::TransactionSource};
+use sp_runtime::{
+	traits::{Header, UniqueSaturatedInto},
+	transaction_validity::TransactionSource,
+};
+use std::io::Write;
 use std::ops::Deref;
+use std::path::{Path, PathBuf};
 use std::{cmp::Ordering, marker::PhantomData, sync::Arc};
 use threadpool::ThreadPool;
 use xcm_runtime_apis::dry_run::{CallDryRunEffects, DryRunApi, Error as XcmDryRunApiError};
 
+mod borrower_discovery;
+use borrower_discovery::BorrowersStore;
+
 const LOG_TARGET: &str = "liquidation-worker";
 
 // Address of the pool address provider contract.
@@ -58,6 +67,15 @@ const TARGET_HF: u128 = 1_001_000_000_000_000_000u128; // 1.001
 // Failed liquidations are suspended for this number of blocks before we try to execute them again.
 const WAIT_PERIOD: BlockNumber = 10;
 
+// Default path of the report file written in dry-run mode.
+const DRY_RUN_REPORT_PATH: &str = "liquidation-worker-dry-run.jsonl";
+
+// Default path of the file with borrowers discovered from the EVM logs.
+const BORROWERS_STORE_PATH: &str = "liquidation-worker-borrowers.json";
+
+// Discovered borrowers are persisted after processing this number of blocks during the initial scan.
+const BORROWERS_STORE_SAVE_PERIOD: BlockNumber = 10_000;
+
 type HttpClient = Arc<Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>, Body>>;
 
 /// The configuration for the liquidation worker.
@@ -87,6 +105,46 @@ pub struct LiquidationWorkerConfig {
 	/// Target health factor
 	#[clap(long, default_value_t = TARGET_HF)]
 	pub target_hf: u128,
+
+	/// Run the liquidation worker in dry-run mode.
+	/// Liquidation options are evaluated on every block and written to the report file instead of being submitted.
+	#[clap(long, default_value = "false")]
+	pub liquidation_worker_dry_run: bool,
+
+	/// Path of the JSON-lines report file written in dry-run mode.
+	#[clap(long, default_value = DRY_RUN_REPORT_PATH)]
+	pub liquidation_worker_report_path: PathBuf,
+
+	/// Discover borrowers from money market `Borrow` and `Supply` EVM logs stored by the node
+	/// instead of fetching them from the external indexer.
+	#[clap(long, default_value = "false")]
+	pub liquidation_worker_offline_discovery: bool,
+
+	/// Path of the file the discovered borrowers are persisted to.
+	#[clap(long, default_value = BORROWERS_STORE_PATH)]
+	pub liquidation_worker_borrowers_store: PathBuf,
+
+	/// Block the borrower discovery starts from if no borrowers have been persisted yet.
+	#[clap(long, default_value_t = 0)]
+	pub liquidation_worker_discovery_start_block: BlockNumber,
+}
+
+/// One line of the dry-run report.
+/// Describes the liquidation the worker would have submitted for the borrower.
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunReportEntry {
+	pub block_number: BlockNumber,
+	pub borrower: EvmAddress,
+	pub health_factor: U256,
+	pub health_factor_after_liquidation: U256,
+	pub collateral_asset: EvmAddress,
+	pub collateral_asset_id: Option<AssetId>,
+	pub debt_asset: EvmAddress,
+	pub debt_asset_id: Option<AssetId>,
+	pub debt_to_liquidate: U256,
+	/// Value of the seized collateral minus the value of the repaid debt, in the base currency of the money market.
+	pub expected_profit: U256,
 }
 
 pub struct ApiProvider<C>(C);
@@ -153,12 +211,14 @@ where
 	/// Starting point for the liquidation worker.
 	/// Executes `on_block_imported` on every block.
 	/// Initial list of borrowers is fetched and sorted by the HF.
+	/// If the offline discovery is enabled, the list is reconstructed from the EVM logs instead.
 	/// `tx_waitlist` is initialized here because it's persistent between liquidation runs.
 	pub async fn run(
 		client: Arc<C>,
 		config: LiquidationWorkerConfig,
 		transaction_pool: Arc<P>,
 		spawner: SpawnTaskHandle,
+		overrides: Arc<dyn StorageOverride<B>>,
 	) {
 		// liquidation calculations are performed in a separate thread.
 		let thread_pool = Arc::new(Mutex::new(ThreadPool::with_name(
@@ -166,23 +226,74 @@ where
 			num_cpus::get(),
 		)));
 
-		// initialize the client once and reuse it.
-		let https = hyper_rustls::HttpsConnectorBuilder::new()
-			.with_native_roots()
-			.https_or_http()
-			.enable_http1()
-			.enable_http2()
-			.build();
-		let http_client: HttpClient = Arc::new(Client::builder().build(https));
-
-		// Fetch and sort the data with borrowers info.
-		let Some(borrowers_data) = Self::fetch_borrowers_data(http_client.clone()).await else {
-			tracing::error!(target: LOG_TARGET, "fetch_borrowers_data failed");
-			return;
+		// Borrowers discovered from the EVM logs. Updated on every finalized block.
+		let mut borrowers_store = None;
+
+		let sorted_borrowers_data = if config.liquidation_worker_offline_discovery {
+			let Some((store, pool_contract)) =
+				Self::discover_borrowers(client.clone(), overrides.clone(), &config).await
+			else {
+				tracing::error!(target: LOG_TARGET, "discover_borrowers failed");
+				return;
+			};
+			// HF of discovered borrowers is not known yet. It is set to 0 and calculated later.
+			let borrowers = store.borrowers.iter().map(|b| (*b, U256::zero())).collect::<Vec<_>>();
+			borrowers_store = Some((Arc::new(std::sync::Mutex::from(store)), pool_contract));
+			borrowers
+		} else {
+			// initialize the client once and reuse it.
+			let https = hyper_rustls::HttpsConnectorBuilder::new()
+				.with_native_roots()
+				.https_or_http()
+				.enable_http1()
+				.enable_http2()
+				.build();
+			let http_client: HttpClient = Arc::new(Client::builder().build(https));
+
+			// Fetch and sort the data with borrowers info.
+			let Some(borrowers_data) = Self::fetch_borrowers_data(http_client.clone()).await else {
+				tracing::error!(target: LOG_TARGET, "fetch_borrowers_data failed");
+				return;
+			};
+			Self::process_borrowers_data(borrowers_data)
 		};
-		let sorted_borrowers_data = Self::process_borrowers_data(borrowers_data);
 		let borrowers = Arc::new(std::sync::Mutex::from(sorted_borrowers_data));
 
+		// Discovered borrowers are updated from finalized blocks only, so forks can't cause canonical blocks to be skipped.
+		if let Some((store, pool_contract)) = borrowers_store {
+			let client_c = client.clone();
+			let borrowers_c = borrowers.clone();
+			let store_path = config.liquidation_worker_borrowers_store.clone();
+			spawner.spawn(
+				"liquidation-worker-borrowers-discovery",
+				Some("liquidation-worker"),
+				client.finality_notification_stream().for_each(move |n| {
+					let client = client_c.clone();
+					let overrides = overrides.clone();
+					let store = store.clone();
+					let borrowers = borrowers_c.clone();
+					let store_path = store_path.clone();
+					let (hash, header) = (n.hash, n.header);
+					async move {
+						// Processing of the logs and the store rewrite are blocking operations.
+						let _ = tokio::task::spawn_blocking(move || {
+							Self::process_discovered_borrowers(
+								client,
+								hash,
+								&header,
+								overrides,
+								store,
+								pool_contract,
+								borrowers,
+								&store_path,
+							)
+						})
+						.await;
+					}
+				}),
+			);
+		}
+
 		// We store the last best block. We use it to stop older tasks.
 		let best_block = Arc::new(std::sync::Mutex::from(B::Hash::default()));
 
@@ -268,6 +379,23 @@ where
 				return
 			};
 
+			// In dry-run mode, liquidation options of all borrowers are evaluated once per block
+			// and nothing is submitted, so we don't need to listen to new transactions.
+			if config.liquidation_worker_dry_run {
+				let client = client.clone();
+				let header = header.clone();
+				let borrowers = borrowers.clone();
+				let config = config.clone();
+				Self::spawn_worker(thread_pool.clone(), move || {
+					let now = std::time::Instant::now();
+
+					Self::dry_run_liquidations(client, header, borrowers, config);
+
+					tracing::info!(target: LOG_TARGET, "dry_run_liquidations execution time: {:?}", now.elapsed().as_millis());
+				});
+				return
+			}
+
 			let current_block_number = *header.number();
 
 			// `tx_waitlist` maintenance.
@@ -639,6 +767,277 @@ where
 		Ok(())
 	}
 
+	/// Evaluates the best liquidation option of every borrower with the current prices and writes
+	/// the liquidatable ones to the dry-run report. No transaction is submitted.
+	fn dry_run_liquidations(
+		client: Arc<C>,
+		header: B::Header,
+		borrowers: Arc<std::sync::Mutex<Vec<(H160, U256)>>>,
+		config: LiquidationWorkerConfig,
+	) {
+		let runtime_api = client.runtime_api();
+		let hash = header.hash();
+		let runtime_api_caller = config.runtime_api_caller.unwrap_or(RUNTIME_API_CALLER);
+
+		let block_number: BlockNumber = (*header.number()).unique_saturated_into();
+
+		let Some(current_evm_timestamp) = ApiProvider::<&C::Api>(runtime_api.deref()).current_timestamp(hash) else {
+			tracing::info!(target: LOG_TARGET, "fetch_current_evm_block_timestamp failed");
+			return;
+		};
+
+		let Ok(mut money_market_data) =
+			MoneyMarketData::<B, ApiProvider<&C::Api>, OriginCaller, RuntimeCall, RuntimeEvent>::new(
+				ApiProvider::<&C::Api>(runtime_api.deref()),
+				hash,
+				config.pap_contract.unwrap_or(PAP_CONTRACT),
+				runtime_api_caller,
+			)
+		else {
+			tracing::info!(target: LOG_TARGET, "MoneyMarketData initialization failed");
+			return;
+		};
+
+		// Prices are not updated in dry-run mode. Current price of any reserve is used as the "price update".
+		let Some(current_price) = money_market_data
+			.reserves()
+			.first()
+			.map(|reserve| (reserve.asset_address(), reserve.price()))
+		else {
+			return;
+		};
+
+		let target_hf: U256 = config.target_hf.into();
+		let hf_one = U256::from(10u128.pow(18));
+		let mut report = Vec::new();
+
+		{
+			let Ok(mut borrowers_data) = borrowers.lock() else {
+				tracing::debug!(target: LOG_TARGET, "borrowers_data mutex is poisoned");
+				// return if the mutex is poisoned
+				return;
+			};
+
+			for borrower in borrowers_data.iter_mut() {
+				let Ok(user_data) = UserData::new(
+					ApiProvider::<&C::Api>(runtime_api.deref()),
+					hash,
+					&money_market_data,
+					borrower.0,
+					current_evm_timestamp,
+					runtime_api_caller,
+				) else {
+					continue;
+				};
+
+				let Ok(current_hf) = user_data.health_factor(&money_market_data) else {
+					continue;
+				};
+				// update user's HF
+				borrower.1 = current_hf;
+
+				if current_hf > hf_one {
+					continue;
+				}
+
+				let Ok(Some(liquidation_option)) =
+					money_market_data.get_best_liquidation_option(&user_data, target_hf, current_price)
+				else {
+					continue;
+				};
+
+				let expected_profit = money_market_data
+					.calculate_debt_to_liquidate(
+						&user_data,
+						target_hf,
+						liquidation_option.collateral_asset,
+						liquidation_option.debt_asset,
+					)
+					.map(|amounts| {
+						amounts
+							.collateral_in_base_currency
+							.saturating_sub(amounts.debt_in_base_currency)
+					})
+					.unwrap_or_default();
+
+				let api_provider = ApiProvider::<&C::Api>(runtime_api.deref());
+				report.push(DryRunReportEntry {
+					block_number,
+					borrower: borrower.0,
+					health_factor: current_hf,
+					health_factor_after_liquidation: liquidation_option.health_factor,
+					collateral_asset: liquidation_option.collateral_asset,
+					collateral_asset_id: api_provider
+						.address_to_asset(hash, liquidation_option.collateral_asset)
+						.ok()
+						.flatten(),
+					debt_asset: liquidation_option.debt_asset,
+					debt_asset_id: api_provider
+						.address_to_asset(hash, liquidation_option.debt_asset)
+						.ok()
+						.flatten(),
+					debt_to_liquidate: liquidation_option.debt_to_liquidate,
+					expected_profit,
+				});
+			}
+		}
+
+		if let Err(e) = write_dry_run_report(&config.liquidation_worker_report_path, &report) {
+			tracing::error!(target: LOG_TARGET, "failed to write dry-run report: {:?}", e);
+		}
+	}
+
+	/// Reconstructs the set of borrowers from the EVM logs of already finalized blocks.
+	/// Continues from the last block persisted in the borrowers store.
+	/// Returns the store and the address of the money market pool contract.
+	async fn discover_borrowers(
+		client: Arc<C>,
+		overrides: Arc<dyn StorageOverride<B>>,
+		config: &LiquidationWorkerConfig,
+	) -> Option<(BorrowersStore, EvmAddress)> {
+		let store_path = config.liquidation_worker_borrowers_store.clone();
+		let start_block = config.liquidation_worker_discovery_start_block;
+		let pap_contract = config.pap_contract.unwrap_or(PAP_CONTRACT);
+		let runtime_api_caller = config.runtime_api_caller.unwrap_or(RUNTIME_API_CALLER);
+
+		// Scanning of the blocks can take a long time, don't block the async runtime.
+		tokio::task::spawn_blocking(move || {
+			let mut store = BorrowersStore::load(&store_path)
+				.map_err(|e| tracing::error!(target: LOG_TARGET, "failed to load borrowers store: {:?}", e))
+				.ok()?;
+
+			let best_hash = client.info().best_hash;
+			let runtime_api = client.runtime_api();
+			let pool_contract =
+				MoneyMarketData::<B, ApiProvider<&C::Api>, OriginCaller, RuntimeCall, RuntimeEvent>::fetch_pool(
+					&ApiProvider::<&C::Api>(runtime_api.deref()),
+					best_hash,
+					pap_contract,
+					runtime_api_caller,
+				)
+				.map_err(|e| tracing::error!(target: LOG_TARGET, "fetch_pool failed: {:?}", e))
+				.ok()?;
+
+			// Only finalized blocks are scanned. Newer blocks are processed when they are finalized.
+			let finalized_number: BlockNumber = client.info().finalized_number.unique_saturated_into();
+			let mut block_number = store.next_block(start_block);
+			tracing::info!(target: LOG_TARGET, "discovering borrowers from block {:?} to {:?}", block_number, finalized_number);
+
+			while block_number <= finalized_number {
+				// Missing receipts can't be treated as an empty block, the borrowers of the block would be lost.
+				// The scan stops without advancing the store, so it can be resumed from the same block.
+				let Some(receipts) = Self::block_receipts(&client, &overrides, block_number) else {
+					if let Err(e) = store.save(&store_path) {
+						tracing::error!(target: LOG_TARGET, "failed to save borrowers store: {:?}", e);
+					}
+					tracing::error!(target: LOG_TARGET, "borrowers discovery stopped at block {:?}, the discovery start block has to be within the state retained by the node", block_number);
+					return None;
+				};
+				store.process_block(block_number, pool_contract, &receipts);
+
+				if block_number % BORROWERS_STORE_SAVE_PERIOD == 0 {
+					if let Err(e) = store.save(&store_path) {
+						tracing::error!(target: LOG_TARGET, "failed to save borrowers store: {:?}", e);
+					}
+				}
+
+				block_number = block_number.saturating_add(1);
+			}
+
+			if let Err(e) = store.save(&store_path) {
+				tracing::error!(target: LOG_TARGET, "failed to save borrowers store: {:?}", e);
+			}
+			tracing::info!(target: LOG_TARGET, "discovered {:?} borrowers", store.borrowers.len());
+
+			Some((store, pool_contract))
+		})
+		.await
+		.ok()?
+	}
+
+	/// Returns the EVM receipts of the canonical block.
+	/// Returns `None` if the block or its receipts are not available, e.g. because the state has been pruned.
+	fn block_receipts(
+		client: &Arc<C>,
+		overrides: &Arc<dyn StorageOverride<B>>,
+		block_number: BlockNumber,
+	) -> Option<Vec<ethereum::ReceiptV3>> {
+		let hash = match client.hash(block_number.into()) {
+			Ok(Some(hash)) => hash,
+			Ok(None) => {
+				tracing::error!(target: LOG_TARGET, "hash of block {:?} not found", block_number);
+				return None;
+			}
+			Err(e) => {
+				tracing::error!(target: LOG_TARGET, "failed to get hash of block {:?}: {:?}", block_number, e);
+				return None;
+			}
+		};
+
+		let receipts = overrides.current_receipts(hash);
+		if receipts.is_none() {
+			tracing::error!(target: LOG_TARGET, "receipts of block {:?} are not available", block_number);
+		}
+		receipts
+	}
+
+	/// Adds borrowers from the EVM logs of the finalized block to the borrowers store and to the list of borrowers.
+	/// Blocks finalized since the last processed block are processed first. The finality notification
+	/// is not emitted for each finalized block, and their canonical hashes are looked up by number.
+	#[allow(clippy::too_many_arguments)]
+	fn process_discovered_borrowers(
+		client: Arc<C>,
+		hash: B::Hash,
+		header: &B::Header,
+		overrides: Arc<dyn StorageOverride<B>>,
+		store: Arc<std::sync::Mutex<BorrowersStore>>,
+		pool_contract: EvmAddress,
+		borrowers: Arc<std::sync::Mutex<Vec<(H160, U256)>>>,
+		store_path: &Path,
+	) {
+		let block_number: BlockNumber = (*header.number()).unique_saturated_into();
+
+		let Ok(mut store) = store.lock() else {
+			tracing::debug!(target: LOG_TARGET, "borrowers_store mutex is poisoned");
+			// return if the mutex is poisoned
+			return;
+		};
+
+		// The block has been already processed, e.g. during the initial scan.
+		// Finalized blocks form a single chain, so the block number identifies the block.
+		if store.last_processed_block.is_some_and(|last| block_number <= last) {
+			return;
+		}
+
+		let mut new_borrowers = Vec::new();
+
+		// Blocks finalized together with this block or between the initial scan and the subscription.
+		// Processing stops at the first block without receipts, so the store is not advanced past it.
+		let mut complete = true;
+		for missed_block in store.next_block(block_number)..block_number {
+			let Some(receipts) = Self::block_receipts(&client, &overrides, missed_block) else {
+				complete = false;
+				break;
+			};
+			new_borrowers.extend(store.process_block(missed_block, pool_contract, &receipts));
+		}
+
+		if complete {
+			match overrides.current_receipts(hash) {
+				Some(receipts) => new_borrowers.extend(store.process_block(block_number, pool_contract, &receipts)),
+				None => tracing::error!(target: LOG_TARGET, "receipts of block {:?} are not available", block_number),
+			}
+		}
+
+		for borrower in new_borrowers {
+			let _ = Self::process_new_borrow(borrower, borrowers.clone());
+		}
+
+		if let Err(e) = store.save(store_path) {
+			tracing::error!(target: LOG_TARGET, "failed to save borrowers store: {:?}", e);
+		}
+	}
+
 	// TODO: return Result type
 	/// Fetch the preprocessed data used to evaluate possible candidates for liquidation.
 	async fn fetch_borrowers_data(http_client: HttpClient) -> Option<BorrowerData<AccountId>> {
@@ -788,6 +1187,23 @@ where
 	}
 }
 
+/// Appends the entries to the report file, one JSON object per line.
+pub fn write_dry_run_report(path: &Path, entries: &[DryRunReportEntry]) -> std::io::Result<()> {
+	if entries.is_empty() {
+		return Ok(());
+	}
+
+	let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+
+	let mut buffer = Vec::new();
+	for entry in entries {
+		serde_json::to_writer(&mut buffer, entry)?;
+		buffer.push(b'\n');
+	}
+
+	file.write_all(&buffer)
+}
+
 /// The data from DIA oracle update transaction.
 #[derive(Eq, PartialEq, Clone, RuntimeDebug)]
 pub struct OracleUpdataData {
@@ -989,4 +1405,39 @@ mod tests {
 		];
 		assert_eq!(expected, parse_oracle_transaction(&tx).unwrap());
 	}
+
+	#[test]
+	fn write_dry_run_report_should_append_json_lines() {
+		let path = std::env::temp_dir().join("liquidation-worker-dry-run-test.jsonl");
+		let _ = std::fs::remove_file(&path);
+
+		let entry = DryRunReportEntry {
+			block_number: 10,
+			borrower: H160::from_low_u64_be(1),
+			health_factor: U256::from(900_000_000_000_000_000u128),
+			health_factor_after_liquidation: U256::from(TARGET_HF),
+			collateral_asset: H160::from_low_u64_be(2),
+			collateral_asset_id: Some(5),
+			debt_asset: H160::from_low_u64_be(3),
+			debt_asset_id: None,
+			debt_to_liquidate: U256::from(1_000u128),
+			expected_profit: U256::from(50u128),
+		};
+
+		write_dry_run_report(&path, &[entry.clone()]).unwrap();
+		write_dry_run_report(&path, &[entry.clone(), entry]).unwrap();
+		// empty report doesn't change the file
+		write_dry_run_report(&path, &[]).unwrap();
+
+		let content = std::fs::read_to_string(&path).unwrap();
+		let lines = content.lines().collect::<Vec<_>>();
+		assert_eq!(lines.len(), 3);
+
+		let parsed: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+		assert_eq!(parsed["blockNumber"], 10);
+		assert_eq!(parsed["collateralAssetId"], 5);
+		assert!(parsed["debtAssetId"].is_null());
+
+		let _ = std::fs::remove_file(&path);
+	}
 }
@@ -0,0 +1,192 @@
+//! Discovery of money market borrowers from the EVM logs stored by the node.
+//!
+//! Borrowers are collected from `Supply` and `Borrow` events emitted by the money market pool contract.
+//! The set of borrowers is persisted to a local file together with the last processed block,
+//! so the discovery continues where it stopped after the node restart.
+
+use ethereum::ReceiptV3;
+use hydradx_runtime::evm::EvmAddress;
+use primitives::BlockNumber;
+use sp_core::{keccak_256, H256};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+// Supply(address indexed reserve, address user, address indexed onBehalfOf, uint256 amount, uint16 indexed referralCode)
+const SUPPLY_EVENT: &str = "Supply(address,address,address,uint256,uint16)";
+
+// Borrow(address indexed reserve, address user, address indexed onBehalfOf, uint256 amount, uint8 interestRateMode, uint256 borrowRate, uint16 indexed referralCode)
+const BORROW_EVENT: &str = "Borrow(address,address,address,uint256,uint8,uint256,uint16)";
+
+// Index of the `onBehalfOf` topic in both `Supply` and `Borrow` events.
+const ON_BEHALF_OF_TOPIC_INDEX: usize = 2;
+
+/// Returns the topic of an event with the given signature.
+fn event_topic(signature: &str) -> H256 {
+	H256::from(keccak_256(signature.as_bytes()))
+}
+
+/// Set of borrowers discovered from the EVM logs.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowersStore {
+	/// Last block whose logs were processed.
+	pub last_processed_block: Option<BlockNumber>,
+	/// Accounts that supplied or borrowed an asset in the money market.
+	pub borrowers: BTreeSet<EvmAddress>,
+}
+
+impl BorrowersStore {
+	/// Loads the store from the file. Returns an empty store if the file doesn't exist.
+	pub fn load(path: &Path) -> std::io::Result<Self> {
+		match std::fs::read(path) {
+			Ok(data) => serde_json::from_slice(&data).map_err(Into::into),
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// Persists the store to the file.
+	/// The data are written to a temporary file first, so the store is not corrupted if the node is killed.
+	pub fn save(&self, path: &Path) -> std::io::Result<()> {
+		let tmp_path = path.with_extension("tmp");
+		std::fs::write(&tmp_path, serde_json::to_vec(self)?)?;
+		std::fs::rename(tmp_path, path)
+	}
+
+	/// Returns the first block that hasn't been processed yet.
+	pub fn next_block(&self, start_block: BlockNumber) -> BlockNumber {
+		self.last_processed_block
+			.map(|block| block.saturating_add(1))
+			.unwrap_or(start_block)
+	}
+
+	/// Adds borrowers from `Supply` and `Borrow` events emitted by the `pool_contract` in the block.
+	/// Returns borrowers that were not in the store before.
+	pub fn process_block(
+		&mut self,
+		block_number: BlockNumber,
+		pool_contract: EvmAddress,
+		receipts: &[ReceiptV3],
+	) -> Vec<EvmAddress> {
+		let supply_topic = event_topic(SUPPLY_EVENT);
+		let borrow_topic = event_topic(BORROW_EVENT);
+
+		let mut new_borrowers = Vec::new();
+		for receipt in receipts {
+			let (ReceiptV3::Legacy(data) | ReceiptV3::EIP2930(data) | ReceiptV3::EIP1559(data)) = receipt;
+
+			for log in data.logs.iter().filter(|log| log.address == pool_contract) {
+				let Some(topic) = log.topics.first() else {
+					continue;
+				};
+				if *topic != supply_topic && *topic != borrow_topic {
+					continue;
+				}
+
+				let Some(on_behalf_of) = log.topics.get(ON_BEHALF_OF_TOPIC_INDEX) else {
+					continue;
+				};
+				let borrower = EvmAddress::from(*on_behalf_of);
+				if self.borrowers.insert(borrower) {
+					new_borrowers.push(borrower);
+				}
+			}
+		}
+
+		self.last_processed_block = Some(block_number);
+
+		new_borrowers
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ethereum::{EIP658ReceiptData, Log};
+	use sp_core::{H160, U256};
+
+	fn log(address: EvmAddress, event: &str, on_behalf_of: EvmAddress) -> Log {
+		Log {
+			address,
+			topics: vec![event_topic(event), H256::zero(), H256::from(on_behalf_of), H256::zero()],
+			data: Default::default(),
+		}
+	}
+
+	fn receipt(logs: Vec<Log>) -> ReceiptV3 {
+		ReceiptV3::EIP1559(EIP658ReceiptData {
+			status_code: 1,
+			used_gas: U256::zero(),
+			logs_bloom: Default::default(),
+			logs,
+		})
+	}
+
+	#[test]
+	fn event_topics_should_match_money_market_events() {
+		assert_eq!(
+			event_topic(SUPPLY_EVENT),
+			H256::from(hex_literal::hex!(
+				"2b627736bca15cd5381dcf80b0bf11fd197d01a037c52b927a881a10fb73ba61"
+			))
+		);
+		assert_eq!(
+			event_topic(BORROW_EVENT),
+			H256::from(hex_literal::hex!(
+				"b3d084820fb1a9decffb176436bd02558d15fac9b0ddfed8c465bc7359d7dce0"
+			))
+		);
+	}
+
+	#[test]
+	fn process_block_should_add_borrowers_from_pool_events() {
+		let pool = H160::from_low_u64_be(1);
+		let other_contract = H160::from_low_u64_be(2);
+		let alice = H160::from_low_u64_be(10);
+		let bob = H160::from_low_u64_be(11);
+		let charlie = H160::from_low_u64_be(12);
+
+		let mut store = BorrowersStore::default();
+		assert_eq!(store.next_block(100), 100);
+
+		let new_borrowers = store.process_block(
+			100,
+			pool,
+			&[
+				receipt(vec![log(pool, SUPPLY_EVENT, alice), log(pool, BORROW_EVENT, alice)]),
+				receipt(vec![
+					log(pool, BORROW_EVENT, bob),
+					log(other_contract, BORROW_EVENT, charlie),
+					log(pool, "Transfer(address,address,uint256)", charlie),
+				]),
+			],
+		);
+
+		assert_eq!(new_borrowers, vec![alice, bob]);
+		assert_eq!(store.borrowers, BTreeSet::from([alice, bob]));
+		assert_eq!(store.next_block(0), 101);
+
+		// already known borrowers are not reported again
+		let new_borrowers = store.process_block(101, pool, &[receipt(vec![log(pool, SUPPLY_EVENT, bob)])]);
+		assert!(new_borrowers.is_empty());
+		assert_eq!(store.last_processed_block, Some(101));
+	}
+
+	#[test]
+	fn store_should_be_persisted() {
+		let path = std::env::temp_dir().join("liquidation-worker-borrowers-test.json");
+		let _ = std::fs::remove_file(&path);
+
+		assert_eq!(BorrowersStore::load(&path).unwrap(), BorrowersStore::default());
+
+		let store = BorrowersStore {
+			last_processed_block: Some(5),
+			borrowers: BTreeSet::from([H160::from_low_u64_be(10)]),
+		};
+		store.save(&path).unwrap();
+
+		assert_eq!(BorrowersStore::load(&path).unwrap(), store);
+
+		let _ = std::fs::remove_file(&path);
+	}
+}
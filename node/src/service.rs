@@ -332,6 +332,8 @@ async fn start_node_impl(
 		);
 	}
 
+	let overrides = Arc::new(crate::rpc::StorageOverrideHandler::new(client.clone()));
+
 	if !liquidation_worker_config.disable_liquidation_worker {
 		task_manager.spawn_handle().spawn(
 			"liquidation-worker",
@@ -341,11 +343,11 @@ async fn start_node_impl(
 				liquidation_worker_config,
 				transaction_pool.clone(),
 				task_manager.spawn_handle(),
+				overrides.clone(),
 			),
 		);
 	}
 
-	let overrides = Arc::new(crate::rpc::StorageOverrideHandler::new(client.clone()));
 	let block_data_cache = Arc::new(fc_rpc::EthBlockDataCacheTask::new(
 		task_manager.spawn_handle(),
 		overrides.clone(),
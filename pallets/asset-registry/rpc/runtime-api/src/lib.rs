@@ -0,0 +1,39 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the asset registry pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query asset registry.
+	pub trait AssetRegistryApi<AssetId, AccountId, ExtendedMetadata> where
+		AssetId: Codec,
+		AccountId: Codec,
+		ExtendedMetadata: Codec,
+	{
+		/// Returns extended metadata of each of `asset_ids`, `None` for assets without extended metadata.
+		fn extended_metadata(asset_ids: Vec<AssetId>) -> Vec<(AssetId, Option<ExtendedMetadata>)>;
+
+		/// Returns `true` if `account`'s balance of `asset_id` is frozen.
+		fn is_account_frozen(asset_id: AssetId, account: AccountId) -> bool;
+
+		/// Returns all accounts with frozen balance of `asset_id`.
+		fn frozen_accounts(asset_id: AssetId) -> Vec<AccountId>;
+	}
+}
@@ -22,9 +22,28 @@ use super::*;
 use crate::types::AssetDetails;
 use frame_benchmarking::{account, benchmarks};
 use frame_support::traits::tokens::fungibles::Mutate as FungiblesMutate;
+use frame_support::traits::Currency;
 use frame_system::RawOrigin;
 use sp_std::vec;
 
+fn register_owned_asset<T: Config>(owner: &T::AccountId) -> T::AssetId {
+	T::DepositCurrency::make_free_balance_be(owner, 1_000_000_000_000_000_000_u128);
+
+	let asset_id = Pallet::<T>::next_asset_id().unwrap();
+	let name: BoundedVec<u8, T::StringLimit> = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+	let symbol: BoundedVec<u8, T::StringLimit> = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+	assert!(Pallet::<T>::register_owned(
+		RawOrigin::Signed(owner.clone()).into(),
+		name,
+		symbol,
+		12,
+		Some(T::MinOwnedAssetExistentialDeposit::get())
+	)
+	.is_ok());
+
+	asset_id
+}
+
 benchmarks! {
 	 where_clause { where
 		T::Currency: FungiblesMutate<T::AccountId>,
@@ -135,6 +154,142 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::banned_assets(asset_id), None);
 	}
 
+	set_extended_metadata {
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::DepositCurrency::make_free_balance_be(&caller, 1_000_000_000_000_000_000_u128);
+
+		let asset_id = Pallet::<T>::next_asset_id().unwrap();
+		Pallet::<T>::register_external(RawOrigin::Signed(caller.clone()).into(), Default::default())?;
+
+		let field: BoundedVec<u8, T::MetadataFieldLimit> = vec![97u8; T::MetadataFieldLimit::get() as usize].try_into().unwrap();
+		let metadata = ExtendedMetadata {
+			website: Some(field.clone()),
+			logo: Some(field.clone()),
+			coingecko_id: Some(field.clone()),
+			description: Some(field),
+		};
+	}: _(RawOrigin::Signed(caller), asset_id, metadata.clone())
+	verify {
+		assert_eq!(Pallet::<T>::extended_metadata(asset_id), Some(metadata));
+		assert!(Pallet::<T>::metadata_deposit(asset_id).is_some());
+	}
+
+	clear_extended_metadata {
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::DepositCurrency::make_free_balance_be(&caller, 1_000_000_000_000_000_000_u128);
+
+		let asset_id = Pallet::<T>::next_asset_id().unwrap();
+		Pallet::<T>::register_external(RawOrigin::Signed(caller.clone()).into(), Default::default())?;
+
+		let field: BoundedVec<u8, T::MetadataFieldLimit> = vec![97u8; T::MetadataFieldLimit::get() as usize].try_into().unwrap();
+		let metadata = ExtendedMetadata {
+			website: Some(field.clone()),
+			logo: Some(field.clone()),
+			coingecko_id: Some(field.clone()),
+			description: Some(field),
+		};
+		Pallet::<T>::set_extended_metadata(RawOrigin::Signed(caller.clone()).into(), asset_id, metadata)?;
+	}: _(RawOrigin::Signed(caller), asset_id)
+	verify {
+		assert_eq!(Pallet::<T>::extended_metadata(asset_id), None);
+		assert_eq!(Pallet::<T>::metadata_deposit(asset_id), None);
+	}
+	register_owned {
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::DepositCurrency::make_free_balance_be(&caller, 1_000_000_000_000_000_000_u128);
+
+		let expected_asset_id = Pallet::<T>::next_asset_id().unwrap();
+		let name: BoundedVec<u8, T::StringLimit> = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let symbol: BoundedVec<u8, T::StringLimit> = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), name.clone(), symbol, 12, Some(T::MinOwnedAssetExistentialDeposit::get()))
+	verify {
+		assert_eq!(Pallet::<T>::owned_asset_ids(name), Some(expected_asset_id));
+		assert_eq!(Pallet::<T>::asset_owner(expected_asset_id).map(|o| o.owner), Some(caller));
+	}
+
+	owner_update {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let asset_id = register_owned_asset::<T>(&caller);
+
+		let new_name: BoundedVec<u8, T::StringLimit> = vec![98u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let new_symbol: BoundedVec<u8, T::StringLimit> = vec![98u8; T::StringLimit::get() as usize].try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), asset_id, Some(new_name.clone()), Some(new_symbol), Some(18))
+	verify {
+		assert_eq!(Pallet::<T>::owned_asset_ids(new_name), Some(asset_id));
+		assert_eq!(Pallet::<T>::assets(asset_id).and_then(|d| d.decimals), Some(18));
+	}
+
+	set_mint_limit {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let asset_id = register_owned_asset::<T>(&caller);
+
+	}: _(RawOrigin::Root, asset_id, Some(1_000_000_000_000_000_u128))
+	verify {
+		assert_eq!(Pallet::<T>::mint_limit(asset_id), Some(1_000_000_000_000_000_u128));
+	}
+
+	mint {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let asset_id = register_owned_asset::<T>(&caller);
+
+		Pallet::<T>::set_mint_limit(RawOrigin::Root.into(), asset_id, Some(1_000_000_000_000_000_u128))?;
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, caller.clone(), 1_000_000_000_000_u128)
+	verify {
+		assert_eq!(T::Currency::balance(asset_id, &caller), 1_000_000_000_000_u128);
+	}
+
+	burn {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let asset_id = register_owned_asset::<T>(&caller);
+
+		Pallet::<T>::set_mint_limit(RawOrigin::Root.into(), asset_id, Some(1_000_000_000_000_000_u128))?;
+		Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), asset_id, caller.clone(), 2_000_000_000_000_u128)?;
+	}: _(RawOrigin::Signed(caller.clone()), asset_id, 1_000_000_000_000_u128)
+	verify {
+		assert_eq!(T::Currency::balance(asset_id, &caller), 1_000_000_000_000_u128);
+	}
+
+	deregister {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let asset_id = register_owned_asset::<T>(&caller);
+
+		let field: BoundedVec<u8, T::MetadataFieldLimit> = vec![97u8; T::MetadataFieldLimit::get() as usize].try_into().unwrap();
+		let metadata = ExtendedMetadata {
+			website: Some(field.clone()),
+			logo: Some(field.clone()),
+			coingecko_id: Some(field.clone()),
+			description: Some(field),
+		};
+		Pallet::<T>::set_extended_metadata(RawOrigin::Signed(caller.clone()).into(), asset_id, metadata)?;
+	}: _(RawOrigin::Signed(caller.clone()), asset_id)
+	verify {
+		assert!(Pallet::<T>::assets(asset_id).is_none());
+		assert!(Pallet::<T>::asset_owner(asset_id).is_none());
+		assert!(Pallet::<T>::metadata_deposit(asset_id).is_none());
+	}
+
+	freeze_account {
+		let who: T::AccountId = account("who", 0, 1);
+		let caller: T::AccountId = account("caller", 0, 1);
+		let asset_id = register_owned_asset::<T>(&caller);
+
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, asset_id, who.clone())
+	verify {
+		assert!(Pallet::<T>::is_account_frozen(asset_id, &who));
+	}
+
+	unfreeze_account {
+		let who: T::AccountId = account("who", 0, 1);
+		let caller: T::AccountId = account("caller", 0, 1);
+		let asset_id = register_owned_asset::<T>(&caller);
+
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		Pallet::<T>::freeze_account(origin.clone(), asset_id, who.clone())?;
+	}: _<T::RuntimeOrigin>(origin, asset_id, who.clone())
+	verify {
+		assert!(!Pallet::<T>::is_account_frozen(asset_id, &who));
+	}
 
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
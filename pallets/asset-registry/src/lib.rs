@@ -22,10 +22,12 @@ use frame_support::pallet_prelude::*;
 use frame_support::require_transactional;
 use frame_support::sp_runtime::traits::CheckedAdd;
 use frame_support::traits::tokens::fungibles::{Inspect as FungiblesInspect, Mutate as FungiblesMutate};
-use frame_support::traits::Contains;
+use frame_support::traits::tokens::{Fortitude, Precision, Preservation};
+use frame_support::traits::{Contains, ReservableCurrency};
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::BaseArithmetic;
+use sp_runtime::traits::{Saturating, Zero};
 use sp_runtime::DispatchError;
 use sp_std::convert::TryInto;
 use sp_std::vec::Vec;
@@ -45,13 +47,13 @@ pub use types::AssetType;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
-pub use crate::types::{AssetDetails, Balance, Name, Symbol};
+pub use crate::types::{AssetDetails, AssetOwnership, Balance, ExtendedMetadata, MetadataDeposit, Name, Symbol};
 use frame_support::storage::with_transaction;
 use frame_support::BoundedVec;
 use hydradx_traits::evm::EvmAddress;
 use hydradx_traits::{
 	registry::{Create, Inspect, Mutate},
-	AssetKind, BoundErc20, RegisterAssetHook,
+	AccountFreeze, AssetKind, BoundErc20, RegisterAssetHook,
 };
 use orml_traits::GetByKey;
 use polkadot_xcm::v3::Junction::AccountKey20;
@@ -73,6 +75,7 @@ pub mod pallet {
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	pub type AssetDetailsT<T> = AssetDetails<<T as Config>::StringLimit>;
+	pub type ExtendedMetadataOf<T> = ExtendedMetadata<<T as Config>::MetadataFieldLimit>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -104,6 +107,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type SequentialIdStartAt: Get<Self::AssetId>;
 
+		/// Native asset identifier.
+		#[pallet::constant]
+		type NativeAssetId: Get<Self::AssetId>;
+
 		/// The maximum length of a name or symbol stored on-chain.
 		#[pallet::constant]
 		type StringLimit: Get<u32> + Debug + PartialEq;
@@ -119,6 +126,30 @@ pub mod pallet {
 		/// Hook executed after new asset is registered
 		type RegisterAssetHook: RegisterAssetHook<Self::AssetId>;
 
+		/// Currency used to hold deposits.
+		type DepositCurrency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+		/// The maximum length of a single extended metadata field.
+		#[pallet::constant]
+		type MetadataFieldLimit: Get<u32> + Debug + PartialEq;
+
+		/// Base deposit held for extended metadata set by asset's registrar.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<Balance>;
+
+		/// Deposit held per byte of encoded extended metadata set by asset's registrar.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<Balance>;
+
+		/// Deposit held for permissionless asset registration until the asset is deregistered.
+		#[pallet::constant]
+		type RegistrationDeposit: Get<Balance>;
+
+		/// Minimal existential deposit of permissionlessly registered assets. It is also used when
+		/// existential deposit is not provided.
+		#[pallet::constant]
+		type MinOwnedAssetExistentialDeposit: Get<Balance>;
+
 		/// Weight information for the extrinsics
 		type WeightInfo: WeightInfo;
 	}
@@ -183,6 +214,42 @@ pub mod pallet {
 
 		/// Asset is not banned.
 		AssetNotBanned,
+
+		/// Extended metadata must contain at least one field.
+		EmptyMetadata,
+
+		/// Extended metadata is not set for the asset.
+		MetadataNotFound,
+
+		/// Origin is not owner of the asset.
+		NotAssetOwner,
+
+		/// Asset was not registered permissionlessly and has no owner.
+		AssetNotOwned,
+
+		/// Minting would exceed mint limit set by governance.
+		MintLimitExceeded,
+
+		/// Operation requires total issuance of the asset to be zero.
+		NonZeroIssuance,
+
+		/// Account's balance of the asset is already frozen.
+		AccountAlreadyFrozen,
+
+		/// Account's balance of the asset is not frozen.
+		AccountNotFrozen,
+
+		/// Native asset balances can't be frozen.
+		CannotFreezeNativeAsset,
+
+		/// Account's balance of the asset is frozen.
+		AccountFrozen,
+
+		/// Existential deposit is lower than `MinOwnedAssetExistentialDeposit`.
+		ExistentialDepositTooLow,
+
+		/// Asset has frozen accounts which have to be unfrozen first.
+		AssetHasFrozenAccounts,
 	}
 
 	#[pallet::type_value]
@@ -224,6 +291,47 @@ pub mod pallet {
 	pub type LocationAssets<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetNativeLocation, T::AssetId, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn asset_registrar)]
+	/// Account which registered an asset via permissionless registration.
+	pub type AssetRegistrars<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn extended_metadata)]
+	/// Extended metadata of an asset.
+	pub type AssetExtendedMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, ExtendedMetadataOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn metadata_deposit)]
+	/// Deposit held for extended metadata of an asset.
+	pub type MetadataDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, MetadataDeposit<T::AccountId>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_owner)]
+	/// Owner and registration deposit of permissionlessly registered assets.
+	pub type AssetOwners<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, AssetOwnership<T::AccountId>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn owned_asset_ids)]
+	/// Mapping between owned asset name and asset id.
+	/// Owned assets have separate name namespace, so they can't take names of other assets.
+	pub type OwnedAssetIds<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::StringLimit>, T::AssetId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn mint_limit)]
+	/// Max total issuance up to which asset's owner can mint. Owner can't mint if not set.
+	pub type MintLimits<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, Balance, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_account)]
+	/// Accounts which can't move their balance of an asset.
+	pub type FrozenAccounts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::storage]
 	/// Number of accounts that paid existential deposits for insufficient assets.
 	/// This storage is used by `SufficiencyCheck`.
@@ -346,6 +454,52 @@ pub mod pallet {
 
 		/// Asset's ban was removed.
 		AssetUnbanned { asset_id: T::AssetId },
+
+		/// Extended metadata was set for an asset.
+		ExtendedMetadataSet {
+			asset_id: T::AssetId,
+			metadata: ExtendedMetadataOf<T>,
+			deposit: Balance,
+		},
+
+		/// Extended metadata of an asset was removed.
+		ExtendedMetadataCleared { asset_id: T::AssetId },
+
+		/// Asset was registered permissionlessly and registration deposit was held.
+		OwnedAssetRegistered {
+			asset_id: T::AssetId,
+			owner: T::AccountId,
+			deposit: Balance,
+		},
+
+		/// Mint limit of an owned asset was set or removed.
+		MintLimitSet {
+			asset_id: T::AssetId,
+			limit: Option<Balance>,
+		},
+
+		/// Owner minted an owned asset.
+		Minted {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			amount: Balance,
+		},
+
+		/// Owner burned an owned asset.
+		Burned {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			amount: Balance,
+		},
+
+		/// Owned asset was deregistered and registration deposit was released.
+		Deregistered { asset_id: T::AssetId },
+
+		/// Account's balance of an asset was frozen.
+		AccountFrozen { asset_id: T::AssetId, who: T::AccountId },
+
+		/// Account's balance of an asset was unfrozen.
+		AccountUnfrozen { asset_id: T::AssetId, who: T::AccountId },
 	}
 
 	#[pallet::call]
@@ -428,18 +582,23 @@ pub mod pallet {
 
 			Self::validate_symbol(&symbol)?;
 
+			let is_owned = AssetOwners::<T>::contains_key(asset_id);
+
 			Assets::<T>::try_mutate(asset_id, |maybe_detail| -> DispatchResult {
 				let detail = maybe_detail.as_mut().ok_or(Error::<T>::AssetNotFound)?;
 
 				if let Some(new_name) = name.as_ref() {
-					ensure!(Self::asset_ids(new_name).is_none(), Error::<T>::AssetAlreadyRegistered);
+					ensure!(
+						Self::asset_id_by_name(new_name, is_owned).is_none(),
+						Error::<T>::AssetAlreadyRegistered
+					);
 
 					if let Some(old_name) = &detail.name {
-						AssetIds::<T>::remove(old_name);
+						Self::remove_asset_name(old_name, is_owned);
 					}
 
 					if Some(new_name.clone()) != detail.name {
-						AssetIds::<T>::insert(new_name, asset_id);
+						Self::insert_asset_name(new_name, asset_id, is_owned);
 					}
 				};
 
@@ -496,13 +655,14 @@ pub mod pallet {
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config>::WeightInfo::register_external().saturating_mul(<T as Config>::RegExternalWeightMultiplier::get()))]
 		pub fn register_external(origin: OriginFor<T>, location: T::AssetNativeLocation) -> DispatchResult {
-			let _ = ensure_signed(origin)?;
+			let who = ensure_signed(origin)?;
 
-			Self::do_register_asset(
+			let asset_id = Self::do_register_asset(
 				None,
 				&AssetDetails::new(None, AssetType::External, DEFAULT_ED, None, None, None, false),
 				Some(location),
 			)?;
+			AssetRegistrars::<T>::insert(asset_id, who);
 
 			Ok(())
 		}
@@ -537,6 +697,365 @@ pub mod pallet {
 			Self::deposit_event(Event::AssetUnbanned { asset_id });
 			Ok(())
 		}
+
+		/// Set extended metadata of an asset.
+		///
+		/// Can be called by the asset's registrar or by `UpdateOrigin`/`RegistryOrigin`.
+		///
+		/// Registrar holds a deposit of `MetadataDepositBase + MetadataDepositPerByte * encoded_len`.
+		/// Deposit is adjusted when metadata is updated. Metadata set by governance requires no
+		/// deposit, previously held deposit is released and the registrar can no longer change
+		/// the metadata.
+		///
+		/// Emits `ExtendedMetadataSet` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_extended_metadata())]
+		pub fn set_extended_metadata(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			metadata: ExtendedMetadataOf<T>,
+		) -> DispatchResult {
+			let maybe_registrar = Self::ensure_metadata_origin(origin, asset_id)?;
+
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+			ensure!(!metadata.is_empty(), Error::<T>::EmptyMetadata);
+
+			let deposit = match maybe_registrar {
+				Some(who) => {
+					let amount = Self::metadata_deposit_amount(&metadata);
+					Self::update_metadata_deposit(asset_id, Some(MetadataDeposit { depositor: who, amount }))?;
+					amount
+				}
+				None => {
+					Self::update_metadata_deposit(asset_id, None)?;
+					AssetRegistrars::<T>::remove(asset_id);
+					Zero::zero()
+				}
+			};
+
+			AssetExtendedMetadata::<T>::insert(asset_id, &metadata);
+
+			Self::deposit_event(Event::ExtendedMetadataSet {
+				asset_id,
+				metadata,
+				deposit,
+			});
+			Ok(())
+		}
+
+		/// Remove extended metadata of an asset and release its deposit.
+		///
+		/// Can be called by the asset's registrar or by `UpdateOrigin`/`RegistryOrigin`.
+		/// When cleared by governance, the registrar can no longer set the metadata.
+		///
+		/// Emits `ExtendedMetadataCleared` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_extended_metadata())]
+		pub fn clear_extended_metadata(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			let maybe_registrar = Self::ensure_metadata_origin(origin, asset_id)?;
+
+			ensure!(
+				AssetExtendedMetadata::<T>::take(asset_id).is_some(),
+				Error::<T>::MetadataNotFound
+			);
+			Self::update_metadata_deposit(asset_id, None)?;
+			if maybe_registrar.is_none() {
+				AssetRegistrars::<T>::remove(asset_id);
+			}
+
+			Self::deposit_event(Event::ExtendedMetadataCleared { asset_id });
+			Ok(())
+		}
+
+		/// Register a new `Token` asset without governance.
+		///
+		/// Asset is given sequential id and is insufficient. Caller becomes owner and registrar of
+		/// the asset and `RegistrationDeposit` is held from the caller until the asset is deregistered.
+		///
+		/// Existential deposit can't be lower than `MinOwnedAssetExistentialDeposit` which is also
+		/// used if it is not provided.
+		///
+		/// Emits `Registered` and `OwnedAssetRegistered` events when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_owned())]
+		pub fn register_owned(
+			origin: OriginFor<T>,
+			name: Name<T::StringLimit>,
+			symbol: Symbol<T::StringLimit>,
+			decimals: u8,
+			existential_deposit: Option<Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let existential_deposit = existential_deposit.unwrap_or_else(T::MinOwnedAssetExistentialDeposit::get);
+			ensure!(
+				existential_deposit >= T::MinOwnedAssetExistentialDeposit::get(),
+				Error::<T>::ExistentialDepositTooLow
+			);
+
+			let deposit = T::RegistrationDeposit::get();
+			T::DepositCurrency::reserve(&who, deposit)?;
+
+			let asset_id = Self::register_asset_details(
+				None,
+				&AssetDetails::new(
+					Some(name),
+					AssetType::Token,
+					existential_deposit,
+					Some(symbol),
+					Some(decimals),
+					None,
+					false,
+				),
+				None,
+				true,
+			)?;
+
+			AssetOwners::<T>::insert(
+				asset_id,
+				AssetOwnership {
+					owner: who.clone(),
+					deposit,
+				},
+			);
+			AssetRegistrars::<T>::insert(asset_id, &who);
+
+			Self::deposit_event(Event::OwnedAssetRegistered {
+				asset_id,
+				owner: who,
+				deposit,
+			});
+			Ok(())
+		}
+
+		/// Update name, symbol or decimals of an owned asset.
+		///
+		/// Can be called only by asset's owner. Decimals can be changed only while total issuance
+		/// of the asset is zero.
+		///
+		/// Emits `Updated` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::owner_update())]
+		pub fn owner_update(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			name: Option<Name<T::StringLimit>>,
+			symbol: Option<Symbol<T::StringLimit>>,
+			decimals: Option<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_asset_owner(asset_id, &who)?;
+
+			if let Some(n) = name.as_ref() {
+				ensure!(n.len() >= T::MinStringLimit::get() as usize, Error::<T>::TooShort);
+			}
+			Self::validate_symbol(&symbol)?;
+
+			if decimals.is_some() {
+				ensure!(
+					T::Currency::total_issuance(asset_id).is_zero(),
+					Error::<T>::NonZeroIssuance
+				);
+			}
+
+			Assets::<T>::try_mutate(asset_id, |maybe_detail| -> DispatchResult {
+				let detail = maybe_detail.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+				if let Some(new_name) = name.as_ref() {
+					// Asset's own current name can be submitted again.
+					ensure!(
+						Self::owned_asset_ids(new_name).map_or(true, |id| id == asset_id),
+						Error::<T>::AssetAlreadyRegistered
+					);
+
+					if let Some(old_name) = &detail.name {
+						OwnedAssetIds::<T>::remove(old_name);
+					}
+					OwnedAssetIds::<T>::insert(new_name, asset_id);
+				}
+
+				detail.name = name.or_else(|| detail.name.clone());
+				detail.symbol = symbol.or_else(|| detail.symbol.clone());
+				detail.decimals = decimals.or(detail.decimals);
+
+				Self::deposit_event(Event::Updated {
+					asset_id,
+					asset_name: detail.name.clone(),
+					asset_type: detail.asset_type,
+					existential_deposit: detail.existential_deposit,
+					xcm_rate_limit: detail.xcm_rate_limit,
+					symbol: detail.symbol.clone(),
+					decimals: detail.decimals,
+					is_sufficient: detail.is_sufficient,
+				});
+
+				Ok(())
+			})
+		}
+
+		/// Set max total issuance up to which owner can mint an owned asset.
+		///
+		/// Can be called only by `RegistryOrigin`. `None` removes the limit and disables minting by owner.
+		///
+		/// Emits `MintLimitSet` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_mint_limit())]
+		pub fn set_mint_limit(origin: OriginFor<T>, asset_id: T::AssetId, limit: Option<Balance>) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(AssetOwners::<T>::contains_key(asset_id), Error::<T>::AssetNotOwned);
+
+			MintLimits::<T>::set(asset_id, limit);
+
+			Self::deposit_event(Event::MintLimitSet { asset_id, limit });
+			Ok(())
+		}
+
+		/// Mint an owned asset to `beneficiary`.
+		///
+		/// Can be called only by asset's owner. Total issuance after mint can't exceed mint limit.
+		///
+		/// Emits `Minted` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			beneficiary: T::AccountId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_asset_owner(asset_id, &who)?;
+
+			let limit = Self::mint_limit(asset_id).ok_or(Error::<T>::MintLimitExceeded)?;
+			let new_issuance = T::Currency::total_issuance(asset_id)
+				.checked_add(amount)
+				.ok_or(Error::<T>::MintLimitExceeded)?;
+			ensure!(new_issuance <= limit, Error::<T>::MintLimitExceeded);
+
+			T::Currency::mint_into(asset_id, &beneficiary, amount)?;
+
+			Self::deposit_event(Event::Minted {
+				asset_id,
+				who: beneficiary,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Burn an owned asset from owner's account.
+		///
+		/// Emits `Burned` event when successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::burn())]
+		pub fn burn(origin: OriginFor<T>, asset_id: T::AssetId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_asset_owner(asset_id, &who)?;
+
+			T::Currency::burn_from(
+				asset_id,
+				&who,
+				amount,
+				Preservation::Expendable,
+				Precision::Exact,
+				Fortitude::Polite,
+			)?;
+
+			Self::deposit_event(Event::Burned { asset_id, who, amount });
+			Ok(())
+		}
+
+		/// Deregister an owned asset and release registration and metadata deposits.
+		///
+		/// Can be called by asset's owner or `RegistryOrigin`. Total issuance of the asset must be zero
+		/// and the asset can't have frozen accounts.
+		///
+		/// Emits `Deregistered` event when successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::deregister())]
+		pub fn deregister(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			let ownership = Self::asset_owner(asset_id).ok_or(Error::<T>::AssetNotOwned)?;
+			if T::RegistryOrigin::ensure_origin(origin.clone()).is_err() {
+				let who = ensure_signed(origin)?;
+				ensure!(who == ownership.owner, Error::<T>::NotAssetOwner);
+			}
+
+			ensure!(
+				T::Currency::total_issuance(asset_id).is_zero(),
+				Error::<T>::NonZeroIssuance
+			);
+			// Clearing of frozen accounts would be unbounded.
+			ensure!(
+				FrozenAccounts::<T>::iter_key_prefix(asset_id).next().is_none(),
+				Error::<T>::AssetHasFrozenAccounts
+			);
+
+			if let Some(details) = Assets::<T>::take(asset_id) {
+				if let Some(name) = details.name {
+					OwnedAssetIds::<T>::remove(name);
+				}
+			}
+			if let Some(location) = AssetLocations::<T>::take(asset_id) {
+				LocationAssets::<T>::remove(location);
+			}
+			BannedAssets::<T>::remove(asset_id);
+			MintLimits::<T>::remove(asset_id);
+			AssetRegistrars::<T>::remove(asset_id);
+			AssetOwners::<T>::remove(asset_id);
+			AssetExtendedMetadata::<T>::remove(asset_id);
+			Self::update_metadata_deposit(asset_id, None)?;
+
+			T::DepositCurrency::unreserve(&ownership.owner, ownership.deposit);
+
+			Self::deposit_event(Event::Deregistered { asset_id });
+			Ok(())
+		}
+
+		/// Freeze account's balance of an asset.
+		///
+		/// Frozen account can't transfer, trade or withdraw the asset. Asset keeps working for
+		/// all other accounts and frozen account can still receive it.
+		///
+		/// Native asset can't be frozen.
+		///
+		/// Emits `AccountFrozen` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::freeze_account())]
+		pub fn freeze_account(origin: OriginFor<T>, asset_id: T::AssetId, who: T::AccountId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::CannotFreezeNativeAsset);
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			ensure!(
+				!FrozenAccounts::<T>::contains_key(asset_id, &who),
+				Error::<T>::AccountAlreadyFrozen
+			);
+
+			FrozenAccounts::<T>::insert(asset_id, &who, ());
+
+			Self::deposit_event(Event::AccountFrozen { asset_id, who });
+			Ok(())
+		}
+
+		/// Remove freeze of account's balance of an asset.
+		///
+		/// Emits `AccountUnfrozen` event when successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::unfreeze_account())]
+		pub fn unfreeze_account(origin: OriginFor<T>, asset_id: T::AssetId, who: T::AccountId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				FrozenAccounts::<T>::contains_key(asset_id, &who),
+				Error::<T>::AccountNotFrozen
+			);
+
+			FrozenAccounts::<T>::remove(asset_id, &who);
+
+			Self::deposit_event(Event::AccountUnfrozen { asset_id, who });
+			Ok(())
+		}
 	}
 }
 
@@ -553,6 +1072,86 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_asset_owner(asset_id: T::AssetId, who: &T::AccountId) -> DispatchResult {
+		let ownership = Self::asset_owner(asset_id).ok_or(Error::<T>::AssetNotOwned)?;
+		ensure!(&ownership.owner == who, Error::<T>::NotAssetOwner);
+		Ok(())
+	}
+
+	/// Returns registrar account if origin is asset's registrar or `None` if origin is governance.
+	fn ensure_metadata_origin(
+		origin: OriginFor<T>,
+		asset_id: T::AssetId,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		if let Ok(who) = ensure_signed(origin.clone()) {
+			if Self::asset_registrar(asset_id).as_ref() == Some(&who) {
+				return Ok(Some(who));
+			}
+		}
+
+		if T::RegistryOrigin::ensure_origin(origin.clone()).is_err() {
+			T::UpdateOrigin::ensure_origin(origin)?;
+		}
+
+		Ok(None)
+	}
+
+	fn metadata_deposit_amount(metadata: &ExtendedMetadataOf<T>) -> Balance {
+		T::MetadataDepositPerByte::get()
+			.saturating_mul(metadata.encoded_size() as Balance)
+			.saturating_add(T::MetadataDepositBase::get())
+	}
+
+	/// Replaces deposit held for asset's extended metadata. Releases previous deposit.
+	fn update_metadata_deposit(
+		asset_id: T::AssetId,
+		new_deposit: Option<MetadataDeposit<T::AccountId>>,
+	) -> DispatchResult {
+		let old_deposit = MetadataDeposits::<T>::take(asset_id);
+
+		match (old_deposit, &new_deposit) {
+			(Some(old), Some(new)) if old.depositor == new.depositor => {
+				if new.amount > old.amount {
+					T::DepositCurrency::reserve(&new.depositor, new.amount.saturating_sub(old.amount))?;
+				} else {
+					T::DepositCurrency::unreserve(&new.depositor, old.amount.saturating_sub(new.amount));
+				}
+			}
+			(old, new) => {
+				if let Some(old) = old {
+					T::DepositCurrency::unreserve(&old.depositor, old.amount);
+				}
+				if let Some(new) = new {
+					T::DepositCurrency::reserve(&new.depositor, new.amount)?;
+				}
+			}
+		}
+
+		if let Some(deposit) = new_deposit {
+			MetadataDeposits::<T>::insert(asset_id, deposit);
+		}
+
+		Ok(())
+	}
+
+	/// Return extended metadata of given assets.
+	pub fn extended_metadata_of(asset_ids: Vec<T::AssetId>) -> Vec<(T::AssetId, Option<ExtendedMetadataOf<T>>)> {
+		asset_ids
+			.into_iter()
+			.map(|asset_id| (asset_id, Self::extended_metadata(asset_id)))
+			.collect()
+	}
+
+	/// Return `true` if account's balance of the asset is frozen.
+	pub fn is_account_frozen(asset_id: T::AssetId, who: &T::AccountId) -> bool {
+		FrozenAccounts::<T>::contains_key(asset_id, who)
+	}
+
+	/// Return all accounts with frozen balance of the asset.
+	pub fn frozen_accounts_of(asset_id: T::AssetId) -> Vec<T::AccountId> {
+		FrozenAccounts::<T>::iter_key_prefix(asset_id).collect()
+	}
+
 	pub fn next_asset_id() -> Option<T::AssetId> {
 		NextAssetId::<T>::get().checked_add(&T::SequentialIdStartAt::get())
 	}
@@ -576,6 +1175,16 @@ impl<T: Config> Pallet<T> {
 		selected_asset_id: Option<T::AssetId>,
 		details: &AssetDetails<T::StringLimit>,
 		location: Option<T::AssetNativeLocation>,
+	) -> Result<T::AssetId, DispatchError> {
+		Self::register_asset_details(selected_asset_id, details, location, false)
+	}
+
+	/// Registers an asset. Name of an owned asset is registered in the owned assets namespace.
+	fn register_asset_details(
+		selected_asset_id: Option<T::AssetId>,
+		details: &AssetDetails<T::StringLimit>,
+		location: Option<T::AssetNativeLocation>,
+		is_owned: bool,
 	) -> Result<T::AssetId, DispatchError> {
 		Self::validate_symbol(&details.symbol)?;
 
@@ -601,8 +1210,11 @@ impl<T: Config> Pallet<T> {
 		Assets::<T>::insert(asset_id, details);
 		if let Some(name) = details.name.as_ref() {
 			ensure!(name.len() >= T::MinStringLimit::get() as usize, Error::<T>::TooShort);
-			ensure!(!AssetIds::<T>::contains_key(name), Error::<T>::AssetAlreadyRegistered);
-			AssetIds::<T>::insert(name, asset_id);
+			ensure!(
+				Self::asset_id_by_name(name, is_owned).is_none(),
+				Error::<T>::AssetAlreadyRegistered
+			);
+			Self::insert_asset_name(name, asset_id, is_owned);
 		}
 
 		if let Some(loc) = location {
@@ -625,6 +1237,30 @@ impl<T: Config> Pallet<T> {
 		Ok(asset_id)
 	}
 
+	fn asset_id_by_name(name: &BoundedVec<u8, T::StringLimit>, is_owned: bool) -> Option<T::AssetId> {
+		if is_owned {
+			OwnedAssetIds::<T>::get(name)
+		} else {
+			AssetIds::<T>::get(name)
+		}
+	}
+
+	fn insert_asset_name(name: &BoundedVec<u8, T::StringLimit>, asset_id: T::AssetId, is_owned: bool) {
+		if is_owned {
+			OwnedAssetIds::<T>::insert(name, asset_id);
+		} else {
+			AssetIds::<T>::insert(name, asset_id);
+		}
+	}
+
+	fn remove_asset_name(name: &BoundedVec<u8, T::StringLimit>, is_owned: bool) {
+		if is_owned {
+			OwnedAssetIds::<T>::remove(name);
+		} else {
+			AssetIds::<T>::remove(name);
+		}
+	}
+
 	/// Return location for given asset.
 	pub fn asset_to_location(asset_id: T::AssetId) -> Option<T::AssetNativeLocation> {
 		Self::locations(asset_id)
@@ -786,6 +1422,12 @@ where
 	}
 }
 
+impl<T: Config> AccountFreeze<T::AssetId, T::AccountId> for Pallet<T> {
+	fn is_frozen(asset_id: T::AssetId, who: &T::AccountId) -> bool {
+		Self::is_account_frozen(asset_id, who)
+	}
+}
+
 /// Oracle whitelist based on the asset sufficiency.
 pub struct OracleWhitelist<T>(PhantomData<T>);
 impl<T: Config> Contains<(hydradx_traits::Source, <T as Config>::AssetId, <T as Config>::AssetId)>
@@ -0,0 +1,331 @@
+use super::*;
+
+use frame_support::error::BadOrigin;
+use frame_support::traits::tokens::fungibles::Mutate as MutateFungibles;
+use mock::{AssetId, MetadataFieldLimit, Registry};
+use polkadot_xcm::v3::{
+	Junction::{self, Parachain},
+	Junctions::X2,
+	MultiLocation,
+};
+use pretty_assertions::assert_eq;
+
+const BOB: u64 = 1_001;
+
+fn field(value: &[u8]) -> Option<BoundedVec<u8, MetadataFieldLimit>> {
+	Some(value.to_vec().try_into().unwrap())
+}
+
+fn metadata() -> ExtendedMetadata<MetadataFieldLimit> {
+	ExtendedMetadata {
+		website: field(b"https://hydration.net"),
+		logo: field(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+		coingecko_id: field(b"hydration"),
+		description: None,
+	}
+}
+
+fn expected_deposit(metadata: &ExtendedMetadata<MetadataFieldLimit>) -> Balance {
+	mock::MetadataDepositBase::get() + mock::MetadataDepositPerByte::get() * metadata.encoded_size() as Balance
+}
+
+fn reserved(who: u64) -> Balance {
+	<Test as Config>::DepositCurrency::reserved_balance(&who)
+}
+
+fn register_external_asset(who: u64) -> AssetId {
+	let asset_id = Pallet::<Test>::next_asset_id().unwrap();
+	let key = Junction::from(BoundedVec::try_from(asset_id.encode()).unwrap());
+	let location = AssetLocation(MultiLocation::new(0, X2(Parachain(200), key)));
+
+	assert_ok!(Registry::register_external(RuntimeOrigin::signed(who), location));
+
+	asset_id
+}
+
+#[test]
+fn register_external_should_store_registrar() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = register_external_asset(ALICE);
+
+		assert_eq!(Registry::asset_registrar(asset_id), Some(ALICE));
+	});
+}
+
+#[test]
+fn set_extended_metadata_should_hold_deposit_when_called_by_registrar() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		Tokens::mint_into(NativeAssetId::get(), &ALICE, 10_000 * UNIT).unwrap();
+		let asset_id = register_external_asset(ALICE);
+		let metadata = metadata();
+		let deposit = expected_deposit(&metadata);
+
+		//Act
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			metadata.clone()
+		));
+
+		//Assert
+		assert_eq!(Registry::extended_metadata(asset_id), Some(metadata.clone()));
+		assert_eq!(
+			Registry::metadata_deposit(asset_id),
+			Some(MetadataDeposit {
+				depositor: ALICE,
+				amount: deposit
+			})
+		);
+		assert_eq!(reserved(ALICE), deposit);
+		assert_last_event!(Event::<Test>::ExtendedMetadataSet {
+			asset_id,
+			metadata,
+			deposit,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn set_extended_metadata_should_adjust_deposit_when_metadata_is_updated() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		Tokens::mint_into(NativeAssetId::get(), &ALICE, 10_000 * UNIT).unwrap();
+		let asset_id = register_external_asset(ALICE);
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			metadata()
+		));
+
+		let mut larger = metadata();
+		larger.description = field(b"Liquidity hub of Polkadot");
+
+		//Act & Assert
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			larger.clone()
+		));
+		assert_eq!(reserved(ALICE), expected_deposit(&larger));
+
+		let smaller = ExtendedMetadata {
+			website: None,
+			logo: None,
+			coingecko_id: field(b"hydration"),
+			description: None,
+		};
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			smaller.clone()
+		));
+		assert_eq!(reserved(ALICE), expected_deposit(&smaller));
+	});
+}
+
+#[test]
+fn set_extended_metadata_should_fail_when_registrar_cannot_pay_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = register_external_asset(ALICE);
+
+		assert!(Registry::set_extended_metadata(RuntimeOrigin::signed(ALICE), asset_id, metadata()).is_err());
+		assert_eq!(Registry::extended_metadata(asset_id), None);
+	});
+}
+
+#[test]
+fn set_extended_metadata_should_release_deposit_when_set_by_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		Tokens::mint_into(NativeAssetId::get(), &ALICE, 10_000 * UNIT).unwrap();
+		let asset_id = register_external_asset(ALICE);
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			metadata()
+		));
+
+		//Act
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::root(),
+			asset_id,
+			metadata()
+		));
+
+		//Assert
+		assert_eq!(reserved(ALICE), 0);
+		assert_eq!(Registry::metadata_deposit(asset_id), None);
+		assert_eq!(Registry::extended_metadata(asset_id), Some(metadata()));
+	});
+}
+
+#[test]
+fn set_extended_metadata_should_revoke_registrar_when_set_by_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		Tokens::mint_into(NativeAssetId::get(), &ALICE, 10_000 * UNIT).unwrap();
+		let asset_id = register_external_asset(ALICE);
+
+		//Act
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::root(),
+			asset_id,
+			metadata()
+		));
+
+		//Assert
+		assert_eq!(Registry::asset_registrar(asset_id), None);
+	});
+}
+
+#[test]
+fn clear_extended_metadata_should_revoke_registrar_when_cleared_by_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		Tokens::mint_into(NativeAssetId::get(), &ALICE, 10_000 * UNIT).unwrap();
+		let asset_id = register_external_asset(ALICE);
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			metadata()
+		));
+
+		//Act
+		assert_ok!(Registry::clear_extended_metadata(RuntimeOrigin::root(), asset_id));
+
+		//Assert
+		assert_eq!(reserved(ALICE), 0);
+		assert_eq!(Registry::asset_registrar(asset_id), None);
+	});
+}
+
+#[test]
+fn set_extended_metadata_should_not_hold_deposit_when_called_by_update_origin() {
+	ExtBuilder::default()
+		.with_assets(vec![(
+			Some(1),
+			Some(b"Tkn1".to_vec().try_into().unwrap()),
+			UNIT,
+			None,
+			None,
+			None,
+			true,
+		)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Registry::set_extended_metadata(
+				RuntimeOrigin::signed(BOB),
+				1,
+				metadata()
+			));
+
+			assert_eq!(Registry::extended_metadata(1), Some(metadata()));
+			assert_eq!(Registry::metadata_deposit(1), None);
+			assert_eq!(reserved(BOB), 0);
+		});
+}
+
+#[test]
+fn set_extended_metadata_should_fail_when_invalid() {
+	ExtBuilder::default()
+		.with_assets(vec![(
+			Some(1),
+			Some(b"Tkn1".to_vec().try_into().unwrap()),
+			UNIT,
+			None,
+			None,
+			None,
+			true,
+		)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Registry::set_extended_metadata(RuntimeOrigin::none(), 1, metadata()),
+				BadOrigin
+			);
+			assert_noop!(
+				Registry::set_extended_metadata(RuntimeOrigin::root(), 2, metadata()),
+				Error::<Test>::AssetNotFound
+			);
+			assert_noop!(
+				Registry::set_extended_metadata(
+					RuntimeOrigin::root(),
+					1,
+					ExtendedMetadata {
+						website: None,
+						logo: None,
+						coingecko_id: None,
+						description: None,
+					}
+				),
+				Error::<Test>::EmptyMetadata
+			);
+		});
+}
+
+#[test]
+fn clear_extended_metadata_should_release_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		Tokens::mint_into(NativeAssetId::get(), &ALICE, 10_000 * UNIT).unwrap();
+		let asset_id = register_external_asset(ALICE);
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			metadata()
+		));
+
+		//Act
+		assert_ok!(Registry::clear_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id
+		));
+
+		//Assert
+		assert_eq!(Registry::extended_metadata(asset_id), None);
+		assert_eq!(Registry::metadata_deposit(asset_id), None);
+		assert_eq!(reserved(ALICE), 0);
+		assert_last_event!(Event::<Test>::ExtendedMetadataCleared { asset_id }.into());
+
+		assert_noop!(
+			Registry::clear_extended_metadata(RuntimeOrigin::signed(ALICE), asset_id),
+			Error::<Test>::MetadataNotFound
+		);
+	});
+}
+
+#[test]
+fn extended_metadata_of_should_return_metadata_of_all_requested_assets() {
+	ExtBuilder::default()
+		.with_assets(vec![
+			(
+				Some(1),
+				Some(b"Tkn1".to_vec().try_into().unwrap()),
+				UNIT,
+				None,
+				None,
+				None,
+				true,
+			),
+			(
+				Some(2),
+				Some(b"Tkn2".to_vec().try_into().unwrap()),
+				UNIT,
+				None,
+				None,
+				None,
+				true,
+			),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Registry::set_extended_metadata(RuntimeOrigin::root(), 2, metadata()));
+
+			assert_eq!(
+				Registry::extended_metadata_of(vec![1, 2, 3]),
+				vec![(1, None), (2, Some(metadata())), (3, None)]
+			);
+		});
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+use frame_support::error::BadOrigin;
+use hydradx_traits::AccountFreeze;
+use mock::{NativeAssetId, Registry};
+use pretty_assertions::assert_eq;
+
+const BOB: u64 = 1_001;
+const ASSET: u32 = 1;
+
+fn builder() -> ExtBuilder {
+	ExtBuilder::default().with_assets(vec![(
+		Some(ASSET),
+		Some(b"tkn1".to_vec().try_into().unwrap()),
+		UNIT,
+		None,
+		None,
+		None,
+		true,
+	)])
+}
+
+#[test]
+fn freeze_account_should_work_when_asset_is_registered() {
+	builder().build().execute_with(|| {
+		//Act
+		assert_ok!(Registry::freeze_account(RuntimeOrigin::signed(ALICE), ASSET, BOB));
+
+		//Assert
+		assert_eq!(Registry::frozen_account(ASSET, BOB), Some(()));
+		assert!(<Registry as AccountFreeze<AssetId, u64>>::is_frozen(ASSET, &BOB));
+		assert!(!Registry::is_account_frozen(ASSET, &ALICE));
+		assert!(!Registry::is_account_frozen(0, &BOB));
+		assert_eq!(Registry::frozen_accounts_of(ASSET), vec![BOB]);
+
+		assert_last_event!(Event::<Test>::AccountFrozen {
+			asset_id: ASSET,
+			who: BOB
+		}
+		.into());
+	});
+}
+
+#[test]
+fn freeze_account_should_fail_when_asset_is_not_registered() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			Registry::freeze_account(RuntimeOrigin::signed(ALICE), 42, BOB),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn freeze_account_should_fail_when_account_is_already_frozen() {
+	builder().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Registry::freeze_account(RuntimeOrigin::signed(ALICE), ASSET, BOB));
+
+		//Act & assert
+		assert_noop!(
+			Registry::freeze_account(RuntimeOrigin::signed(ALICE), ASSET, BOB),
+			Error::<Test>::AccountAlreadyFrozen
+		);
+	});
+}
+
+#[test]
+fn freeze_account_should_fail_when_asset_is_native() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			Registry::freeze_account(RuntimeOrigin::signed(ALICE), NativeAssetId::get(), BOB),
+			Error::<Test>::CannotFreezeNativeAsset
+		);
+	});
+}
+
+#[test]
+fn freeze_account_should_fail_when_origin_is_not_allowed() {
+	builder().build().execute_with(|| {
+		assert_noop!(Registry::freeze_account(RuntimeOrigin::none(), ASSET, BOB), BadOrigin);
+	});
+}
+
+#[test]
+fn unfreeze_account_should_work_when_account_is_frozen() {
+	builder().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Registry::freeze_account(RuntimeOrigin::signed(ALICE), ASSET, BOB));
+
+		//Act
+		assert_ok!(Registry::unfreeze_account(RuntimeOrigin::signed(ALICE), ASSET, BOB));
+
+		//Assert
+		assert_eq!(Registry::frozen_account(ASSET, BOB), None);
+		assert!(Registry::frozen_accounts_of(ASSET).is_empty());
+
+		assert_last_event!(Event::<Test>::AccountUnfrozen {
+			asset_id: ASSET,
+			who: BOB
+		}
+		.into());
+	});
+}
+
+#[test]
+fn unfreeze_account_should_fail_when_account_is_not_frozen() {
+	builder().build().execute_with(|| {
+		assert_noop!(
+			Registry::unfreeze_account(RuntimeOrigin::signed(ALICE), ASSET, BOB),
+			Error::<Test>::AccountNotFrozen
+		);
+	});
+}
@@ -107,6 +107,15 @@ impl From<AssetLocation> for MultiLocation {
 	}
 }
 
+parameter_types! {
+	#[derive(PartialEq, Debug)]
+	pub const MetadataFieldLimit: u32 = 64;
+	pub const MetadataDepositBase: Balance = 1_000;
+	pub const MetadataDepositPerByte: Balance = 10;
+	pub const RegistrationDeposit: Balance = 10_000;
+	pub const MinOwnedAssetExistentialDeposit: Balance = 100;
+}
+
 impl pallet_asset_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Tokens;
@@ -117,8 +126,15 @@ impl pallet_asset_registry::Config for Test {
 	type StringLimit = RegistryStringLimit;
 	type MinStringLimit = RegistryMinStringLimit;
 	type SequentialIdStartAt = SequentialIdStart;
+	type NativeAssetId = NativeAssetId;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
 	type RegisterAssetHook = ();
+	type DepositCurrency = orml_tokens::CurrencyAdapter<Test, NativeAssetId>;
+	type MetadataFieldLimit = MetadataFieldLimit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type RegistrationDeposit = RegistrationDeposit;
+	type MinOwnedAssetExistentialDeposit = MinOwnedAssetExistentialDeposit;
 	type WeightInfo = ();
 }
 
@@ -5,9 +5,12 @@ use frame_support::{assert_noop, assert_ok};
 
 mod create_trait;
 mod evm;
+mod extended_metadata;
+mod freeze;
 mod inspect_trait;
 pub(crate) mod mock;
 mod mutate_trait;
+mod owned_assets;
 mod register;
 #[allow(clippy::module_inception)]
 mod tests;
@@ -0,0 +1,475 @@
+use super::*;
+
+use frame_support::error::BadOrigin;
+use frame_support::traits::tokens::fungibles::{Inspect as InspectFungibles, Mutate as MutateFungibles};
+use mock::{AssetId, MetadataFieldLimit, Registry, RegistryStringLimit};
+use pretty_assertions::assert_eq;
+
+const BOB: u64 = 1_001;
+
+fn name(value: &[u8]) -> BoundedVec<u8, RegistryStringLimit> {
+	value.to_vec().try_into().unwrap()
+}
+
+fn reserved(who: u64) -> Balance {
+	<Test as Config>::DepositCurrency::reserved_balance(&who)
+}
+
+fn register_owned_asset(who: u64) -> AssetId {
+	let asset_id = Pallet::<Test>::next_asset_id().unwrap();
+
+	assert_ok!(Registry::register_owned(
+		RuntimeOrigin::signed(who),
+		name(b"Owned"),
+		name(b"OWN"),
+		12,
+		Some(1_000)
+	));
+
+	asset_id
+}
+
+fn fund(who: u64) {
+	Tokens::mint_into(NativeAssetId::get(), &who, 100_000 * UNIT).unwrap();
+}
+
+#[test]
+fn register_owned_should_work_when_deposit_is_paid() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		fund(ALICE);
+		let expected_id = Pallet::<Test>::next_asset_id().unwrap();
+
+		//Act
+		assert_ok!(Registry::register_owned(
+			RuntimeOrigin::signed(ALICE),
+			name(b"Owned"),
+			name(b"OWN"),
+			12,
+			None
+		));
+
+		//Assert
+		assert_eq!(
+			Registry::assets(expected_id),
+			Some(AssetDetails {
+				name: Some(name(b"Owned")),
+				asset_type: AssetType::Token,
+				existential_deposit: mock::MinOwnedAssetExistentialDeposit::get(),
+				xcm_rate_limit: None,
+				symbol: Some(name(b"OWN")),
+				decimals: Some(12),
+				is_sufficient: false
+			})
+		);
+		assert_eq!(Registry::owned_asset_ids(name(b"Owned")), Some(expected_id));
+		assert_eq!(Registry::asset_ids(name(b"Owned")), None);
+		assert_eq!(
+			Registry::asset_owner(expected_id),
+			Some(AssetOwnership {
+				owner: ALICE,
+				deposit: mock::RegistrationDeposit::get()
+			})
+		);
+		assert_eq!(Registry::asset_registrar(expected_id), Some(ALICE));
+		assert_eq!(reserved(ALICE), mock::RegistrationDeposit::get());
+		assert_last_event!(Event::<Test>::OwnedAssetRegistered {
+			asset_id: expected_id,
+			owner: ALICE,
+			deposit: mock::RegistrationDeposit::get(),
+		}
+		.into());
+	});
+}
+
+#[test]
+fn register_owned_should_fail_when_deposit_cannot_be_paid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(
+			Registry::register_owned(RuntimeOrigin::signed(ALICE), name(b"Owned"), name(b"OWN"), 12, None).is_err()
+		);
+		assert_eq!(Registry::owned_asset_ids(name(b"Owned")), None);
+	});
+}
+
+#[test]
+fn register_owned_should_fail_when_existential_deposit_is_too_low() {
+	ExtBuilder::default().build().execute_with(|| {
+		fund(ALICE);
+
+		assert_noop!(
+			Registry::register_owned(
+				RuntimeOrigin::signed(ALICE),
+				name(b"Owned"),
+				name(b"OWN"),
+				12,
+				Some(mock::MinOwnedAssetExistentialDeposit::get() - 1)
+			),
+			Error::<Test>::ExistentialDepositTooLow
+		);
+	});
+}
+
+#[test]
+fn register_owned_should_fail_when_name_is_used_by_owned_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		fund(ALICE);
+		fund(BOB);
+		register_owned_asset(ALICE);
+
+		assert_noop!(
+			Registry::register_owned(RuntimeOrigin::signed(BOB), name(b"Owned"), name(b"OWN"), 12, None),
+			Error::<Test>::AssetAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn register_owned_should_not_take_name_of_registered_asset() {
+	ExtBuilder::default()
+		.with_assets(vec![(Some(1), Some(name(b"Owned")), UNIT, None, None, None, true)])
+		.build()
+		.execute_with(|| {
+			fund(ALICE);
+
+			let asset_id = register_owned_asset(ALICE);
+
+			assert_eq!(Registry::asset_ids(name(b"Owned")), Some(1));
+			assert_eq!(Registry::owned_asset_ids(name(b"Owned")), Some(asset_id));
+		});
+}
+
+#[test]
+fn register_should_not_fail_when_name_is_used_by_owned_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		fund(ALICE);
+		let owned_asset_id = register_owned_asset(ALICE);
+
+		assert_ok!(Registry::register(
+			RuntimeOrigin::root(),
+			Some(1),
+			Some(name(b"Owned")),
+			AssetType::Token,
+			Some(UNIT),
+			None,
+			None,
+			None,
+			None,
+			true
+		));
+
+		assert_eq!(Registry::asset_ids(name(b"Owned")), Some(1));
+		assert_eq!(Registry::owned_asset_ids(name(b"Owned")), Some(owned_asset_id));
+	});
+}
+
+#[test]
+fn update_should_keep_owned_asset_name_in_owned_namespace() {
+	ExtBuilder::default().build().execute_with(|| {
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+
+		assert_ok!(Registry::update(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(name(b"Renamed")),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_eq!(Registry::owned_asset_ids(name(b"Owned")), None);
+		assert_eq!(Registry::owned_asset_ids(name(b"Renamed")), Some(asset_id));
+		assert_eq!(Registry::asset_ids(name(b"Renamed")), None);
+	});
+}
+
+#[test]
+fn owner_update_should_work_when_called_by_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+
+		//Act
+		assert_ok!(Registry::owner_update(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			Some(name(b"Renamed")),
+			Some(name(b"REN")),
+			Some(18)
+		));
+
+		//Assert
+		let details = Registry::assets(asset_id).unwrap();
+		assert_eq!(details.name, Some(name(b"Renamed")));
+		assert_eq!(details.symbol, Some(name(b"REN")));
+		assert_eq!(details.decimals, Some(18));
+		assert_eq!(Registry::owned_asset_ids(name(b"Owned")), None);
+		assert_eq!(Registry::owned_asset_ids(name(b"Renamed")), Some(asset_id));
+	});
+}
+
+#[test]
+fn owner_update_should_work_when_name_is_current_name_of_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+
+		//Act
+		assert_ok!(Registry::owner_update(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			Some(name(b"Owned")),
+			Some(name(b"REN")),
+			None
+		));
+
+		//Assert
+		let details = Registry::assets(asset_id).unwrap();
+		assert_eq!(details.name, Some(name(b"Owned")));
+		assert_eq!(details.symbol, Some(name(b"REN")));
+		assert_eq!(Registry::owned_asset_ids(name(b"Owned")), Some(asset_id));
+	});
+}
+
+#[test]
+fn owner_update_should_fail_when_not_owner() {
+	ExtBuilder::default()
+		.with_assets(vec![(Some(1), Some(name(b"Tkn1")), UNIT, None, None, None, true)])
+		.build()
+		.execute_with(|| {
+			fund(ALICE);
+			let asset_id = register_owned_asset(ALICE);
+
+			assert_noop!(
+				Registry::owner_update(RuntimeOrigin::signed(BOB), asset_id, Some(name(b"Renamed")), None, None),
+				Error::<Test>::NotAssetOwner
+			);
+			assert_noop!(
+				Registry::owner_update(RuntimeOrigin::signed(ALICE), 1, Some(name(b"Renamed")), None, None),
+				Error::<Test>::AssetNotOwned
+			);
+		});
+}
+
+#[test]
+fn owner_update_should_not_change_decimals_when_asset_was_minted() {
+	ExtBuilder::default().build().execute_with(|| {
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+		assert_ok!(Registry::set_mint_limit(RuntimeOrigin::root(), asset_id, Some(UNIT)));
+		assert_ok!(Registry::mint(RuntimeOrigin::signed(ALICE), asset_id, BOB, UNIT));
+
+		assert_noop!(
+			Registry::owner_update(RuntimeOrigin::signed(ALICE), asset_id, None, None, Some(18)),
+			Error::<Test>::NonZeroIssuance
+		);
+		assert_ok!(Registry::owner_update(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			None,
+			Some(name(b"REN")),
+			None
+		));
+	});
+}
+
+#[test]
+fn mint_should_work_within_mint_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+
+		assert_noop!(
+			Registry::mint(RuntimeOrigin::signed(ALICE), asset_id, BOB, UNIT),
+			Error::<Test>::MintLimitExceeded
+		);
+
+		assert_noop!(
+			Registry::set_mint_limit(RuntimeOrigin::none(), asset_id, Some(10 * UNIT)),
+			BadOrigin
+		);
+		assert_ok!(Registry::set_mint_limit(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(10 * UNIT)
+		));
+		assert_last_event!(Event::<Test>::MintLimitSet {
+			asset_id,
+			limit: Some(10 * UNIT),
+		}
+		.into());
+
+		//Act
+		assert_ok!(Registry::mint(RuntimeOrigin::signed(ALICE), asset_id, BOB, 6 * UNIT));
+
+		//Assert
+		assert_eq!(Tokens::balance(asset_id, &BOB), 6 * UNIT);
+		assert_last_event!(Event::<Test>::Minted {
+			asset_id,
+			who: BOB,
+			amount: 6 * UNIT,
+		}
+		.into());
+
+		assert_noop!(
+			Registry::mint(RuntimeOrigin::signed(ALICE), asset_id, BOB, 5 * UNIT),
+			Error::<Test>::MintLimitExceeded
+		);
+		assert_noop!(
+			Registry::mint(RuntimeOrigin::signed(BOB), asset_id, BOB, UNIT),
+			Error::<Test>::NotAssetOwner
+		);
+		assert_ok!(Registry::mint(RuntimeOrigin::signed(ALICE), asset_id, BOB, 4 * UNIT));
+	});
+}
+
+#[test]
+fn set_mint_limit_should_fail_when_asset_is_not_owned() {
+	ExtBuilder::default()
+		.with_assets(vec![(Some(1), Some(name(b"Tkn1")), UNIT, None, None, None, true)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Registry::set_mint_limit(RuntimeOrigin::root(), 1, Some(UNIT)),
+				Error::<Test>::AssetNotOwned
+			);
+		});
+}
+
+#[test]
+fn burn_should_burn_from_owner_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+		assert_ok!(Registry::set_mint_limit(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(10 * UNIT)
+		));
+		assert_ok!(Registry::mint(RuntimeOrigin::signed(ALICE), asset_id, ALICE, 10 * UNIT));
+
+		//Act
+		assert_ok!(Registry::burn(RuntimeOrigin::signed(ALICE), asset_id, 4 * UNIT));
+
+		//Assert
+		assert_eq!(Tokens::balance(asset_id, &ALICE), 6 * UNIT);
+		assert_eq!(Tokens::total_issuance(asset_id), 6 * UNIT);
+		assert_last_event!(Event::<Test>::Burned {
+			asset_id,
+			who: ALICE,
+			amount: 4 * UNIT,
+		}
+		.into());
+
+		// burned amount can be minted again
+		assert_ok!(Registry::mint(RuntimeOrigin::signed(ALICE), asset_id, ALICE, 4 * UNIT));
+	});
+}
+
+#[test]
+fn deregister_should_release_deposits_when_total_issuance_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+		let metadata = ExtendedMetadata::<MetadataFieldLimit> {
+			website: Some(b"https://owned.io".to_vec().try_into().unwrap()),
+			logo: None,
+			coingecko_id: None,
+			description: None,
+		};
+		assert_ok!(Registry::set_extended_metadata(
+			RuntimeOrigin::signed(ALICE),
+			asset_id,
+			metadata
+		));
+		assert_ok!(Registry::set_mint_limit(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(10 * UNIT)
+		));
+		assert_ok!(Registry::mint(RuntimeOrigin::signed(ALICE), asset_id, ALICE, 10 * UNIT));
+
+		assert_noop!(
+			Registry::deregister(RuntimeOrigin::signed(ALICE), asset_id),
+			Error::<Test>::NonZeroIssuance
+		);
+		assert_noop!(
+			Registry::deregister(RuntimeOrigin::signed(BOB), asset_id),
+			Error::<Test>::NotAssetOwner
+		);
+
+		assert_ok!(Registry::burn(RuntimeOrigin::signed(ALICE), asset_id, 10 * UNIT));
+
+		//Act
+		assert_ok!(Registry::deregister(RuntimeOrigin::signed(ALICE), asset_id));
+
+		//Assert
+		assert_eq!(Registry::assets(asset_id), None);
+		assert_eq!(Registry::owned_asset_ids(name(b"Owned")), None);
+		assert_eq!(Registry::asset_owner(asset_id), None);
+		assert_eq!(Registry::asset_registrar(asset_id), None);
+		assert_eq!(Registry::mint_limit(asset_id), None);
+		assert_eq!(Registry::extended_metadata(asset_id), None);
+		assert_eq!(Registry::metadata_deposit(asset_id), None);
+		assert_eq!(reserved(ALICE), 0);
+		assert_last_event!(Event::<Test>::Deregistered { asset_id }.into());
+
+		// name can be registered again
+		register_owned_asset(ALICE);
+	});
+}
+
+#[test]
+fn deregister_should_work_when_called_by_registry_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+
+		assert_ok!(Registry::deregister(RuntimeOrigin::root(), asset_id));
+
+		assert_eq!(Registry::assets(asset_id), None);
+		assert_eq!(reserved(ALICE), 0);
+	});
+}
+
+#[test]
+fn deregister_should_fail_when_asset_is_not_owned() {
+	ExtBuilder::default()
+		.with_assets(vec![(Some(1), Some(name(b"Tkn1")), UNIT, None, None, None, true)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Registry::deregister(RuntimeOrigin::root(), 1),
+				Error::<Test>::AssetNotOwned
+			);
+		});
+}
+
+#[test]
+fn deregister_should_fail_when_asset_has_frozen_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		fund(ALICE);
+		let asset_id = register_owned_asset(ALICE);
+		assert_ok!(Registry::freeze_account(RuntimeOrigin::signed(ALICE), asset_id, BOB));
+
+		//Act & assert
+		assert_noop!(
+			Registry::deregister(RuntimeOrigin::root(), asset_id),
+			Error::<Test>::AssetHasFrozenAccounts
+		);
+
+		assert_ok!(Registry::unfreeze_account(RuntimeOrigin::signed(ALICE), asset_id, BOB));
+		assert_ok!(Registry::deregister(RuntimeOrigin::root(), asset_id));
+	});
+}
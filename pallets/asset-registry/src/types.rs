@@ -111,3 +111,47 @@ impl<StringLimit: Get<u32>> AssetDetails<StringLimit> {
 		}
 	}
 }
+
+/// Optional off-chain facing metadata of an asset used by wallets and explorers.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(FieldLimit))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExtendedMetadata<FieldLimit: Get<u32>> {
+	/// Project website URL.
+	pub website: Option<BoundedVec<u8, FieldLimit>>,
+
+	/// IPFS CID of the asset's logo.
+	pub logo: Option<BoundedVec<u8, FieldLimit>>,
+
+	/// Asset's CoinGecko ID.
+	pub coingecko_id: Option<BoundedVec<u8, FieldLimit>>,
+
+	/// Short description of the asset.
+	pub description: Option<BoundedVec<u8, FieldLimit>>,
+}
+
+impl<FieldLimit: Get<u32>> ExtendedMetadata<FieldLimit> {
+	pub fn is_empty(&self) -> bool {
+		self.website.is_none() && self.logo.is_none() && self.coingecko_id.is_none() && self.description.is_none()
+	}
+}
+
+/// Deposit held for extended metadata set by asset's registrar.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MetadataDeposit<AccountId> {
+	/// Account the deposit is held from.
+	pub depositor: AccountId,
+
+	/// Held amount.
+	pub amount: Balance,
+}
+
+/// Owner of a permissionlessly registered asset.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AssetOwnership<AccountId> {
+	/// Account which registered the asset and manages it.
+	pub owner: AccountId,
+
+	/// Registration deposit held from the owner until the asset is deregistered.
+	pub deposit: Balance,
+}
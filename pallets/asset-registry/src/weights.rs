@@ -15,6 +15,16 @@ pub trait WeightInfo {
 	fn register_external() -> Weight;
 	fn ban_asset() -> Weight;
 	fn unban_asset() -> Weight;
+	fn set_extended_metadata() -> Weight;
+	fn clear_extended_metadata() -> Weight;
+	fn register_owned() -> Weight;
+	fn owner_update() -> Weight;
+	fn set_mint_limit() -> Weight;
+	fn mint() -> Weight;
+	fn burn() -> Weight;
+	fn deregister() -> Weight;
+	fn freeze_account() -> Weight;
+	fn unfreeze_account() -> Weight;
 }
 /// Weights for pallet_asset_registry using the hydraDX node and recommended hardware.
 
@@ -48,13 +58,16 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::AssetLocations` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::LocationAssets` (r:0 w:1)
 	/// Proof: `AssetRegistry::LocationAssets` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetOwners` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetOwners` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	fn update() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `617`
 		//  Estimated: `4087`
 		// Minimum execution time: 48_110_000 picoseconds.
+		// `AssetRegistry::AssetOwners` read added manually, needs to be regenerated.
 		Weight::from_parts(48_686_000, 4087)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:1)
@@ -69,6 +82,8 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::AssetLocations` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:0 w:1)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetRegistrars` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetRegistrars` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn register_external() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `301`
@@ -76,7 +91,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 35_103_000 picoseconds.
 		Weight::from_parts(35_530_000, 4087)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
@@ -102,4 +117,160 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRegistry::AssetRegistrars` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetRegistrars` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::MetadataDeposits` (r:1 w:1)
+	/// Proof: `AssetRegistry::MetadataDeposits` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetExtendedMetadata` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetExtendedMetadata` (`max_values`: None, `max_size`: Some(1064), added: 3539, mode: `MaxEncodedLen`)
+	fn set_extended_metadata() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(41_913_000, 4529)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AssetRegistry::AssetRegistrars` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetRegistrars` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetExtendedMetadata` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetExtendedMetadata` (`max_values`: None, `max_size`: Some(1064), added: 3539, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::MetadataDeposits` (r:1 w:1)
+	/// Proof: `AssetRegistry::MetadataDeposits` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn clear_extended_metadata() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(39_120_000, 4529)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:1)
+	/// Proof: `AssetRegistry::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::OwnedAssetIds` (r:1 w:1)
+	/// Proof: `AssetRegistry::OwnedAssetIds` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetRegistry::Assets` (r:0 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetOwners` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetOwners` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetRegistrars` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetRegistrars` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn register_owned() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(52_614_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `AssetRegistry::AssetOwners` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetOwners` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::OwnedAssetIds` (r:1 w:2)
+	/// Proof: `AssetRegistry::OwnedAssetIds` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn owner_update() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(34_210_000, 3590)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetRegistry::AssetOwners` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetOwners` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::MintLimits` (r:0 w:1)
+	/// Proof: `AssetRegistry::MintLimits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn set_mint_limit() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(17_902_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetRegistry::AssetOwners` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetOwners` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::MintLimits` (r:1 w:0)
+	/// Proof: `AssetRegistry::MintLimits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn mint() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(61_337_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetRegistry::AssetOwners` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetOwners` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn burn() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(58_021_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetRegistry::AssetOwners` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetOwners` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetLocations` (r:1 w:0)
+	/// Proof: `AssetRegistry::AssetLocations` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::MetadataDeposits` (r:1 w:0)
+	/// Proof: `AssetRegistry::MetadataDeposits` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::FrozenAccounts` (r:1 w:0)
+	/// Proof: `AssetRegistry::FrozenAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::OwnedAssetIds` (r:0 w:1)
+	/// Proof: `AssetRegistry::OwnedAssetIds` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:0 w:1)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::MintLimits` (r:0 w:1)
+	/// Proof: `AssetRegistry::MintLimits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetRegistrars` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetRegistrars` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetExtendedMetadata` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetExtendedMetadata` (`max_values`: None, `max_size`: Some(1064), added: 3539, mode: `MaxEncodedLen`)
+	fn deregister() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(47_850_000, 4087)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::FrozenAccounts` (r:1 w:1)
+	/// Proof: `AssetRegistry::FrozenAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn freeze_account() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(23_517_000, 3590)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetRegistry::FrozenAccounts` (r:1 w:1)
+	/// Proof: `AssetRegistry::FrozenAccounts` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn unfreeze_account() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(19_904_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,37 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the asset vesting pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query multi-asset vesting schedules.
+	pub trait AssetVestingApi<AccountId, AssetId, Balance, VestingSchedule> where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		VestingSchedule: Codec,
+	{
+		/// Returns vesting schedules of `account` grouped by asset.
+		fn vesting_schedules(account: AccountId) -> Vec<(AssetId, Vec<VestingSchedule>)>;
+
+		/// Returns amount of `asset_id` which is still locked by vesting schedules of `account`.
+		fn locked_balance(account: AccountId, asset_id: AssetId) -> Balance;
+	}
+}
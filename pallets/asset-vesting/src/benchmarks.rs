@@ -0,0 +1,95 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+fn schedule_kind<T: Config>() -> VestingKind<BlockNumberFor<T>> {
+	VestingKind::Linear {
+		duration: 1_000_000u32.into(),
+	}
+}
+
+fn setup_grantor<T: Config>() -> Result<(T::RuntimeOrigin, T::AssetId), DispatchError> {
+	let origin = T::GrantorOrigin::try_successful_origin().map_err(|_| DispatchError::BadOrigin)?;
+	let grantor = T::GrantorOrigin::ensure_origin(origin.clone())?;
+	let asset_id = T::BenchmarkHelper::register_asset()?;
+	T::Currency::deposit(asset_id, &grantor, 1_000_000 * ONE)?;
+
+	Ok((origin, asset_id))
+}
+
+fn add_schedules<T: Config>(
+	origin: T::RuntimeOrigin,
+	who: &T::AccountId,
+	asset_id: T::AssetId,
+	count: u32,
+) -> DispatchResult {
+	for _ in 0..count {
+		Pallet::<T>::vested_transfer(
+			origin.clone(),
+			T::Lookup::unlookup(who.clone()),
+			asset_id,
+			Zero::zero(),
+			schedule_kind::<T>(),
+			10 * ONE,
+		)?;
+	}
+	Ok(())
+}
+
+benchmarks! {
+	vested_transfer {
+		let (origin, asset_id) = setup_grantor::<T>()?;
+		let who: T::AccountId = account("who", 0, 1);
+		add_schedules::<T>(origin.clone(), &who, asset_id, T::MaxVestingSchedules::get() - 1)?;
+		let dest = T::Lookup::unlookup(who.clone());
+	}: _<T::RuntimeOrigin>(origin, dest, asset_id, Zero::zero(), schedule_kind::<T>(), 10 * ONE)
+	verify {
+		assert_eq!(VestingSchedules::<T>::get(&who, asset_id).len() as u32, T::MaxVestingSchedules::get());
+	}
+
+	claim {
+		let (origin, asset_id) = setup_grantor::<T>()?;
+		let who: T::AccountId = account("who", 0, 1);
+		add_schedules::<T>(origin, &who, asset_id, T::MaxVestingSchedules::get())?;
+	}: _(RawOrigin::Signed(who.clone()), asset_id)
+	verify {
+		assert_eq!(VestingSchedules::<T>::get(&who, asset_id).len() as u32, T::MaxVestingSchedules::get());
+	}
+
+	merge_schedules {
+		let (origin, asset_id) = setup_grantor::<T>()?;
+		let who: T::AccountId = account("who", 0, 1);
+		add_schedules::<T>(origin.clone(), &who, asset_id, T::MaxVestingSchedules::get())?;
+	}: _<T::RuntimeOrigin>(origin, who.clone(), asset_id, 0, 1)
+	verify {
+		assert_eq!(VestingSchedules::<T>::get(&who, asset_id).len() as u32, T::MaxVestingSchedules::get() - 1);
+	}
+
+	cancel_schedule {
+		let (origin, asset_id) = setup_grantor::<T>()?;
+		let who: T::AccountId = account("who", 0, 1);
+		add_schedules::<T>(origin.clone(), &who, asset_id, T::MaxVestingSchedules::get())?;
+	}: _<T::RuntimeOrigin>(origin, who.clone(), asset_id, 0)
+	verify {
+		assert_eq!(VestingSchedules::<T>::get(&who, asset_id).len() as u32, T::MaxVestingSchedules::get() - 1);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
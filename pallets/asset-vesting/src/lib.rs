@@ -0,0 +1,395 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset vesting pallet
+//!
+//! ## Description
+//! The pallet allows to vest any asset, not only the native one. Vested amount is transferred from the grantor
+//! to the beneficiary and locked in beneficiary's account via currency locks. Locked amount is released
+//! according to the schedule:
+//! * `Cliff` - whole amount is released at once when the schedule ends,
+//! * `Linear` - amount is released linearly from the start to the end of the schedule,
+//! * `Step` - amount is released in equal steps, one every period.
+//!
+//! Each schedule records its grantor. Only the grantor can merge or cancel its schedules. When a schedule is
+//! cancelled, already vested amount is released and unvested amount is returned to the grantor.
+//!
+//! ## Notes
+//! Lock is not updated automatically when the time passes. Beneficiary has to `claim` to release the vested amount.
+//!
+//! ## Dispatchable functions
+//! * `vested_transfer` - transfer an asset to an account and lock it according to the schedule.
+//! * `claim` - release vested amount of an asset.
+//! * `merge_schedules` - merge two schedules into a single linear schedule.
+//! * `cancel_schedule` - cancel a schedule and return unvested amount to the grantor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::manual_inspect)]
+
+use frame_support::{pallet_prelude::*, require_transactional};
+use frame_system::pallet_prelude::*;
+use orml_traits::{LockIdentifier, MultiCurrency, MultiLockableCurrency};
+use sp_runtime::traits::{BlockNumberProvider, Saturating, StaticLookup, Zero};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarks;
+
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub const VESTING_LOCK_ID: LockIdentifier = *b"assetvst";
+
+pub type VestingScheduleOf<T> = VestingSchedule<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Asset type.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Multi currency mechanism used to transfer and lock vested assets.
+		type Currency: MultiLockableCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Origin which can create, merge and cancel schedules. Returns the grantor account which funds the schedules.
+		type GrantorOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// Provider of the block number used to evaluate schedules.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Max number of schedules of an account per asset.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_schedules)]
+	/// Vesting schedules of an account per asset.
+	pub type VestingSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Asset was transferred and locked according to the vesting schedule.
+		VestingScheduleAdded {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			schedule: VestingScheduleOf<T>,
+		},
+		/// Vested amount was released. `locked` is the amount which remains locked.
+		Claimed {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			locked: Balance,
+		},
+		/// Two vesting schedules were merged into a single linear schedule.
+		VestingSchedulesMerged {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			schedule: VestingScheduleOf<T>,
+		},
+		/// Vesting schedule was cancelled and unvested amount was returned to the grantor.
+		VestingScheduleCancelled {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			grantor: T::AccountId,
+			returned: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Schedule has zero amount or zero duration or its end overflows.
+		InvalidSchedule,
+		/// Vested amount is lower than existential deposit of the asset.
+		AmountLow,
+		/// Account has max number of schedules for the asset.
+		TooManySchedules,
+		/// Schedule with given index doesn't exist.
+		ScheduleNotFound,
+		/// Origin is not grantor of the schedule.
+		NotGrantor,
+		/// Schedule can't be merged with itself.
+		SameSchedule,
+		/// Account has no vesting schedules of the asset.
+		NotVesting,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Transfer `total` of `asset_id` from grantor to `dest` and lock it according to the schedule.
+		///
+		/// Parameters:
+		/// - `origin`: `GrantorOrigin`. Grantor account funds the schedule.
+		/// - `dest`: beneficiary of the schedule.
+		/// - `asset_id`: vested asset.
+		/// - `start`: block from which vesting starts.
+		/// - `kind`: the way how vested amount is released.
+		/// - `total`: vested amount.
+		///
+		/// Emits `VestingScheduleAdded` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::vested_transfer())]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			asset_id: T::AssetId,
+			start: BlockNumberFor<T>,
+			kind: VestingKind<BlockNumberFor<T>>,
+			total: Balance,
+		) -> DispatchResult {
+			let grantor = T::GrantorOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(dest)?;
+
+			let schedule = VestingSchedule {
+				grantor,
+				start,
+				kind,
+				total,
+			};
+			ensure!(schedule.is_valid(), Error::<T>::InvalidSchedule);
+			ensure!(total >= T::Currency::minimum_balance(asset_id), Error::<T>::AmountLow);
+
+			Self::do_vested_transfer(&who, asset_id, schedule)
+		}
+
+		/// Release vested amount of `asset_id` and remove finished schedules.
+		///
+		/// Emits `Claimed` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schedules = VestingSchedules::<T>::get(&who, asset_id);
+			ensure!(!schedules.is_empty(), Error::<T>::NotVesting);
+
+			let locked = Self::update_schedules(&who, asset_id, schedules.into_inner())?;
+
+			Self::deposit_event(Event::Claimed { who, asset_id, locked });
+			Ok(())
+		}
+
+		/// Merge two schedules of `who` into a single linear schedule.
+		///
+		/// Merged schedule starts at the current block or at the later start of the two schedules and ends
+		/// at the later end of the two schedules. It vests amount which is still locked by both schedules.
+		/// Both schedules must be created by the grantor.
+		///
+		/// Emits `VestingSchedulesMerged` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_schedules())]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let grantor = T::GrantorOrigin::ensure_origin(origin)?;
+			ensure!(schedule1_index != schedule2_index, Error::<T>::SameSchedule);
+
+			let mut schedules = VestingSchedules::<T>::get(&who, asset_id).into_inner();
+			let schedule1 = schedules
+				.get(schedule1_index as usize)
+				.cloned()
+				.ok_or(Error::<T>::ScheduleNotFound)?;
+			let schedule2 = schedules
+				.get(schedule2_index as usize)
+				.cloned()
+				.ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(
+				schedule1.grantor == grantor && schedule2.grantor == grantor,
+				Error::<T>::NotGrantor
+			);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let locked = schedule1.locked_at(now).saturating_add(schedule2.locked_at(now));
+			let start = now.max(schedule1.start).max(schedule2.start);
+			let end = schedule1
+				.end()
+				.max(schedule2.end())
+				.ok_or(Error::<T>::InvalidSchedule)?;
+
+			let merged = VestingSchedule {
+				grantor,
+				start,
+				kind: VestingKind::Linear {
+					duration: end.saturating_sub(start),
+				},
+				total: locked,
+			};
+
+			schedules.remove(schedule1_index.max(schedule2_index) as usize);
+			schedules.remove(schedule1_index.min(schedule2_index) as usize);
+			if merged.is_valid() {
+				schedules.push(merged.clone());
+			}
+
+			Self::update_schedules(&who, asset_id, schedules)?;
+
+			Self::deposit_event(Event::VestingSchedulesMerged {
+				who,
+				asset_id,
+				schedule: merged,
+			});
+			Ok(())
+		}
+
+		/// Cancel schedule of `who` and return unvested amount to the grantor.
+		///
+		/// Already vested amount is released. Schedule must be created by the grantor.
+		///
+		/// Emits `VestingScheduleCancelled` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_schedule())]
+		pub fn cancel_schedule(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			schedule_index: u32,
+		) -> DispatchResult {
+			let grantor = T::GrantorOrigin::ensure_origin(origin)?;
+
+			let mut schedules = VestingSchedules::<T>::get(&who, asset_id).into_inner();
+			let schedule = schedules
+				.get(schedule_index as usize)
+				.cloned()
+				.ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(schedule.grantor == grantor, Error::<T>::NotGrantor);
+
+			let returned = schedule.locked_at(T::BlockNumberProvider::current_block_number());
+			schedules.remove(schedule_index as usize);
+
+			Self::do_cancel_schedule(&who, asset_id, &grantor, schedules, returned)?;
+
+			Self::deposit_event(Event::VestingScheduleCancelled {
+				who,
+				asset_id,
+				grantor,
+				returned,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	#[require_transactional]
+	fn do_vested_transfer(who: &T::AccountId, asset_id: T::AssetId, schedule: VestingScheduleOf<T>) -> DispatchResult {
+		let mut schedules = VestingSchedules::<T>::get(who, asset_id);
+		schedules
+			.try_push(schedule.clone())
+			.map_err(|_| Error::<T>::TooManySchedules)?;
+
+		T::Currency::transfer(asset_id, &schedule.grantor, who, schedule.total)?;
+		Self::update_schedules(who, asset_id, schedules.into_inner())?;
+
+		Self::deposit_event(Event::VestingScheduleAdded {
+			who: who.clone(),
+			asset_id,
+			schedule,
+		});
+		Ok(())
+	}
+
+	#[require_transactional]
+	fn do_cancel_schedule(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		grantor: &T::AccountId,
+		schedules: Vec<VestingScheduleOf<T>>,
+		returned: Balance,
+	) -> DispatchResult {
+		Self::update_schedules(who, asset_id, schedules)?;
+		T::Currency::transfer(asset_id, who, grantor, returned)
+	}
+
+	/// Stores schedules which still lock some amount and updates the lock. Returns locked amount.
+	fn update_schedules(
+		who: &T::AccountId,
+		asset_id: T::AssetId,
+		mut schedules: Vec<VestingScheduleOf<T>>,
+	) -> Result<Balance, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		schedules.retain(|schedule| !schedule.locked_at(now).is_zero());
+
+		let locked = Self::locked_at(&schedules, now);
+		if locked.is_zero() {
+			T::Currency::remove_lock(VESTING_LOCK_ID, asset_id, who)?;
+			VestingSchedules::<T>::remove(who, asset_id);
+		} else {
+			T::Currency::set_lock(VESTING_LOCK_ID, asset_id, who, locked)?;
+			let schedules: BoundedVec<_, T::MaxVestingSchedules> =
+				schedules.try_into().map_err(|_| Error::<T>::TooManySchedules)?;
+			VestingSchedules::<T>::insert(who, asset_id, schedules);
+		}
+
+		Ok(locked)
+	}
+
+	fn locked_at(schedules: &[VestingScheduleOf<T>], now: BlockNumberFor<T>) -> Balance {
+		schedules.iter().fold(Balance::zero(), |acc, schedule| {
+			acc.saturating_add(schedule.locked_at(now))
+		})
+	}
+
+	/// Return vesting schedules of an account grouped by asset.
+	pub fn schedules_of(who: &T::AccountId) -> Vec<(T::AssetId, Vec<VestingScheduleOf<T>>)> {
+		VestingSchedules::<T>::iter_prefix(who)
+			.map(|(asset_id, schedules)| (asset_id, schedules.into_inner()))
+			.collect()
+	}
+
+	/// Return amount of an asset which is still locked by vesting schedules of an account at current block.
+	pub fn locked_balance(who: &T::AccountId, asset_id: T::AssetId) -> Balance {
+		Self::locked_at(
+			&VestingSchedules::<T>::get(who, asset_id),
+			T::BlockNumberProvider::current_block_number(),
+		)
+	}
+}
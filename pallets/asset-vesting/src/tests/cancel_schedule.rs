@@ -0,0 +1,126 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::{Error, Event, VestingKind, VestingSchedules};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+fn add_schedule(grantor: AccountId, kind: VestingKind<u64>, total: Balance) {
+	assert_ok!(AssetVesting::vested_transfer(
+		RuntimeOrigin::signed(grantor),
+		ALICE,
+		DAI,
+		1,
+		kind,
+		total,
+	));
+}
+
+#[test]
+fn cancel_schedule_should_return_unvested_amount_to_grantor() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_schedule(GRANTOR, VestingKind::Linear { duration: 100 }, 100 * ONE);
+		set_block_number(41);
+
+		//Act
+		assert_ok!(AssetVesting::cancel_schedule(
+			RuntimeOrigin::signed(GRANTOR),
+			ALICE,
+			DAI,
+			0
+		));
+
+		//Assert
+		assert!(!VestingSchedules::<Test>::contains_key(ALICE, DAI));
+		assert!(Tokens::locks(ALICE, DAI).is_empty());
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 40 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &GRANTOR), GRANTOR_INITIAL_BALANCE - 40 * ONE);
+
+		expect_events(vec![Event::VestingScheduleCancelled {
+			who: ALICE,
+			asset_id: DAI,
+			grantor: GRANTOR,
+			returned: 60 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn cancel_schedule_should_keep_lock_of_other_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_schedule(OTHER_GRANTOR, VestingKind::Cliff { duration: 100 }, 10 * ONE);
+		add_schedule(
+			GRANTOR,
+			VestingKind::Step {
+				period: 10,
+				period_count: 10,
+			},
+			100 * ONE,
+		);
+		set_block_number(31);
+
+		//Act
+		assert_ok!(AssetVesting::cancel_schedule(
+			RuntimeOrigin::signed(GRANTOR),
+			ALICE,
+			DAI,
+			1
+		));
+
+		//Assert
+		assert_eq!(AssetVesting::vesting_schedules(ALICE, DAI).len(), 1);
+		assert_eq!(Tokens::accounts(ALICE, DAI).frozen, 10 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 40 * ONE);
+	});
+}
+
+#[test]
+fn cancel_schedule_should_fail_when_schedule_belongs_to_other_grantor() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_schedule(OTHER_GRANTOR, VestingKind::Linear { duration: 100 }, 100 * ONE);
+
+		//Act & assert
+		assert_noop!(
+			AssetVesting::cancel_schedule(RuntimeOrigin::signed(GRANTOR), ALICE, DAI, 0),
+			Error::<Test>::NotGrantor
+		);
+	});
+}
+
+#[test]
+fn cancel_schedule_should_fail_when_schedule_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetVesting::cancel_schedule(RuntimeOrigin::signed(GRANTOR), ALICE, DAI, 0),
+			Error::<Test>::ScheduleNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_schedule_should_fail_when_origin_is_not_grantor_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetVesting::cancel_schedule(RuntimeOrigin::none(), ALICE, DAI, 0),
+			BadOrigin
+		);
+	});
+}
@@ -0,0 +1,120 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::{Error, Event, VestingKind, VestingSchedules};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+fn add_linear_schedule(total: Balance) {
+	assert_ok!(AssetVesting::vested_transfer(
+		RuntimeOrigin::signed(GRANTOR),
+		ALICE,
+		DAI,
+		1,
+		VestingKind::Linear { duration: 100 },
+		total,
+	));
+}
+
+#[test]
+fn claim_should_release_vested_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_linear_schedule(100 * ONE);
+		set_block_number(26);
+
+		//Act
+		assert_ok!(AssetVesting::claim(RuntimeOrigin::signed(ALICE), DAI));
+
+		//Assert
+		assert_eq!(Tokens::accounts(ALICE, DAI).frozen, 75 * ONE);
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(
+			DAI,
+			&ALICE,
+			&BOB,
+			25 * ONE
+		));
+		assert_noop!(
+			<Tokens as MultiCurrency<AccountId>>::transfer(DAI, &ALICE, &BOB, ONE),
+			orml_tokens::Error::<Test>::LiquidityRestrictions
+		);
+
+		expect_events(vec![Event::Claimed {
+			who: ALICE,
+			asset_id: DAI,
+			locked: 75 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn claim_should_remove_lock_and_schedules_when_vesting_is_finished() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_linear_schedule(100 * ONE);
+		set_block_number(101);
+
+		//Act
+		assert_ok!(AssetVesting::claim(RuntimeOrigin::signed(ALICE), DAI));
+
+		//Assert
+		assert!(!VestingSchedules::<Test>::contains_key(ALICE, DAI));
+		assert!(Tokens::locks(ALICE, DAI).is_empty());
+		assert_eq!(AssetVesting::locked_balance(&ALICE, DAI), 0);
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(
+			DAI,
+			&ALICE,
+			&BOB,
+			100 * ONE
+		));
+	});
+}
+
+#[test]
+fn claim_should_remove_only_finished_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(AssetVesting::vested_transfer(
+			RuntimeOrigin::signed(GRANTOR),
+			ALICE,
+			DAI,
+			1,
+			VestingKind::Cliff { duration: 10 },
+			10 * ONE,
+		));
+		add_linear_schedule(100 * ONE);
+		set_block_number(11);
+
+		//Act
+		assert_ok!(AssetVesting::claim(RuntimeOrigin::signed(ALICE), DAI));
+
+		//Assert
+		assert_eq!(AssetVesting::vesting_schedules(ALICE, DAI).len(), 1);
+		assert_eq!(Tokens::accounts(ALICE, DAI).frozen, 90 * ONE);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_account_has_no_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetVesting::claim(RuntimeOrigin::signed(ALICE), DAI),
+			Error::<Test>::NotVesting
+		);
+	});
+}
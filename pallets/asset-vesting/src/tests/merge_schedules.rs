@@ -0,0 +1,139 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::{Error, Event, VestingKind, VestingSchedule};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use pretty_assertions::assert_eq;
+
+fn add_schedule(grantor: AccountId, start: u64, kind: VestingKind<u64>, total: Balance) {
+	assert_ok!(AssetVesting::vested_transfer(
+		RuntimeOrigin::signed(grantor),
+		ALICE,
+		DAI,
+		start,
+		kind,
+		total,
+	));
+}
+
+#[test]
+fn merge_schedules_should_create_linear_schedule_from_locked_amounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_schedule(GRANTOR, 1, VestingKind::Linear { duration: 100 }, 100 * ONE);
+		add_schedule(GRANTOR, 1, VestingKind::Cliff { duration: 200 }, 50 * ONE);
+		set_block_number(51);
+
+		//Act
+		assert_ok!(AssetVesting::merge_schedules(
+			RuntimeOrigin::signed(GRANTOR),
+			ALICE,
+			DAI,
+			0,
+			1
+		));
+
+		//Assert
+		let merged = VestingSchedule {
+			grantor: GRANTOR,
+			start: 51,
+			kind: VestingKind::Linear { duration: 150 },
+			total: 100 * ONE,
+		};
+		assert_eq!(
+			AssetVesting::vesting_schedules(ALICE, DAI).into_inner(),
+			vec![merged.clone()]
+		);
+		assert_eq!(Tokens::accounts(ALICE, DAI).frozen, 100 * ONE);
+		assert_eq!(AssetVesting::locked_balance(&ALICE, DAI), 100 * ONE);
+
+		expect_events(vec![Event::VestingSchedulesMerged {
+			who: ALICE,
+			asset_id: DAI,
+			schedule: merged,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn merge_schedules_should_keep_other_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_schedule(OTHER_GRANTOR, 1, VestingKind::Cliff { duration: 10 }, 10 * ONE);
+		add_schedule(GRANTOR, 1, VestingKind::Linear { duration: 100 }, 100 * ONE);
+		add_schedule(GRANTOR, 1, VestingKind::Linear { duration: 100 }, 100 * ONE);
+
+		//Act
+		assert_ok!(AssetVesting::merge_schedules(
+			RuntimeOrigin::signed(GRANTOR),
+			ALICE,
+			DAI,
+			2,
+			1
+		));
+
+		//Assert
+		let schedules = AssetVesting::vesting_schedules(ALICE, DAI);
+		assert_eq!(schedules.len(), 2);
+		assert_eq!(schedules[0].grantor, OTHER_GRANTOR);
+		assert_eq!(schedules[1].total, 200 * ONE);
+		assert_eq!(Tokens::accounts(ALICE, DAI).frozen, 210 * ONE);
+	});
+}
+
+#[test]
+fn merge_schedules_should_fail_when_schedule_belongs_to_other_grantor() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_schedule(GRANTOR, 1, VestingKind::Linear { duration: 100 }, 100 * ONE);
+		add_schedule(OTHER_GRANTOR, 1, VestingKind::Linear { duration: 100 }, 100 * ONE);
+
+		//Act & assert
+		assert_noop!(
+			AssetVesting::merge_schedules(RuntimeOrigin::signed(GRANTOR), ALICE, DAI, 0, 1),
+			Error::<Test>::NotGrantor
+		);
+	});
+}
+
+#[test]
+fn merge_schedules_should_fail_when_schedule_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		add_schedule(GRANTOR, 1, VestingKind::Linear { duration: 100 }, 100 * ONE);
+
+		//Act & assert
+		assert_noop!(
+			AssetVesting::merge_schedules(RuntimeOrigin::signed(GRANTOR), ALICE, DAI, 0, 1),
+			Error::<Test>::ScheduleNotFound
+		);
+		assert_noop!(
+			AssetVesting::merge_schedules(RuntimeOrigin::signed(GRANTOR), ALICE, DAI, 0, 0),
+			Error::<Test>::SameSchedule
+		);
+	});
+}
+
+#[test]
+fn merge_schedules_should_fail_when_origin_is_not_grantor_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetVesting::merge_schedules(RuntimeOrigin::none(), ALICE, DAI, 0, 1),
+			BadOrigin
+		);
+	});
+}
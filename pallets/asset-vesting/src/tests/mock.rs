@@ -0,0 +1,174 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as asset_vesting;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, ConstU64, Everything, Nothing},
+};
+use frame_system::EnsureSigned;
+use orml_tokens::AccountData;
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const GRANTOR: AccountId = 100;
+pub const OTHER_GRANTOR: AccountId = 101;
+
+pub const GRANTOR_INITIAL_BALANCE: Balance = 1_000_000 * ONE;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		AssetVesting: asset_vesting,
+	}
+);
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		ONE / 100
+	};
+}
+
+parameter_types! {
+	pub const MaxVestingSchedules: u32 = 3;
+}
+
+impl asset_vesting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type GrantorOrigin = EnsureSigned<AccountId>;
+	type BlockNumberProvider = System;
+	type MaxVestingSchedules = MaxVestingSchedules;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ConstU32<10>;
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type CurrencyHooks = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::types::BenchmarkHelper<AssetId> for MockBenchmarkHelper {
+	fn register_asset() -> Result<AssetId, sp_runtime::DispatchError> {
+		Ok(DAI)
+	}
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(GRANTOR, HDX, GRANTOR_INITIAL_BALANCE),
+				(GRANTOR, DAI, GRANTOR_INITIAL_BALANCE),
+				(OTHER_GRANTOR, DAI, GRANTOR_INITIAL_BALANCE),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| System::set_block_number(1));
+		r
+	}
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
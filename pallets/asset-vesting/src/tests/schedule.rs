@@ -0,0 +1,96 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{VestingKind, VestingSchedule};
+use pretty_assertions::assert_eq;
+
+const TOTAL: u128 = 1_000;
+
+fn schedule(start: u64, kind: VestingKind<u64>) -> VestingSchedule<u64, u64> {
+	VestingSchedule {
+		grantor: 1,
+		start,
+		kind,
+		total: TOTAL,
+	}
+}
+
+#[test]
+fn cliff_schedule_should_release_whole_amount_at_end() {
+	let s = schedule(10, VestingKind::Cliff { duration: 100 });
+
+	assert_eq!(s.end(), Some(110));
+	assert_eq!(s.locked_at(0), TOTAL);
+	assert_eq!(s.locked_at(109), TOTAL);
+	assert_eq!(s.locked_at(110), 0);
+}
+
+#[test]
+fn linear_schedule_should_release_amount_proportionally() {
+	let s = schedule(10, VestingKind::Linear { duration: 100 });
+
+	assert_eq!(s.end(), Some(110));
+	assert_eq!(s.locked_at(10), TOTAL);
+	assert_eq!(s.locked_at(35), 750);
+	assert_eq!(s.locked_at(60), 500);
+	assert_eq!(s.locked_at(110), 0);
+	assert_eq!(s.locked_at(1_000), 0);
+}
+
+#[test]
+fn step_schedule_should_release_amount_in_steps() {
+	let s = schedule(
+		10,
+		VestingKind::Step {
+			period: 10,
+			period_count: 4,
+		},
+	);
+
+	assert_eq!(s.end(), Some(50));
+	assert_eq!(s.locked_at(19), TOTAL);
+	assert_eq!(s.locked_at(20), 750);
+	assert_eq!(s.locked_at(39), 500);
+	assert_eq!(s.locked_at(50), 0);
+}
+
+#[test]
+fn schedule_should_be_invalid_when_it_has_no_duration_or_amount() {
+	assert!(schedule(0, VestingKind::Linear { duration: 1 }).is_valid());
+	assert!(!schedule(0, VestingKind::Linear { duration: 0 }).is_valid());
+	assert!(!schedule(0, VestingKind::Cliff { duration: 0 }).is_valid());
+	assert!(!schedule(
+		0,
+		VestingKind::Step {
+			period: 0,
+			period_count: 1
+		}
+	)
+	.is_valid());
+	assert!(!schedule(
+		0,
+		VestingKind::Step {
+			period: 1,
+			period_count: 0
+		}
+	)
+	.is_valid());
+	assert!(!schedule(u64::MAX, VestingKind::Linear { duration: 1 }).is_valid());
+	assert!(!VestingSchedule {
+		total: 0,
+		..schedule(0, VestingKind::Linear { duration: 1 })
+	}
+	.is_valid());
+}
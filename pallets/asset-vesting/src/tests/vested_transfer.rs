@@ -0,0 +1,203 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::{Error, Event, VestingKind, VestingSchedule, VestingSchedules, VESTING_LOCK_ID};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn vested_transfer_should_transfer_and_lock_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(AssetVesting::vested_transfer(
+			RuntimeOrigin::signed(GRANTOR),
+			ALICE,
+			DAI,
+			1,
+			VestingKind::Linear { duration: 100 },
+			100 * ONE,
+		));
+
+		//Assert
+		let schedule = VestingSchedule {
+			grantor: GRANTOR,
+			start: 1,
+			kind: VestingKind::Linear { duration: 100 },
+			total: 100 * ONE,
+		};
+		assert_eq!(
+			AssetVesting::vesting_schedules(ALICE, DAI).into_inner(),
+			vec![schedule.clone()]
+		);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 100 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &GRANTOR), GRANTOR_INITIAL_BALANCE - 100 * ONE);
+		assert_eq!(AssetVesting::locked_balance(&ALICE, DAI), 100 * ONE);
+		assert_noop!(
+			<Tokens as MultiCurrency<AccountId>>::transfer(DAI, &ALICE, &BOB, ONE),
+			orml_tokens::Error::<Test>::LiquidityRestrictions
+		);
+
+		expect_events(vec![Event::VestingScheduleAdded {
+			who: ALICE,
+			asset_id: DAI,
+			schedule,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn vested_transfer_should_lock_sum_of_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(AssetVesting::vested_transfer(
+			RuntimeOrigin::signed(GRANTOR),
+			ALICE,
+			DAI,
+			1,
+			VestingKind::Cliff { duration: 100 },
+			100 * ONE,
+		));
+		assert_ok!(AssetVesting::vested_transfer(
+			RuntimeOrigin::signed(OTHER_GRANTOR),
+			ALICE,
+			DAI,
+			1,
+			VestingKind::Step {
+				period: 10,
+				period_count: 5
+			},
+			50 * ONE,
+		));
+		assert_ok!(AssetVesting::vested_transfer(
+			RuntimeOrigin::signed(GRANTOR),
+			ALICE,
+			HDX,
+			1,
+			VestingKind::Linear { duration: 100 },
+			10 * ONE,
+		));
+
+		//Assert
+		assert_eq!(AssetVesting::vesting_schedules(ALICE, DAI).len(), 2);
+		assert_eq!(AssetVesting::locked_balance(&ALICE, DAI), 150 * ONE);
+		assert_eq!(AssetVesting::locked_balance(&ALICE, HDX), 10 * ONE);
+		assert_eq!(AssetVesting::schedules_of(&ALICE).len(), 2);
+		assert_eq!(Tokens::accounts(ALICE, DAI).frozen, 150 * ONE);
+		assert_eq!(Tokens::locks(ALICE, DAI).len(), 1);
+		assert_eq!(Tokens::locks(ALICE, DAI)[0].id, VESTING_LOCK_ID);
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail_when_schedule_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(GRANTOR),
+				ALICE,
+				DAI,
+				1,
+				VestingKind::Linear { duration: 0 },
+				100 * ONE,
+			),
+			Error::<Test>::InvalidSchedule
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail_when_amount_is_below_existential_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(GRANTOR),
+				ALICE,
+				DAI,
+				1,
+				VestingKind::Linear { duration: 100 },
+				ONE / 1_000,
+			),
+			Error::<Test>::AmountLow
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail_when_max_schedules_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		for _ in 0..MaxVestingSchedules::get() {
+			assert_ok!(AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(GRANTOR),
+				ALICE,
+				DAI,
+				1,
+				VestingKind::Linear { duration: 100 },
+				ONE,
+			));
+		}
+
+		//Act & assert
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(GRANTOR),
+				ALICE,
+				DAI,
+				1,
+				VestingKind::Linear { duration: 100 },
+				ONE,
+			),
+			Error::<Test>::TooManySchedules
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail_when_grantor_has_not_enough_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				DAI,
+				1,
+				VestingKind::Linear { duration: 100 },
+				ONE,
+			),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+		assert!(!VestingSchedules::<Test>::contains_key(ALICE, DAI));
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail_when_origin_is_not_grantor_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::none(),
+				ALICE,
+				DAI,
+				1,
+				VestingKind::Linear { duration: 100 },
+				ONE,
+			),
+			BadOrigin
+		);
+	});
+}
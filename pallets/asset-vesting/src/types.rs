@@ -0,0 +1,111 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedInto, Zero};
+use sp_runtime::{Rounding, RuntimeDebug};
+
+pub type Balance = u128;
+
+/// The way how vested amount is released.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum VestingKind<BlockNumber> {
+	/// Whole amount is released at once `duration` blocks after start.
+	Cliff { duration: BlockNumber },
+	/// Amount is released linearly during `duration` blocks after start.
+	Linear { duration: BlockNumber },
+	/// Amount is released in `period_count` equal steps, one every `period` blocks after start.
+	Step { period: BlockNumber, period_count: u32 },
+}
+
+/// Vesting schedule of an asset.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<AccountId, BlockNumber> {
+	/// Account which funded the schedule and receives unvested amount if schedule is cancelled.
+	pub grantor: AccountId,
+	/// Block from which vesting starts.
+	pub start: BlockNumber,
+	/// The way how vested amount is released.
+	pub kind: VestingKind<BlockNumber>,
+	/// Total vested amount.
+	pub total: Balance,
+}
+
+impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> VestingSchedule<AccountId, BlockNumber> {
+	/// Block at which whole amount is released. `None` if it overflows.
+	pub fn end(&self) -> Option<BlockNumber> {
+		let duration = match self.kind {
+			VestingKind::Cliff { duration } | VestingKind::Linear { duration } => duration,
+			VestingKind::Step { period, period_count } => period.checked_mul(&period_count.into())?,
+		};
+		self.start.checked_add(&duration)
+	}
+
+	/// Schedule is valid if it releases non-zero amount in non-zero time and its end doesn't overflow.
+	pub fn is_valid(&self) -> bool {
+		let has_duration = match self.kind {
+			VestingKind::Cliff { duration } | VestingKind::Linear { duration } => !duration.is_zero(),
+			VestingKind::Step { period, period_count } => !period.is_zero() && period_count > 0,
+		};
+		has_duration && !self.total.is_zero() && self.end().is_some()
+	}
+
+	/// Amount which is still locked at block `now`.
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		let elapsed = now.saturating_sub(self.start);
+
+		let released = match self.kind {
+			VestingKind::Cliff { duration } => {
+				if elapsed >= duration {
+					self.total
+				} else {
+					Zero::zero()
+				}
+			}
+			VestingKind::Linear { duration } => {
+				if elapsed >= duration {
+					self.total
+				} else {
+					multiply_by_rational_with_rounding(
+						self.total,
+						elapsed.unique_saturated_into(),
+						duration.unique_saturated_into(),
+						Rounding::Down,
+					)
+					.unwrap_or_else(Zero::zero)
+				}
+			}
+			VestingKind::Step { period, period_count } => {
+				let steps: u128 = elapsed
+					.checked_div(&period)
+					.unwrap_or_else(Zero::zero)
+					.unique_saturated_into();
+				let steps = steps.min(period_count as u128);
+				multiply_by_rational_with_rounding(self.total, steps, period_count as u128, Rounding::Down)
+					.unwrap_or_else(Zero::zero)
+			}
+		};
+
+		self.total.saturating_sub(released)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Registers asset which can be vested.
+	fn register_asset() -> Result<AssetId, sp_runtime::DispatchError>;
+}
@@ -0,0 +1,81 @@
+//! Placeholder weights for `pallet_asset_vesting`.
+//!
+//! These values were not produced by a benchmark run and need to be regenerated
+//! with the benchmark CLI before the pallet is used in production.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_asset_vesting.
+pub trait WeightInfo {
+	fn vested_transfer() -> Weight;
+	fn claim() -> Weight;
+	fn merge_schedules() -> Weight;
+	fn cancel_schedule() -> Weight;
+}
+
+/// Weights for pallet_asset_vesting using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AssetVesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `AssetVesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3119), added: 5594, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	fn vested_transfer() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(97_416_000, 6584)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AssetVesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `AssetVesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3119), added: 5594, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn claim() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(61_892_000, 6584)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetVesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `AssetVesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3119), added: 5594, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn merge_schedules() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(65_240_000, 6584)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetVesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `AssetVesting::VestingSchedules` (`max_values`: None, `max_size`: Some(3119), added: 5594, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_schedule() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(93_711_000, 6584)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
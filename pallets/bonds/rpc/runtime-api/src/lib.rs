@@ -0,0 +1,37 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the bonds pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use sp_arithmetic::Permill;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query early redemption of bonds.
+	pub trait BondsApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Returns current early redemption price of the bond as a fraction of the par value.
+		/// `None` if early redemption is not available for the bond.
+		fn early_redemption_price(bond_id: AssetId) -> Option<Permill>;
+
+		/// Returns the amount of the underlying asset received for early redemption of `amount` of the bonds.
+		/// `None` if early redemption is not available for the bond.
+		fn early_redemption_amount(bond_id: AssetId, amount: Balance) -> Option<Balance>;
+	}
+}
@@ -20,7 +20,7 @@
 use super::*;
 
 use frame_benchmarking::benchmarks;
-use frame_support::{assert_ok, traits::EnsureOrigin};
+use frame_support::{assert_ok, traits::EnsureOrigin, BoundedVec};
 use frame_system::RawOrigin;
 
 use orml_traits::MultiCurrency;
@@ -30,6 +30,19 @@ pub const NOW: Moment = 1689844300000; // unix time in milliseconds
 pub const ONE: Balance = 1_000_000_000_000;
 pub const HDX: AssetId = 0;
 
+fn callable_terms() -> Terms {
+	Terms {
+		coupon: Some(Coupon {
+			rate: Permill::from_percent(1),
+			period: MONTH,
+		}),
+		call: Some(CallTerms {
+			from: NOW + MONTH,
+			premium: Permill::from_percent(2),
+		}),
+	}
+}
+
 benchmarks! {
 	 where_clause {
 		where
@@ -79,5 +92,126 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(bond_id, &issuer), 0u32.into());
 	}
 
+	set_early_redemption {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+		let maturity = NOW + MONTH;
+		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer).into(), HDX, amount, maturity));
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+
+		let curve: BoundedVec<DiscountPoint, T::MaxDiscountPoints> = (0..T::MaxDiscountPoints::get())
+			.map(|i| DiscountPoint { moment: NOW + i as Moment, discount: Permill::from_percent(10) })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let set_origin = T::EarlyRedemptionOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(set_origin, bond_id, Some(curve.clone()))
+	verify {
+		assert_eq!(EarlyRedemptionCurves::<T>::get(bond_id), Some(curve));
+	}
+
+	redeem_early {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		//NOTE: bonds are insufficient so issuer must ED for it
+		T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
+		T::Currency::deposit(HDX, &T::FeeReceiver::get(), (100 * ONE).into())?;
+
+		let maturity = NOW + MONTH;
+		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, maturity));
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+
+		let fee = <T as Config>::ProtocolFee::get().mul_ceil(amount);
+		let amount_without_fee: T::Balance = amount.checked_sub(&fee).unwrap();
+
+		let curve: BoundedVec<DiscountPoint, T::MaxDiscountPoints> = (0..T::MaxDiscountPoints::get())
+			.map(|i| DiscountPoint { moment: NOW + i as Moment, discount: Permill::from_percent(10) })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let set_origin = T::EarlyRedemptionOrigin::try_successful_origin().unwrap();
+		assert_ok!(crate::Pallet::<T>::set_early_redemption(set_origin, bond_id, Some(curve)));
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + MONTH / 2).into());
+	}: _(RawOrigin::Signed(issuer.clone()), bond_id, amount_without_fee)
+	verify {
+		assert_eq!(T::Currency::free_balance(bond_id, &issuer), 0u32.into());
+	}
+
+	issue_with_terms {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + 6 * MONTH;
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+
+		let terms = callable_terms();
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), maturity, terms)
+	verify {
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+		assert!(BondTerms::<T>::get(bond_id).is_some());
+	}
+
+	fund_coupons {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
+
+		assert_ok!(crate::Pallet::<T>::issue_with_terms(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, NOW + 6 * MONTH, callable_terms()));
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+	}: _(RawOrigin::Signed(issuer), bond_id, (10 * ONE).into())
+	verify {
+		assert_eq!(CouponReserves::<T>::get(bond_id), (10 * ONE).into());
+	}
+
+	claim_coupons {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
+
+		assert_ok!(crate::Pallet::<T>::issue_with_terms(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, NOW + 6 * MONTH, callable_terms()));
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+		assert_ok!(crate::Pallet::<T>::fund_coupons(RawOrigin::Signed(issuer.clone()).into(), bond_id, (50 * ONE).into()));
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + 3 * MONTH).into());
+	}: _(RawOrigin::Signed(issuer.clone()), bond_id)
+	verify {
+		assert!(CouponAccounts::<T>::get(bond_id, &issuer).accrued.is_zero());
+	}
+
+	call_bond {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
+
+		assert_ok!(crate::Pallet::<T>::issue_with_terms(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, NOW + 6 * MONTH, callable_terms()));
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + 3 * MONTH).into());
+	}: _(RawOrigin::Signed(issuer), bond_id)
+	verify {
+		assert!(CalledBonds::<T>::get(bond_id).is_some());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -38,30 +38,56 @@
 //! * Bonds can be both partially or fully redeemed.
 //! * The amount of the underlying asset an account receives is 1:1 to the `amount` of the bonds redeemed.
 //! * Anyone who holds the bonds is able to redeem them.
+//!
+//! ## Early redemption of bonds
+//! * `T::EarlyRedemptionOrigin` can enable early redemption of bonds by setting a discount curve for the bond.
+//! * The discount curve is a list of points (moment, discount) sorted by moment. The discount is linearly interpolated
+//!   between the points and from the last point to zero discount (par) at maturity.
+//! * Bonds can be redeemed early once the first point of the curve is reached.
+//! * Holder receives the amount of the underlying asset minus the current discount.
+//!   The discount is transferred to the fee receiver.
+//!
+//! ## Interest-bearing and callable bonds
+//! * Bonds with terms are issued by `issue_with_terms` and are never merged with other bonds.
+//! * Terms of the bonds are stored in `BondTerms`. The bond asset is registered with a name
+//!   unique to each issuance, see `terms_bond_name`.
+//! * Terms are not stored in the asset registry metadata, `BondTerms` is the only source of them.
+//! * Coupon bonds pay `rate` of the par value each coupon `period` since the issuance until maturity.
+//! * Coupons are paid from the coupon reserve of the bond, which can be funded by anyone.
+//! * Coupons accrue to the holders on every change of their balance, see `AccrueCoupons`
+//!   which has to be used as a currency hook. Only balances of `AssetKind::Bond` assets are processed.
+//! * Callable bonds can be called by the issuer once the call period starts. The issuer pays
+//!   the call premium for all outstanding bonds, coupons stop accruing and the bonds can be redeemed
+//!   immediately for the par value plus the premium.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::manual_inspect)]
 
+use codec::{Decode, Encode};
 use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchResult, Get},
 	sp_runtime::{
-		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub},
-		DispatchError, Permill, Saturating,
+		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Zero},
+		DispatchError, Permill, RuntimeDebug, Saturating,
 	},
 	traits::{Contains, Time},
 	PalletId,
 };
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use sp_core::MaxEncodedLen;
-use sp_std::{mem, vec::Vec};
+use sp_std::{marker::PhantomData, mem, vec::Vec};
 
 use hydradx_traits::{
 	registry::{Create, Inspect},
 	AssetKind,
 };
-use orml_traits::{GetByKey, MultiCurrency};
+use orml_traits::{
+	currency::{OnDeposit, OnTransfer},
+	GetByKey, MultiCurrency,
+};
 use primitives::{AssetId, Moment};
+use scale_info::TypeInfo;
 
 #[cfg(test)]
 mod tests;
@@ -74,6 +100,59 @@ pub mod weights;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Point of an early redemption discount curve.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct DiscountPoint {
+	/// Unix time in milliseconds.
+	pub moment: Moment,
+	/// Discount from the par value at the `moment`.
+	pub discount: Permill,
+}
+
+/// Coupon terms of interest-bearing bonds.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Coupon {
+	/// Coupon paid each period as a fraction of the par value.
+	pub rate: Permill,
+	/// Length of the coupon period in milliseconds.
+	pub period: Moment,
+}
+
+/// Call terms of callable bonds.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CallTerms {
+	/// Unix time in milliseconds since when the bonds can be called.
+	pub from: Moment,
+	/// Premium paid on top of the par value when the bonds are called.
+	pub premium: Permill,
+}
+
+/// Terms of interest-bearing and callable bonds.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Terms {
+	pub coupon: Option<Coupon>,
+	pub call: Option<CallTerms>,
+}
+
+/// Terms of issued bonds.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct BondInfo<AccountId> {
+	/// Account which issued the bonds and is allowed to call them.
+	pub issuer: AccountId,
+	/// Unix time in milliseconds when the bonds were issued. Coupon periods start at this moment.
+	pub issued_at: Moment,
+	pub terms: Terms,
+}
+
+/// Coupons of a bond holder.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CouponAccount<Balance> {
+	/// Number of coupon periods already accrued.
+	pub periods: u32,
+	/// Accrued coupons not claimed yet.
+	pub accrued: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -127,9 +206,17 @@ pub mod pallet {
 		type ProtocolFee: Get<Permill>;
 
 		/// Protocol fee receiver.
+		/// Receives also the discount of early redeemed bonds.
 		#[pallet::constant]
 		type FeeReceiver: Get<Self::AccountId>;
 
+		/// The origin which can set early redemption discount curves.
+		type EarlyRedemptionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of points of an early redemption discount curve.
+		#[pallet::constant]
+		type MaxDiscountPoints: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -146,6 +233,51 @@ pub mod pallet {
 	#[pallet::getter(fn bond)]
 	pub(super) type Bonds<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, (AssetId, Moment)>;
 
+	#[pallet::storage]
+	/// Early redemption discount curves.
+	/// Maps bond ID -> discount points sorted by moment
+	#[pallet::getter(fn early_redemption_curve)]
+	pub(super) type EarlyRedemptionCurves<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, BoundedVec<DiscountPoint, T::MaxDiscountPoints>>;
+
+	#[pallet::storage]
+	/// Terms of interest-bearing and callable bonds.
+	/// Maps bond ID -> bond info
+	#[pallet::getter(fn bond_terms)]
+	pub(super) type BondTerms<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, BondInfo<T::AccountId>>;
+
+	#[pallet::storage]
+	/// Number of bonds issued with terms.
+	/// Used to derive unique names of the bond assets.
+	#[pallet::getter(fn terms_bond_nonce)]
+	pub(super) type TermsBondNonce<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// Amount of the underlying asset reserved for coupon payments.
+	/// Maps bond ID -> reserve
+	#[pallet::getter(fn coupon_reserve)]
+	pub(super) type CouponReserves<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::Balance, ValueQuery>;
+
+	#[pallet::storage]
+	/// Coupons of bond holders.
+	/// Maps (bond ID, account) -> coupon account
+	#[pallet::getter(fn coupon_account)]
+	pub(super) type CouponAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		CouponAccount<T::Balance>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Called bonds.
+	/// Maps bond ID -> moment when the bonds were called
+	#[pallet::getter(fn called_at)]
+	pub(super) type CalledBonds<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, Moment>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -169,6 +301,39 @@ pub mod pallet {
 			bond_id: AssetId,
 			amount: T::Balance,
 		},
+		/// Early redemption discount curve was set or removed
+		EarlyRedemptionSet {
+			bond_id: AssetId,
+			curve: Option<BoundedVec<DiscountPoint, T::MaxDiscountPoints>>,
+		},
+		/// Bonds were redeemed before maturity
+		RedeemedEarly {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			paid: T::Balance,
+			discount: T::Balance,
+		},
+		/// Bonds with terms were registered
+		TermsSet { bond_id: AssetId, terms: Terms },
+		/// Coupon reserve of bonds was funded
+		CouponsFunded {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Accrued coupons were claimed
+		CouponsClaimed {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Bonds were called by the issuer
+		Called {
+			issuer: T::AccountId,
+			bond_id: AssetId,
+			premium: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -188,6 +353,26 @@ pub mod pallet {
 		InvalidBondName,
 		/// Bond's name parsing was now successful
 		FailToParseName,
+		/// Bond is already mature
+		AlreadyMature,
+		/// Discount curve is empty, not sorted by moment or does not end before maturity
+		InvalidDiscountCurve,
+		/// Early redemption is not enabled for the bond or has not started yet
+		EarlyRedemptionNotAvailable,
+		/// Terms have neither coupon nor call, coupon rate or period is zero or call starts at maturity
+		InvalidTerms,
+		/// Bonds don't pay coupons
+		NotCouponBond,
+		/// Bonds are not callable or the call period has not started yet
+		NotCallable,
+		/// Bonds were already called
+		AlreadyCalled,
+		/// Origin is not the issuer of the bonds
+		NotIssuer,
+		/// No accrued coupons to claim
+		NoCoupons,
+		/// Coupon reserve is not sufficient to pay the coupons
+		InsufficientCouponReserve,
 	}
 
 	#[pallet::call]
@@ -280,6 +465,8 @@ pub mod pallet {
 		/// The amount of the underlying asset the `origin` receives is 1:1 to the `amount` of the bonds.
 		/// Anyone who holds the bonds is able to redeem them.
 		/// Bonds can be both partially or fully redeemed.
+		/// Called bonds can be redeemed before maturity and pay the call premium on top of the par value.
+		/// Accrued coupons remain claimable after redemption.
 		///
 		/// Parameters:
 		/// - `origin`: account id
@@ -296,17 +483,334 @@ pub mod pallet {
 			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
 
 			let now = T::TimestampProvider::now();
-			ensure!(now >= maturity, Error::<T>::NotMature);
+			let called = CalledBonds::<T>::contains_key(bond_id);
+			ensure!(now >= maturity || called, Error::<T>::NotMature);
+
+			let paid = match Self::bond_terms(bond_id) {
+				Some(info) => {
+					Self::accrue_coupons(bond_id, &info, &who);
+					match info.terms.call {
+						Some(call) if called => amount.saturating_add(call.premium.mul_floor(amount)),
+						_ => amount,
+					}
+				}
+				None => amount,
+			};
 
 			T::Currency::withdraw(bond_id, &who, amount)?;
 
 			let pallet_account = Self::pallet_account_id();
-			T::Currency::transfer(underlying_asset_id, &pallet_account, &who, amount)?;
+			T::Currency::transfer(underlying_asset_id, &pallet_account, &who, paid)?;
 
 			Self::deposit_event(Event::Redeemed { who, bond_id, amount });
 
 			Ok(())
 		}
+
+		/// Set or remove early redemption discount curve of bonds.
+		/// The discount is linearly interpolated between the points of the curve and from the last point
+		/// to zero discount at maturity.
+		///
+		/// Parameters:
+		/// - `origin`: needs to be `T::EarlyRedemptionOrigin`
+		/// - `bond_id`: bond asset id
+		/// - `curve`: discount points sorted by moment. The last point must be before maturity.
+		///    `None` disables early redemption.
+		///
+		/// Emits `EarlyRedemptionSet` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_early_redemption())]
+		pub fn set_early_redemption(
+			origin: OriginFor<T>,
+			bond_id: AssetId,
+			curve: Option<BoundedVec<DiscountPoint, T::MaxDiscountPoints>>,
+		) -> DispatchResult {
+			T::EarlyRedemptionOrigin::ensure_origin(origin)?;
+
+			let (_, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+
+			match curve.clone() {
+				Some(curve) => {
+					ensure!(T::TimestampProvider::now() < maturity, Error::<T>::AlreadyMature);
+					ensure!(
+						curve.windows(2).all(|w| w[0].moment < w[1].moment)
+							&& curve.last().is_some_and(|point| point.moment < maturity),
+						Error::<T>::InvalidDiscountCurve
+					);
+					EarlyRedemptionCurves::<T>::insert(bond_id, curve);
+				}
+				None => EarlyRedemptionCurves::<T>::remove(bond_id),
+			}
+
+			Self::deposit_event(Event::EarlyRedemptionSet { bond_id, curve });
+
+			Ok(())
+		}
+
+		/// Redeem bonds for the underlying asset before maturity.
+		/// The amount of the underlying asset the `origin` receives is the `amount` of the bonds
+		/// minus the current discount given by the early redemption curve.
+		/// The discount is transferred to `T::FeeReceiver`.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		/// - `amount`: the amount of the bonds to redeem for the underlying asset
+		///
+		/// Emits `RedeemedEarly` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_early())]
+		pub fn redeem_early(origin: OriginFor<T>, bond_id: AssetId, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+
+			let now = T::TimestampProvider::now();
+			ensure!(now < maturity, Error::<T>::AlreadyMature);
+			ensure!(!CalledBonds::<T>::contains_key(bond_id), Error::<T>::AlreadyCalled);
+
+			if let Some(info) = Self::bond_terms(bond_id) {
+				Self::accrue_coupons(bond_id, &info, &who);
+			}
+
+			let discount = Self::early_redemption_discount(bond_id, now)
+				.ok_or(Error::<T>::EarlyRedemptionNotAvailable)?
+				.mul_ceil(amount);
+			let paid = amount.saturating_sub(discount);
+
+			T::Currency::withdraw(bond_id, &who, amount)?;
+
+			let pallet_account = Self::pallet_account_id();
+			T::Currency::transfer(underlying_asset_id, &pallet_account, &who, paid)?;
+			if !discount.is_zero() {
+				T::Currency::transfer(underlying_asset_id, &pallet_account, &T::FeeReceiver::get(), discount)?;
+			}
+
+			Self::deposit_event(Event::RedeemedEarly {
+				who,
+				bond_id,
+				amount,
+				paid,
+				discount,
+			});
+
+			Ok(())
+		}
+
+		/// Issue new interest-bearing or callable bonds.
+		/// New asset id is always registered for the bonds, bonds with terms are not merged with existing bonds.
+		/// The bond asset is registered with the name returned by `terms_bond_name`, which is unique
+		/// for each issuance. Terms of the bonds are stored in `BondTerms`.
+		/// Protocol fee is applied to the amount, and transferred to `T::FeeReceiver`.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		/// - `terms`: coupon and call terms of the bonds
+		///
+		/// Emits `TokenCreated`, `TermsSet` and `Issued` events when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_with_terms())]
+		pub fn issue_with_terms(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+			terms: Terms,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				T::AssetTypeWhitelist::contains(
+					&T::AssetRegistry::asset_type(asset_id).ok_or(Error::<T>::AssetNotFound)?
+				),
+				Error::<T>::DisallowedAsset
+			);
+
+			let now = T::TimestampProvider::now();
+			ensure!(maturity > now, Error::<T>::InvalidMaturity);
+			ensure!(
+				(terms.coupon.is_some() || terms.call.is_some())
+					&& terms
+						.coupon
+						.map_or(true, |coupon| !coupon.rate.is_zero() && !coupon.period.is_zero())
+					&& terms.call.map_or(true, |call| call.from < maturity),
+				Error::<T>::InvalidTerms
+			);
+
+			let fee = T::ProtocolFee::get().mul_ceil(amount);
+			let amount_without_fee = amount.saturating_sub(fee);
+
+			let nonce = TermsBondNonce::<T>::mutate(|nonce| {
+				let current = *nonce;
+				*nonce = nonce.wrapping_add(1);
+				current
+			});
+
+			let ed = T::ExistentialDeposits::get(&asset_id);
+			let bond_id = T::AssetRegistry::register_insufficient_asset(
+				None,
+				Some(
+					Self::terms_bond_name(asset_id, maturity, nonce)
+						.try_into()
+						.map_err(|_| Error::<T>::InvalidBondName)?,
+				),
+				AssetKind::Bond,
+				Some(ed),
+				None,
+				None,
+				None,
+				None,
+			)?;
+
+			Bonds::<T>::insert(bond_id, (asset_id, maturity));
+			BondTerms::<T>::insert(
+				bond_id,
+				BondInfo {
+					issuer: who.clone(),
+					issued_at: now,
+					terms,
+				},
+			);
+
+			Self::deposit_event(Event::TokenCreated {
+				issuer: who.clone(),
+				asset_id,
+				bond_id,
+				maturity,
+			});
+			Self::deposit_event(Event::TermsSet { bond_id, terms });
+
+			T::Currency::transfer(asset_id, &who, &Self::pallet_account_id(), amount_without_fee)?;
+			T::Currency::transfer(asset_id, &who, &T::FeeReceiver::get(), fee)?;
+			T::Currency::deposit(bond_id, &who, amount_without_fee)?;
+
+			Self::deposit_event(Event::Issued {
+				issuer: who,
+				bond_id,
+				amount: amount_without_fee,
+				fee,
+			});
+
+			Ok(())
+		}
+
+		/// Fund the coupon reserve of interest-bearing bonds with the underlying asset.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		/// - `amount`: the amount of the underlying asset
+		///
+		/// Emits `CouponsFunded` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::fund_coupons())]
+		pub fn fund_coupons(origin: OriginFor<T>, bond_id: AssetId, amount: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (underlying_asset_id, _) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(
+				Self::bond_terms(bond_id).is_some_and(|info| info.terms.coupon.is_some()),
+				Error::<T>::NotCouponBond
+			);
+
+			T::Currency::transfer(underlying_asset_id, &who, &Self::pallet_account_id(), amount)?;
+			CouponReserves::<T>::mutate(bond_id, |reserve| *reserve = reserve.saturating_add(amount));
+
+			Self::deposit_event(Event::CouponsFunded { who, bond_id, amount });
+
+			Ok(())
+		}
+
+		/// Claim accrued coupons of interest-bearing bonds.
+		/// Coupons are paid in the underlying asset from the coupon reserve of the bonds.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		///
+		/// Emits `CouponsClaimed` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_coupons())]
+		pub fn claim_coupons(origin: OriginFor<T>, bond_id: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (underlying_asset_id, _) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			let info = Self::bond_terms(bond_id).ok_or(Error::<T>::NotCouponBond)?;
+			ensure!(info.terms.coupon.is_some(), Error::<T>::NotCouponBond);
+
+			Self::accrue_coupons(bond_id, &info, &who);
+
+			let amount = CouponAccounts::<T>::get(bond_id, &who).accrued;
+			ensure!(!amount.is_zero(), Error::<T>::NoCoupons);
+
+			CouponReserves::<T>::try_mutate(bond_id, |reserve| -> DispatchResult {
+				*reserve = reserve
+					.checked_sub(&amount)
+					.ok_or(Error::<T>::InsufficientCouponReserve)?;
+				Ok(())
+			})?;
+
+			if T::Currency::free_balance(bond_id, &who).is_zero() {
+				CouponAccounts::<T>::remove(bond_id, &who);
+			} else {
+				CouponAccounts::<T>::mutate(bond_id, &who, |account| account.accrued = Zero::zero());
+			}
+
+			T::Currency::transfer(underlying_asset_id, &Self::pallet_account_id(), &who, amount)?;
+
+			Self::deposit_event(Event::CouponsClaimed { who, bond_id, amount });
+
+			Ok(())
+		}
+
+		/// Call callable bonds.
+		/// The issuer pays the call premium for all outstanding bonds. Coupons stop accruing
+		/// and the bonds can be redeemed immediately for the par value plus the premium.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of the bonds
+		/// - `bond_id`: bond asset id
+		///
+		/// Emits `Called` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::call_bond())]
+		pub fn call_bond(origin: OriginFor<T>, bond_id: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			let info = Self::bond_terms(bond_id).ok_or(Error::<T>::NotCallable)?;
+			ensure!(info.issuer == who, Error::<T>::NotIssuer);
+			ensure!(!CalledBonds::<T>::contains_key(bond_id), Error::<T>::AlreadyCalled);
+
+			let now = T::TimestampProvider::now();
+			ensure!(now < maturity, Error::<T>::AlreadyMature);
+			let call = info.terms.call.ok_or(Error::<T>::NotCallable)?;
+			ensure!(now >= call.from, Error::<T>::NotCallable);
+
+			let premium = call.premium.mul_ceil(T::Currency::total_issuance(bond_id));
+			if !premium.is_zero() {
+				T::Currency::transfer(underlying_asset_id, &who, &Self::pallet_account_id(), premium)?;
+			}
+
+			CalledBonds::<T>::insert(bond_id, now);
+
+			Self::deposit_event(Event::Called {
+				issuer: who,
+				bond_id,
+				premium,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -330,6 +834,114 @@ impl<T: Config> Pallet<T> {
 		buf
 	}
 
+	/// Return token name of bonds issued with terms.
+	/// The `nonce` makes the name unique, so bonds with the same underlying asset and maturity
+	/// can be issued multiple times. The name starts with `bond_name`.
+	pub fn terms_bond_name(asset_id: AssetId, when: Moment, nonce: u32) -> Vec<u8> {
+		let mut buf = Self::bond_name(asset_id, when);
+
+		buf.extend_from_slice(b".");
+		buf.extend_from_slice(&nonce.to_le_bytes());
+
+		buf
+	}
+
+	/// Returns early redemption discount of the bond at `now`.
+	///
+	/// Returns `None` if early redemption is not enabled for the bond, the bond is mature
+	/// or the first point of the discount curve has not been reached yet.
+	pub fn early_redemption_discount(bond_id: AssetId, now: Moment) -> Option<Permill> {
+		let (_, maturity) = Self::bond(bond_id)?;
+		if now >= maturity {
+			return None;
+		}
+		let curve = Self::early_redemption_curve(bond_id)?;
+		let idx = curve.iter().rposition(|point| point.moment <= now)?;
+		let from = curve[idx];
+		let (to_moment, to_discount) = curve
+			.get(idx.saturating_add(1))
+			.map_or((maturity, Permill::zero()), |point| (point.moment, point.discount));
+
+		let elapsed = now.saturating_sub(from.moment) as u128;
+		let period = to_moment.saturating_sub(from.moment) as u128;
+		let from_parts = from.discount.deconstruct() as u128;
+		let to_parts = to_discount.deconstruct() as u128;
+		let parts = if to_parts >= from_parts {
+			from_parts.saturating_add(to_parts.saturating_sub(from_parts).saturating_mul(elapsed) / period)
+		} else {
+			from_parts.saturating_sub(from_parts.saturating_sub(to_parts).saturating_mul(elapsed) / period)
+		};
+
+		Some(Permill::from_parts(parts as u32))
+	}
+
+	/// Returns current early redemption price of the bond as a fraction of the par value.
+	pub fn early_redemption_price(bond_id: AssetId) -> Option<Permill> {
+		Self::early_redemption_discount(bond_id, T::TimestampProvider::now()).map(|discount| Permill::one() - discount)
+	}
+
+	/// Returns the amount of the underlying asset received for early redemption of `amount` of the bonds now.
+	pub fn early_redemption_amount(bond_id: AssetId, amount: T::Balance) -> Option<T::Balance> {
+		Self::early_redemption_discount(bond_id, T::TimestampProvider::now())
+			.map(|discount| amount.saturating_sub(discount.mul_ceil(amount)))
+	}
+
+	/// Returns number of coupon periods elapsed at `now`.
+	/// Coupon periods end at maturity or when the bonds are called.
+	fn coupon_periods(bond_id: AssetId, info: &BondInfo<T::AccountId>, now: Moment) -> u32 {
+		let Some(coupon) = info.terms.coupon else {
+			return 0;
+		};
+		let Some((_, maturity)) = Self::bond(bond_id) else {
+			return 0;
+		};
+		let end = Self::called_at(bond_id).unwrap_or(maturity).min(maturity).min(now);
+		let periods = end.saturating_sub(info.issued_at) / coupon.period;
+
+		periods.try_into().unwrap_or(u32::MAX)
+	}
+
+	/// Accrues coupons of `who` for the elapsed coupon periods, based on the current balance of the bonds.
+	/// Has to be called before every change of the balance.
+	fn accrue_coupons(bond_id: AssetId, info: &BondInfo<T::AccountId>, who: &T::AccountId) {
+		let Some(coupon) = info.terms.coupon else {
+			return;
+		};
+		let periods = Self::coupon_periods(bond_id, info, T::TimestampProvider::now());
+
+		CouponAccounts::<T>::mutate(bond_id, who, |account| {
+			if periods <= account.periods {
+				return;
+			}
+			let balance = T::Currency::free_balance(bond_id, who);
+			let elapsed: T::Balance = u128::from(periods.saturating_sub(account.periods)).into();
+			account.accrued = account
+				.accrued
+				.saturating_add(coupon.rate.mul_floor(balance).saturating_mul(elapsed));
+			account.periods = periods;
+		});
+	}
+
+	/// Returns coupons of `who` claimable now, including coupons not accrued yet.
+	pub fn claimable_coupons(bond_id: AssetId, who: &T::AccountId) -> T::Balance {
+		let Some(info) = Self::bond_terms(bond_id) else {
+			return Zero::zero();
+		};
+		let Some(coupon) = info.terms.coupon else {
+			return Zero::zero();
+		};
+		let account = CouponAccounts::<T>::get(bond_id, who);
+		let periods = Self::coupon_periods(bond_id, &info, T::TimestampProvider::now());
+		let elapsed: T::Balance = u128::from(periods.saturating_sub(account.periods)).into();
+
+		account.accrued.saturating_add(
+			coupon
+				.rate
+				.mul_floor(T::Currency::free_balance(bond_id, who))
+				.saturating_mul(elapsed),
+		)
+	}
+
 	pub fn parse_bond_name(name: Vec<u8>) -> Result<AssetId, Error<T>> {
 		Ok(AssetId::from_le_bytes(
 			name[..mem::size_of::<AssetId>()]
@@ -338,3 +950,42 @@ impl<T: Config> Pallet<T> {
 		))
 	}
 }
+
+/// Currency hook accruing coupons of interest-bearing bonds before balances of bond holders change.
+/// Has to be used as `PreDeposit` and `PreTransfer` hook of the currency holding the bonds.
+///
+/// Terms of the bonds are read only for `AssetKind::Bond` assets. The asset type is read from
+/// the asset registry, which is accessed on every transfer already.
+pub struct AccrueCoupons<T>(PhantomData<T>);
+
+impl<T: Config> AccrueCoupons<T> {
+	fn accrue(bond_id: AssetId, accounts: &[&T::AccountId]) {
+		if T::AssetRegistry::asset_type(bond_id) != Some(AssetKind::Bond) {
+			return;
+		}
+		if let Some(info) = Pallet::<T>::bond_terms(bond_id) {
+			for who in accounts {
+				Pallet::<T>::accrue_coupons(bond_id, &info, who);
+			}
+		}
+	}
+}
+
+impl<T: Config> OnDeposit<T::AccountId, AssetId, T::Balance> for AccrueCoupons<T> {
+	fn on_deposit(currency_id: AssetId, who: &T::AccountId, _amount: T::Balance) -> DispatchResult {
+		Self::accrue(currency_id, &[who]);
+		Ok(())
+	}
+}
+
+impl<T: Config> OnTransfer<T::AccountId, AssetId, T::Balance> for AccrueCoupons<T> {
+	fn on_transfer(
+		currency_id: AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		_amount: T::Balance,
+	) -> DispatchResult {
+		Self::accrue(currency_id, &[from, to]);
+		Ok(())
+	}
+}
@@ -0,0 +1,454 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+fn coupon_terms() -> Terms {
+	Terms {
+		coupon: Some(Coupon {
+			rate: Permill::from_percent(1),
+			period: MONTH,
+		}),
+		call: None,
+	}
+}
+
+fn callable_terms() -> Terms {
+	Terms {
+		coupon: Some(Coupon {
+			rate: Permill::from_percent(1),
+			period: MONTH,
+		}),
+		call: Some(CallTerms {
+			from: NOW + 2 * MONTH,
+			premium: Permill::from_percent(2),
+		}),
+	}
+}
+
+#[test]
+fn issue_with_terms_should_register_new_bonds_with_terms() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + 6 * MONTH;
+		let bond_id = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			maturity,
+			coupon_terms()
+		));
+
+		// Assert
+		expect_events(vec![
+			Event::TokenCreated {
+				issuer: ALICE,
+				asset_id: HDX,
+				bond_id,
+				maturity,
+			}
+			.into(),
+			Event::TermsSet {
+				bond_id,
+				terms: coupon_terms(),
+			}
+			.into(),
+			Event::Issued {
+				issuer: ALICE,
+				bond_id,
+				amount: 100 * ONE,
+				fee: 0,
+			}
+			.into(),
+		]);
+
+		assert_eq!(Bonds::bond(bond_id), Some((HDX, maturity)));
+		assert_eq!(Bonds::bond_id((HDX, maturity)), None);
+		assert_eq!(
+			Bonds::bond_terms(bond_id),
+			Some(BondInfo {
+				issuer: ALICE,
+				issued_at: NOW,
+				terms: coupon_terms(),
+			})
+		);
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 100 * ONE);
+	});
+}
+
+#[test]
+fn issue_with_terms_should_always_register_new_bonds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + 6 * MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			ONE,
+			maturity,
+			coupon_terms()
+		));
+
+		// Act
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			ONE,
+			maturity,
+			coupon_terms()
+		));
+
+		// Assert
+		assert!(Bonds::bond(bond_id + 1).is_some());
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), ONE);
+		assert_eq!(Tokens::free_balance(bond_id + 1, &ALICE), ONE);
+	});
+}
+
+#[test]
+fn issue_with_terms_should_fail_when_terms_are_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let maturity = NOW + 6 * MONTH;
+
+		assert_noop!(
+			Bonds::issue_with_terms(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity, Terms::default()),
+			Error::<Test>::InvalidTerms
+		);
+		assert_noop!(
+			Bonds::issue_with_terms(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				ONE,
+				maturity,
+				Terms {
+					coupon: Some(Coupon {
+						rate: Permill::from_percent(1),
+						period: 0,
+					}),
+					call: None,
+				}
+			),
+			Error::<Test>::InvalidTerms
+		);
+		assert_noop!(
+			Bonds::issue_with_terms(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				ONE,
+				maturity,
+				Terms {
+					coupon: None,
+					call: Some(CallTerms {
+						from: maturity,
+						premium: Permill::from_percent(1),
+					}),
+				}
+			),
+			Error::<Test>::InvalidTerms
+		);
+	});
+}
+
+#[test]
+fn issue_with_terms_should_register_new_bonds_when_bonds_with_same_maturity_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + 6 * MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 100 * ONE, maturity));
+
+		// Act
+		let coupon_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			maturity,
+			coupon_terms()
+		));
+		let callable_bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			maturity,
+			callable_terms()
+		));
+
+		// Assert
+		assert_ne!(bond_id, coupon_bond_id);
+		assert_ne!(coupon_bond_id, callable_bond_id);
+		assert_eq!(Bonds::bond_terms(bond_id), None);
+		assert_eq!(Bonds::bond_terms(coupon_bond_id).unwrap().terms, coupon_terms());
+		assert_eq!(Bonds::bond_terms(callable_bond_id).unwrap().terms, callable_terms());
+		assert_eq!(Bonds::terms_bond_nonce(), 2);
+	});
+}
+
+#[test]
+fn terms_bond_name_should_be_unique_and_parsable() {
+	let name = Bonds::bond_name(HDX, NOW);
+	let first = Bonds::terms_bond_name(HDX, NOW, 0);
+	let second = Bonds::terms_bond_name(HDX, NOW, 1);
+
+	assert_ne!(name, first);
+	assert_ne!(first, second);
+	assert!(first.starts_with(&name));
+	assert_eq!(Bonds::parse_bond_name(first), Ok(HDX));
+}
+
+#[test]
+fn claim_coupons_should_pay_elapsed_coupon_periods() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + 6 * MONTH,
+			coupon_terms()
+		));
+		assert_ok!(Bonds::fund_coupons(RuntimeOrigin::signed(ALICE), bond_id, 10 * ONE));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH + WEEK);
+		assert_eq!(Bonds::claimable_coupons(bond_id, &ALICE), 2 * ONE);
+
+		// Act
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(ALICE), bond_id));
+
+		// Assert
+		expect_events(vec![Event::CouponsClaimed {
+			who: ALICE,
+			bond_id,
+			amount: 2 * ONE,
+		}
+		.into()]);
+		assert_eq!(Bonds::coupon_reserve(bond_id), 8 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - 110 * ONE + 2 * ONE);
+		assert_eq!(Bonds::claimable_coupons(bond_id, &ALICE), 0);
+		assert_noop!(
+			Bonds::claim_coupons(RuntimeOrigin::signed(ALICE), bond_id),
+			Error::<Test>::NoCoupons
+		);
+	});
+}
+
+#[test]
+fn coupons_should_accrue_to_holders_when_bonds_are_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + 6 * MONTH,
+			coupon_terms()
+		));
+		assert_ok!(Bonds::fund_coupons(RuntimeOrigin::signed(ALICE), bond_id, 10 * ONE));
+
+		// Act
+		Timestamp::set_timestamp(NOW + MONTH);
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 40 * ONE));
+		Timestamp::set_timestamp(NOW + 3 * MONTH);
+
+		// Assert
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(ALICE), bond_id));
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(BOB), bond_id));
+
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 800_000_000_000);
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			INITIAL_BALANCE - 110 * ONE + 2_200_000_000_000
+		);
+		assert_eq!(Bonds::coupon_reserve(bond_id), 7 * ONE);
+
+		// bonds transferred back don't accrue the same periods again
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(BOB), ALICE, bond_id, 40 * ONE));
+		assert_noop!(
+			Bonds::claim_coupons(RuntimeOrigin::signed(ALICE), bond_id),
+			Error::<Test>::NoCoupons
+		);
+	});
+}
+
+#[test]
+fn coupons_should_stop_accruing_at_maturity() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + 3 * MONTH,
+			coupon_terms()
+		));
+		assert_ok!(Bonds::fund_coupons(RuntimeOrigin::signed(ALICE), bond_id, 10 * ONE));
+
+		Timestamp::set_timestamp(NOW + 12 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, 100 * ONE));
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(ALICE), bond_id));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - 10 * ONE + 3 * ONE);
+		assert_eq!(Bonds::coupon_reserve(bond_id), 7 * ONE);
+		assert_eq!(Bonds::coupon_account(bond_id, ALICE), CouponAccount::default());
+	});
+}
+
+#[test]
+fn claim_coupons_should_fail_when_reserve_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + 6 * MONTH,
+			coupon_terms()
+		));
+		assert_ok!(Bonds::fund_coupons(RuntimeOrigin::signed(ALICE), bond_id, ONE));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::claim_coupons(RuntimeOrigin::signed(ALICE), bond_id),
+			Error::<Test>::InsufficientCouponReserve
+		);
+	});
+}
+
+#[test]
+fn fund_coupons_should_fail_when_bond_does_not_pay_coupons() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, NOW + MONTH));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::fund_coupons(RuntimeOrigin::signed(ALICE), bond_id, ONE),
+			Error::<Test>::NotCouponBond
+		);
+		assert_noop!(
+			Bonds::claim_coupons(RuntimeOrigin::signed(ALICE), bond_id),
+			Error::<Test>::NotCouponBond
+		);
+	});
+}
+
+#[test]
+fn called_bonds_should_be_redeemable_at_call_price_before_maturity() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + 6 * MONTH,
+			callable_terms()
+		));
+		assert_ok!(Bonds::fund_coupons(RuntimeOrigin::signed(ALICE), bond_id, 10 * ONE));
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 50 * ONE));
+
+		Timestamp::set_timestamp(NOW + 3 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::call_bond(RuntimeOrigin::signed(ALICE), bond_id));
+
+		// Assert
+		expect_events(vec![Event::Called {
+			issuer: ALICE,
+			bond_id,
+			premium: 2 * ONE,
+		}
+		.into()]);
+		assert_eq!(Bonds::called_at(bond_id), Some(NOW + 3 * MONTH));
+
+		Timestamp::set_timestamp(NOW + 5 * MONTH);
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(BOB), bond_id, 50 * ONE));
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(BOB), bond_id));
+
+		// par value + 2% premium + 3 coupons of 1%
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 51 * ONE + 1_500_000_000_000);
+		assert_eq!(Tokens::free_balance(bond_id, &BOB), 0);
+	});
+}
+
+#[test]
+fn call_bond_should_fail_when_call_conditions_are_not_met() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue_with_terms(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + 6 * MONTH,
+			callable_terms()
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::call_bond(RuntimeOrigin::signed(ALICE), bond_id),
+			Error::<Test>::NotCallable
+		);
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH);
+		assert_noop!(
+			Bonds::call_bond(RuntimeOrigin::signed(BOB), bond_id),
+			Error::<Test>::NotIssuer
+		);
+		assert_noop!(
+			Bonds::redeem(RuntimeOrigin::signed(ALICE), bond_id, ONE),
+			Error::<Test>::NotMature
+		);
+
+		assert_ok!(Bonds::call_bond(RuntimeOrigin::signed(ALICE), bond_id));
+		assert_noop!(
+			Bonds::call_bond(RuntimeOrigin::signed(ALICE), bond_id),
+			Error::<Test>::AlreadyCalled
+		);
+
+		Timestamp::set_timestamp(NOW + 6 * MONTH);
+		assert_noop!(
+			Bonds::issue_with_terms(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				ONE,
+				NOW + 6 * MONTH,
+				callable_terms()
+			),
+			Error::<Test>::InvalidMaturity
+		);
+	});
+}
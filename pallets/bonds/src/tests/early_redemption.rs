@@ -0,0 +1,279 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+pub use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn curve(points: Vec<(Moment, u32)>) -> BoundedVec<DiscountPoint, <Test as Config>::MaxDiscountPoints> {
+	points
+		.into_iter()
+		.map(|(moment, discount)| DiscountPoint {
+			moment,
+			discount: Permill::from_percent(discount),
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn set_early_redemption_should_work_when_curve_is_valid() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act
+		let c = curve(vec![(NOW, 20), (NOW + WEEK, 10)]);
+		assert_ok!(Bonds::set_early_redemption(
+			RuntimeOrigin::root(),
+			bond_id,
+			Some(c.clone())
+		));
+
+		// Assert
+		assert_eq!(Bonds::early_redemption_curve(bond_id), Some(c.clone()));
+		expect_events(vec![Event::EarlyRedemptionSet {
+			bond_id,
+			curve: Some(c),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_early_redemption_should_remove_curve_when_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+		assert_ok!(Bonds::set_early_redemption(
+			RuntimeOrigin::root(),
+			bond_id,
+			Some(curve(vec![(NOW, 20)]))
+		));
+
+		// Act
+		assert_ok!(Bonds::set_early_redemption(RuntimeOrigin::root(), bond_id, None));
+
+		// Assert
+		assert_eq!(Bonds::early_redemption_curve(bond_id), None);
+		assert_eq!(Bonds::early_redemption_price(bond_id), None);
+	});
+}
+
+#[test]
+fn set_early_redemption_should_fail_when_called_by_wrong_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		assert_noop!(
+			Bonds::set_early_redemption(RuntimeOrigin::signed(ALICE), bond_id, Some(curve(vec![(NOW, 20)]))),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_early_redemption_should_fail_when_bond_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Bonds::set_early_redemption(RuntimeOrigin::root(), next_asset_id(), Some(curve(vec![(NOW, 20)]))),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
+
+#[test]
+fn set_early_redemption_should_fail_when_curve_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::set_early_redemption(RuntimeOrigin::root(), bond_id, Some(curve(vec![]))),
+			Error::<Test>::InvalidDiscountCurve
+		);
+		assert_noop!(
+			Bonds::set_early_redemption(
+				RuntimeOrigin::root(),
+				bond_id,
+				Some(curve(vec![(NOW + WEEK, 20), (NOW, 10)]))
+			),
+			Error::<Test>::InvalidDiscountCurve
+		);
+		assert_noop!(
+			Bonds::set_early_redemption(RuntimeOrigin::root(), bond_id, Some(curve(vec![(NOW, 20), (NOW, 10)]))),
+			Error::<Test>::InvalidDiscountCurve
+		);
+		assert_noop!(
+			Bonds::set_early_redemption(RuntimeOrigin::root(), bond_id, Some(curve(vec![(maturity, 20)]))),
+			Error::<Test>::InvalidDiscountCurve
+		);
+	});
+}
+
+#[test]
+fn set_early_redemption_should_fail_when_bond_is_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+		Timestamp::set_timestamp(maturity);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::set_early_redemption(RuntimeOrigin::root(), bond_id, Some(curve(vec![(NOW, 20)]))),
+			Error::<Test>::AlreadyMature
+		);
+	});
+}
+
+#[test]
+fn early_redemption_discount_should_interpolate_curve_points_and_reach_par_at_maturity() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+		assert_ok!(Bonds::set_early_redemption(
+			RuntimeOrigin::root(),
+			bond_id,
+			Some(curve(vec![(NOW, 20), (NOW + MONTH / 2, 10)]))
+		));
+
+		// Act & Assert
+		assert_eq!(Bonds::early_redemption_discount(bond_id, NOW - 1), None);
+		assert_eq!(
+			Bonds::early_redemption_discount(bond_id, NOW),
+			Some(Permill::from_percent(20))
+		);
+		assert_eq!(
+			Bonds::early_redemption_discount(bond_id, NOW + MONTH / 4),
+			Some(Permill::from_percent(15))
+		);
+		assert_eq!(
+			Bonds::early_redemption_discount(bond_id, NOW + MONTH / 2),
+			Some(Permill::from_percent(10))
+		);
+		assert_eq!(
+			Bonds::early_redemption_discount(bond_id, NOW + 3 * MONTH / 4),
+			Some(Permill::from_percent(5))
+		);
+		assert_eq!(Bonds::early_redemption_discount(bond_id, maturity), None);
+	});
+}
+
+#[test]
+fn redeem_early_should_pay_discounted_amount_and_transfer_discount_to_fee_receiver() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let amount = 100 * ONE;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, amount, maturity));
+		assert_ok!(Bonds::set_early_redemption(
+			RuntimeOrigin::root(),
+			bond_id,
+			Some(curve(vec![(NOW, 10)]))
+		));
+		Timestamp::set_timestamp(NOW + MONTH / 2);
+		assert_eq!(Bonds::early_redemption_price(bond_id), Some(Permill::from_percent(95)));
+		assert_eq!(Bonds::early_redemption_amount(bond_id, amount), Some(95 * ONE));
+
+		// Act
+		assert_ok!(Bonds::redeem_early(RuntimeOrigin::signed(ALICE), bond_id, amount));
+
+		// Assert
+		expect_events(vec![Event::RedeemedEarly {
+			who: ALICE,
+			bond_id,
+			amount,
+			paid: 95 * ONE,
+			discount: 5 * ONE,
+		}
+		.into()]);
+
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - 5 * ONE);
+		assert_eq!(
+			Tokens::free_balance(HDX, &<Test as Config>::FeeReceiver::get()),
+			5 * ONE
+		);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+	});
+}
+
+#[test]
+fn redeem_early_should_fail_when_early_redemption_is_not_available() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem_early(RuntimeOrigin::signed(ALICE), bond_id, ONE),
+			Error::<Test>::EarlyRedemptionNotAvailable
+		);
+
+		assert_ok!(Bonds::set_early_redemption(
+			RuntimeOrigin::root(),
+			bond_id,
+			Some(curve(vec![(NOW + WEEK, 10)]))
+		));
+		assert_noop!(
+			Bonds::redeem_early(RuntimeOrigin::signed(ALICE), bond_id, ONE),
+			Error::<Test>::EarlyRedemptionNotAvailable
+		);
+	});
+}
+
+#[test]
+fn redeem_early_should_fail_when_bond_is_mature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, ONE, maturity));
+		assert_ok!(Bonds::set_early_redemption(
+			RuntimeOrigin::root(),
+			bond_id,
+			Some(curve(vec![(NOW, 10)]))
+		));
+		Timestamp::set_timestamp(maturity);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::redeem_early(RuntimeOrigin::signed(ALICE), bond_id, ONE),
+			Error::<Test>::AlreadyMature
+		);
+	});
+}
@@ -26,13 +26,13 @@ use frame_support::{
 	},
 	traits::{ConstU32, ConstU64, Everything, SortedMembers},
 };
-use frame_system::EnsureSignedBy;
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::BoundedVec;
 use std::{cell::RefCell, collections::HashMap};
 
 use hydradx_traits::registry::{Create, Inspect};
-use orml_traits::parameter_type_with_key;
+use orml_traits::{currency::MutationHooks, parameter_type_with_key};
 pub use primitives::constants::{
 	currency::NATIVE_EXISTENTIAL_DEPOSIT,
 	time::{
@@ -63,6 +63,8 @@ pub const NOW: Moment = 1689844300000; // unix time in milliseconds
 thread_local! {
 	// maps AssetId -> existential deposit
 	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, (Balance, AssetKind)>> = RefCell::new(HashMap::default());
+	// names of registered assets, the registry requires them to be unique
+	pub static REGISTERED_NAMES: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
 	pub static PROTOCOL_FEE: RefCell<Permill> = const { RefCell::new(Permill::from_percent(0)) };
 }
 
@@ -114,6 +116,8 @@ impl pallet_bonds::Config for Test {
 	type AssetTypeWhitelist = AssetTypeWhitelist;
 	type ProtocolFee = ProtocolFee;
 	type FeeReceiver = TreasuryAccount;
+	type EarlyRedemptionOrigin = EnsureRoot<AccountId>;
+	type MaxDiscountPoints = ConstU32<3>;
 	type WeightInfo = ();
 }
 
@@ -156,13 +160,25 @@ impl orml_tokens::Config for Test {
 	type CurrencyId = AssetId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type CurrencyHooks = ();
+	type CurrencyHooks = CurrencyHooks;
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = ();
 	type DustRemovalWhitelist = Everything;
 }
 
+pub struct CurrencyHooks;
+impl MutationHooks<AccountId, AssetId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = AccrueCoupons<Test>;
+	type PostDeposit = ();
+	type PreTransfer = AccrueCoupons<Test>;
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
 parameter_types! {
 	pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
 }
@@ -179,7 +195,7 @@ pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Create<Balance> for DummyRegistry<T> {
 	type Error = DispatchError;
 	type Name = BoundedVec<u8, ConstU32<20>>;
-	type Symbol = BoundedVec<u8, ConstU32<20>>;
+	type Symbol = BoundedVec<u8, ConstU32<32>>;
 
 	fn register_asset(
 		_asset_id: Option<Self::AssetId>,
@@ -197,14 +213,27 @@ impl<T: Config> Create<Balance> for DummyRegistry<T> {
 
 	fn register_insufficient_asset(
 		_asset_id: Option<Self::AssetId>,
-		_name: Option<Self::Name>,
+		name: Option<Self::Name>,
 		_kind: AssetKind,
 		existential_deposit: Option<Balance>,
-		_symbol: Option<Self::Symbol>,
+		symbol: Option<Self::Symbol>,
 		_decimals: Option<u8>,
 		_location: Option<Self::Location>,
 		_xcm_rate_limit: Option<Balance>,
 	) -> Result<Self::AssetId, Self::Error> {
+		if symbol.is_some_and(|s| s.iter().any(|c| char::is_whitespace(*c as char))) {
+			return Err(DispatchError::Other("InvalidSymbol"));
+		}
+		if let Some(name) = name {
+			REGISTERED_NAMES.with(|v| {
+				if v.borrow().contains(&name.to_vec()) {
+					return Err(DispatchError::Other("AssetAlreadyRegistered"));
+				}
+				v.borrow_mut().push(name.to_vec());
+				Ok(())
+			})?;
+		}
+
 		let assigned = REGISTERED_ASSETS.with(|v| {
 			let l = v.borrow().len();
 			v.borrow_mut()
@@ -313,6 +342,8 @@ impl ExtBuilder {
 			*v.borrow_mut() = self.protocol_fee;
 		});
 
+		REGISTERED_NAMES.with(|v| v.borrow_mut().clear());
+
 		orml_tokens::GenesisConfig::<Test> {
 			balances: self
 				.endowed_accounts
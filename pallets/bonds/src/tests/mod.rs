@@ -1,3 +1,5 @@
+mod coupons;
+mod early_redemption;
 mod issue;
 pub mod mock;
 mod redeem;
@@ -15,6 +15,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! # Collator rewards pallet
+//!
+//! ## Overview
+//!
+//! The pallet wraps a session manager and rewards the collators of a session when the session ends.
+//!
+//! Blocks authored by collators are tracked via `pallet_authorship::EventHandler`. The session reward,
+//! `RewardPerCollator` for every collator not listed in `ExcludedCollators`, is distributed pro-rata
+//! to the blocks authored by these collators.
+//!
+//! Collators which authored less than `MinUptime` of the expected number of blocks (number of blocks in the session
+//! divided by the number of collators) are not rewarded and are reported by `CollatorUnderperformed` event.
+//! The number of blocks in the session is the difference of block numbers at the end and at the start of the session.
+//! Their share of the session reward stays in the rewards bag.
+//!
+//! If no authored blocks were tracked in the session, the reward is split equally.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
@@ -28,9 +45,14 @@ mod tests;
 pub mod migration;
 
 use frame_support::{traits::Get, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
 
 use orml_traits::MultiCurrency;
 use pallet_session::SessionManager;
+use sp_runtime::{
+	traits::{CheckedDiv, Zero},
+	Perbill, SaturatedConversion, Saturating,
+};
 use sp_staking::SessionIndex;
 use sp_std::vec::Vec;
 
@@ -42,7 +64,6 @@ pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_support::sp_runtime::traits::AtLeast32BitUnsigned;
-	use frame_system::pallet_prelude::BlockNumberFor;
 
 	/// The current storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -73,9 +94,15 @@ pub mod pallet {
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::CurrencyId, Balance = Self::Balance>;
 
 		/// Reward amount per one collator.
+		/// Session reward distributed pro-rata to authored blocks is `RewardPerCollator` times
+		/// the number of rewarded collators.
 		#[pallet::constant]
 		type RewardPerCollator: Get<Self::Balance>;
 
+		/// Minimum ratio of authored to expected blocks required to be rewarded.
+		#[pallet::constant]
+		type MinUptime: Get<Perbill>;
+
 		/// Reward Asset Id
 		#[pallet::constant]
 		type RewardCurrencyId: Get<Self::CurrencyId>;
@@ -106,6 +133,13 @@ pub mod pallet {
 			amount: T::Balance,
 			currency: T::CurrencyId,
 		},
+		/// Collator authored less blocks than required by the minimum uptime and was not rewarded.
+		CollatorUnderperformed {
+			who: T::AccountId,
+			session: SessionIndex,
+			authored_blocks: u32,
+			expected_blocks: u32,
+		},
 	}
 
 	#[pallet::storage]
@@ -113,6 +147,36 @@ pub mod pallet {
 	/// Stores the collators per session (index).
 	pub type Collators<T: Config> =
 		StorageMap<_, Twox64Concat, SessionIndex, BoundedVec<T::AccountId, T::MaxCandidates>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn session_start)]
+	/// Block number at which the current session started.
+	pub type SessionStart<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn authored_blocks)]
+	/// Number of blocks authored by collators in the current session.
+	pub type AuthoredBlocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	fn reward_collator(collator: T::AccountId, amount: T::Balance) {
+		let currency = T::RewardCurrencyId::get();
+		match T::Currency::transfer(currency, &T::RewardsBag::get(), &collator, amount) {
+			Ok(_) => Self::deposit_event(Event::CollatorRewarded {
+				who: collator,
+				amount,
+				currency,
+			}),
+			Err(err) => log::warn!(target: "runtime::collator-rewards", "Error reward collators: {:?}", err),
+		}
+	}
+}
+
+impl<T: Config> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+	fn note_author(author: T::AccountId) {
+		AuthoredBlocks::<T>::mutate(author, |blocks| *blocks = blocks.saturating_add(1));
+	}
 }
 
 impl<T: Config> SessionManager<T::AccountId> for Pallet<T> {
@@ -132,24 +196,73 @@ impl<T: Config> SessionManager<T::AccountId> for Pallet<T> {
 	}
 
 	fn start_session(index: SessionIndex) {
+		SessionStart::<T>::put(frame_system::Pallet::<T>::block_number());
 		T::SessionManager::start_session(index)
 	}
 
 	fn end_session(index: SessionIndex) {
 		T::SessionManager::end_session(index);
 		let excluded = T::ExcludedCollators::get();
-		// remove the collators so we don't pile up storage
-		for collator in Collators::<T>::take(index) {
-			if !excluded.contains(&collator) {
-				let (currency, amount) = (T::RewardCurrencyId::get(), T::RewardPerCollator::get());
-				match T::Currency::transfer(currency, &T::RewardsBag::get(), &collator, amount) {
-					Ok(_) => Self::deposit_event(Event::CollatorRewarded {
-						who: collator,
-						amount,
-						currency,
-					}),
-					Err(err) => log::warn!(target: "runtime::collator-rewards", "Error reward collators: {:?}", err),
-				}
+		// remove the collators and authored blocks so we don't pile up storage
+		let authored: Vec<(T::AccountId, u32)> = Collators::<T>::take(index)
+			.into_iter()
+			.map(|collator| {
+				let blocks = AuthoredBlocks::<T>::get(&collator);
+				(collator, blocks)
+			})
+			.collect();
+		let _ = AuthoredBlocks::<T>::clear(u32::MAX, None);
+
+		let collators_count = authored.len() as u32;
+		let authored_blocks = authored
+			.iter()
+			.fold(0u32, |acc, (_, blocks)| acc.saturating_add(*blocks));
+		// Falls back to the authored blocks if the start of the session is not known, e.g. right after the upgrade.
+		let session_blocks: u32 = SessionStart::<T>::take()
+			.map(|start| {
+				frame_system::Pallet::<T>::block_number()
+					.saturating_sub(start)
+					.saturated_into()
+			})
+			.unwrap_or(authored_blocks);
+		let rewarded: Vec<(T::AccountId, u32)> = authored
+			.into_iter()
+			.filter(|(collator, _)| !excluded.contains(collator))
+			.collect();
+		let reward_per_collator = T::RewardPerCollator::get();
+
+		if authored_blocks.is_zero() || session_blocks.is_zero() {
+			// no authored blocks were tracked, split the reward equally
+			for (collator, _) in rewarded {
+				Self::reward_collator(collator, reward_per_collator);
+			}
+			return;
+		}
+
+		let session_reward = reward_per_collator.saturating_mul((rewarded.len() as u32).into());
+		let rewarded_blocks = rewarded
+			.iter()
+			.fold(0u32, |acc, (_, blocks)| acc.saturating_add(*blocks));
+		let expected_blocks = session_blocks / collators_count;
+
+		for (collator, blocks) in rewarded {
+			let uptime = Perbill::from_rational(blocks.saturating_mul(collators_count), session_blocks);
+			if uptime < T::MinUptime::get() {
+				Self::deposit_event(Event::CollatorUnderperformed {
+					who: collator,
+					session: index,
+					authored_blocks: blocks,
+					expected_blocks,
+				});
+				continue;
+			}
+
+			let amount = session_reward
+				.saturating_mul(blocks.into())
+				.checked_div(&rewarded_blocks.into())
+				.unwrap_or_default();
+			if !amount.is_zero() {
+				Self::reward_collator(collator, amount);
 			}
 		}
 	}
@@ -14,7 +14,7 @@ use pallet_session::SessionManager;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Perbill,
 };
 use sp_staking::SessionIndex;
 use sp_std::vec::Vec;
@@ -132,6 +132,7 @@ parameter_types! {
 	pub const RewardsBag: AccountId = BAG;
 	pub GcCollators: Vec<AccountId> = vec![GC_COLL_1, GC_COLL_2, GC_COLL_3];
 	pub const MaxCandidates: u32 = 50;
+	pub const MinUptime: Perbill = Perbill::from_percent(50);
 }
 
 thread_local! {
@@ -155,6 +156,7 @@ impl Config for Test {
 	type CurrencyId = AssetId;
 	type Currency = Tokens;
 	type RewardPerCollator = RewardPerCollator;
+	type MinUptime = MinUptime;
 	type RewardCurrencyId = RewardCurrencyId;
 	type RewardsBag = RewardsBag;
 	type ExcludedCollators = GcCollators;
@@ -2,7 +2,7 @@ use super::*;
 use frame_support::assert_ok;
 
 use crate::mock::{
-	set_block_number, CollatorRewards, ExtBuilder, RuntimeOrigin, Test, Tokens, ALICE, BAG, BOB, CHARLIE,
+	set_block_number, AccountId, CollatorRewards, ExtBuilder, RuntimeOrigin, Test, Tokens, ALICE, BAG, BOB, CHARLIE,
 	COLLATOR_REWARD, DAVE, GC_COLL_1, GC_COLL_2, GC_COLL_3, NATIVE_TOKEN, SESSION_ENDED,
 };
 
@@ -93,3 +93,165 @@ fn reward_collator_on_end_session_should_work() {
 fn assert_that_session_ended() {
 	assert!(SESSION_ENDED.with(|t| *t.borrow()));
 }
+
+fn author_blocks(author: AccountId, blocks: u32) {
+	for _ in 0..blocks {
+		<CollatorRewards as pallet_authorship::EventHandler<AccountId, u64>>::note_author(author);
+	}
+}
+
+#[test]
+fn reward_collators_pro_rata_to_authored_blocks_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::set_balance(
+			RuntimeOrigin::root(),
+			BAG,
+			NATIVE_TOKEN,
+			100 * COLLATOR_REWARD,
+			0
+		));
+		CollatorRewards::new_session(1);
+		CollatorRewards::start_session(1);
+
+		// 70 blocks in session, 10 expected per collator
+		author_blocks(GC_COLL_1, 10);
+		author_blocks(GC_COLL_2, 10);
+		author_blocks(GC_COLL_3, 10);
+		author_blocks(ALICE, 20);
+		author_blocks(BOB, 10);
+		author_blocks(CHARLIE, 10);
+		author_blocks(DAVE, 3);
+		assert_eq!(CollatorRewards::authored_blocks(ALICE), 20);
+
+		set_block_number(71);
+		CollatorRewards::end_session(1);
+
+		// session reward 4 * COLLATOR_REWARD is split by 43 blocks authored by rewarded collators
+		assert_eq!(
+			Tokens::free_balance(NATIVE_TOKEN, &ALICE),
+			4 * COLLATOR_REWARD * 20 / 43
+		);
+		assert_eq!(Tokens::free_balance(NATIVE_TOKEN, &BOB), 4 * COLLATOR_REWARD * 10 / 43);
+		assert_eq!(
+			Tokens::free_balance(NATIVE_TOKEN, &CHARLIE),
+			4 * COLLATOR_REWARD * 10 / 43
+		);
+		assert_eq!(Tokens::free_balance(NATIVE_TOKEN, &GC_COLL_1), 0);
+
+		// DAVE authored 3 of 10 expected blocks
+		assert_eq!(Tokens::free_balance(NATIVE_TOKEN, &DAVE), 0);
+		frame_system::Pallet::<Test>::assert_has_event(mock::RuntimeEvent::CollatorRewards(
+			Event::CollatorUnderperformed {
+				who: DAVE,
+				session: 1,
+				authored_blocks: 3,
+				expected_blocks: 10,
+			},
+		));
+
+		assert_eq!(AuthoredBlocks::<Test>::iter().count(), 0);
+		assert!(!Collators::<Test>::contains_key(1));
+		assert_eq!(CollatorRewards::session_start(), None);
+	});
+}
+
+#[test]
+fn collator_at_min_uptime_should_be_rewarded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::set_balance(
+			RuntimeOrigin::root(),
+			BAG,
+			NATIVE_TOKEN,
+			100 * COLLATOR_REWARD,
+			0
+		));
+		CollatorRewards::new_session(1);
+		CollatorRewards::start_session(1);
+
+		// 70 blocks in session, 10 expected per collator
+		author_blocks(GC_COLL_1, 15);
+		author_blocks(GC_COLL_2, 15);
+		author_blocks(GC_COLL_3, 10);
+		author_blocks(ALICE, 10);
+		author_blocks(BOB, 10);
+		author_blocks(CHARLIE, 5);
+		author_blocks(DAVE, 5);
+
+		set_block_number(71);
+		CollatorRewards::end_session(1);
+
+		assert_eq!(
+			Tokens::free_balance(NATIVE_TOKEN, &ALICE),
+			4 * COLLATOR_REWARD * 10 / 30
+		);
+		assert_eq!(Tokens::free_balance(NATIVE_TOKEN, &BOB), 4 * COLLATOR_REWARD * 10 / 30);
+		assert_eq!(
+			Tokens::free_balance(NATIVE_TOKEN, &CHARLIE),
+			4 * COLLATOR_REWARD * 5 / 30
+		);
+		assert_eq!(Tokens::free_balance(NATIVE_TOKEN, &DAVE), 4 * COLLATOR_REWARD * 5 / 30);
+	});
+}
+
+#[test]
+fn collators_underperforming_equally_should_not_be_rewarded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::set_balance(
+			RuntimeOrigin::root(),
+			BAG,
+			NATIVE_TOKEN,
+			100 * COLLATOR_REWARD,
+			0
+		));
+		CollatorRewards::new_session(1);
+		CollatorRewards::start_session(1);
+
+		// 140 blocks in session, 20 expected per collator
+		for collator in [GC_COLL_1, GC_COLL_2, GC_COLL_3, ALICE, BOB, CHARLIE, DAVE] {
+			author_blocks(collator, 5);
+		}
+
+		set_block_number(141);
+		CollatorRewards::end_session(1);
+
+		for collator in [ALICE, BOB, CHARLIE, DAVE] {
+			assert_eq!(Tokens::free_balance(NATIVE_TOKEN, &collator), 0);
+			frame_system::Pallet::<Test>::assert_has_event(mock::RuntimeEvent::CollatorRewards(
+				Event::CollatorUnderperformed {
+					who: collator,
+					session: 1,
+					authored_blocks: 5,
+					expected_blocks: 20,
+				},
+			));
+		}
+	});
+}
+
+#[test]
+fn blocks_authored_by_other_accounts_should_not_reduce_expected_blocks() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::set_balance(
+			RuntimeOrigin::root(),
+			BAG,
+			NATIVE_TOKEN,
+			100 * COLLATOR_REWARD,
+			0
+		));
+		CollatorRewards::new_session(1);
+		CollatorRewards::start_session(1);
+
+		// 70 blocks in session, 10 expected per collator, 40 blocks authored by an account outside the collator set
+		for collator in [GC_COLL_1, GC_COLL_2, GC_COLL_3, ALICE, BOB, CHARLIE] {
+			author_blocks(collator, 5);
+		}
+		author_blocks(DAVE, 10);
+		author_blocks(BAG, 40);
+
+		set_block_number(71);
+		CollatorRewards::end_session(1);
+
+		assert_eq!(Tokens::free_balance(NATIVE_TOKEN, &DAVE), 4 * COLLATOR_REWARD * 10 / 25);
+		assert_eq!(Tokens::free_balance(NATIVE_TOKEN, &ALICE), 4 * COLLATOR_REWARD * 5 / 25);
+	});
+}
@@ -4,7 +4,7 @@ use frame_support::fail;
 use frame_support::traits::tokens::{
 	fungible, fungibles, DepositConsequence, Fortitude, Precision, Preservation, Provenance, WithdrawConsequence,
 };
-use hydradx_traits::BoundErc20;
+use hydradx_traits::{AccountFreeze, BoundErc20};
 use orml_traits::MultiCurrency;
 use sp_runtime::traits::Get;
 #[cfg(any(feature = "try-runtime", test))]
@@ -100,6 +100,9 @@ where
 		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> WithdrawConsequence<Self::Balance> {
+		if T::AccountFreeze::is_frozen(asset, who) {
+			return WithdrawConsequence::Frozen;
+		}
 		if asset == T::GetNativeCurrencyId::get() {
 			<T::NativeCurrency as fungible::Inspect<T::AccountId>>::can_withdraw(who, amount.into()).into()
 		} else {
@@ -246,6 +249,7 @@ where
 		precision: Precision,
 		force: Fortitude,
 	) -> Result<Self::Balance, DispatchError> {
+		Pallet::<T>::ensure_not_frozen(asset, who)?;
 		if asset == T::GetNativeCurrencyId::get() {
 			<T::NativeCurrency as fungible::Mutate<T::AccountId>>::burn_from(
 				who,
@@ -278,6 +282,7 @@ where
 		amount: Self::Balance,
 		preservation: Preservation,
 	) -> Result<Self::Balance, DispatchError> {
+		Pallet::<T>::ensure_not_frozen(asset, source)?;
 		#[cfg(any(feature = "try-runtime", test))]
 		let (initial_source_balance, initial_dest_balance) = {
 			(
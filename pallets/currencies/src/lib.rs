@@ -52,7 +52,7 @@ use frame_support::{
 };
 use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 use hydradx_traits::evm::EvmAddress;
-use hydradx_traits::{AssetKind, BoundErc20};
+use hydradx_traits::{AccountFreeze, AssetKind, BoundErc20};
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	currency::TransferAll,
@@ -110,6 +110,9 @@ pub mod module {
 
 		type BoundErc20: BoundErc20<AssetId = CurrencyIdOf<Self>>;
 
+		/// Accounts which can't transfer or withdraw their balance of a currency.
+		type AccountFreeze: AccountFreeze<CurrencyIdOf<Self>, Self::AccountId>;
+
 		#[pallet::constant]
 		type ReserveAccount: Get<Self::AccountId>;
 
@@ -130,6 +133,8 @@ pub mod module {
 		DepositFailed,
 		/// Operation is not supported for this currency
 		NotSupported,
+		/// Account's balance of the currency is frozen.
+		AccountFrozen,
 	}
 
 	#[pallet::event]
@@ -201,6 +206,7 @@ pub mod module {
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
 			let to = T::Lookup::lookup(dest)?;
+			Self::ensure_not_frozen(T::GetNativeCurrencyId::get(), &from)?;
 			T::NativeCurrency::transfer(&from, &to, amount)?;
 
 			Self::deposit_event(Event::Transferred {
@@ -231,6 +237,16 @@ pub mod module {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	pub(crate) fn ensure_not_frozen(currency_id: CurrencyIdOf<T>, who: &T::AccountId) -> DispatchResult {
+		ensure!(
+			!T::AccountFreeze::is_frozen(currency_id, who),
+			Error::<T>::AccountFrozen
+		);
+		Ok(())
+	}
+}
+
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	type CurrencyId = CurrencyIdOf<T>;
 	type Balance = BalanceOf<T>;
@@ -280,6 +296,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	}
 
 	fn ensure_can_withdraw(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::ensure_not_frozen(currency_id, who)?;
 		if currency_id == T::GetNativeCurrencyId::get() {
 			T::NativeCurrency::ensure_can_withdraw(who, amount)
 		} else {
@@ -299,6 +316,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() || from == to {
 			return Ok(());
 		}
+		Self::ensure_not_frozen(currency_id, from)?;
 		#[cfg(any(feature = "try-runtime", test))]
 		let (initial_source_balance, initial_dest_balance) = {
 			(
@@ -364,6 +382,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() {
 			return Ok(());
 		}
+		Self::ensure_not_frozen(currency_id, who)?;
 		if currency_id == T::GetNativeCurrencyId::get() {
 			T::NativeCurrency::withdraw(who, amount)?;
 		} else {
@@ -15,6 +15,7 @@ use sp_runtime::{
 	traits::{AccountIdConversion, IdentityLookup},
 	AccountId32, BuildStorage,
 };
+use std::cell::RefCell;
 
 pub type AccountId = AccountId32;
 impl frame_system::Config for Runtime {
@@ -109,10 +110,26 @@ impl Config for Runtime {
 	type NativeCurrency = AdaptedBasicCurrency;
 	type Erc20Currency = MockErc20Currency<Runtime>;
 	type BoundErc20 = MockBoundErc20<Runtime>;
+	type AccountFreeze = MockAccountFreeze;
 	type ReserveAccount = ReserveAccount;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type WeightInfo = ();
 }
+thread_local! {
+	pub static FROZEN_ACCOUNTS: RefCell<Vec<(CurrencyId, AccountId)>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn freeze_account(currency_id: CurrencyId, who: AccountId) {
+	FROZEN_ACCOUNTS.with(|v| v.borrow_mut().push((currency_id, who)));
+}
+
+pub struct MockAccountFreeze;
+impl AccountFreeze<CurrencyId, AccountId> for MockAccountFreeze {
+	fn is_frozen(currency_id: CurrencyId, who: &AccountId) -> bool {
+		FROZEN_ACCOUNTS.with(|v| v.borrow().contains(&(currency_id, who.clone())))
+	}
+}
+
 pub type NativeCurrency = NativeCurrencyOf<Runtime>;
 pub type AdaptedBasicCurrency = BasicCurrencyAdapter<Runtime, PalletBalances, i64, u64>;
 
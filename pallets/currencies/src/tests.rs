@@ -394,3 +394,57 @@ fn call_event_should_work() {
 			}));
 		});
 }
+
+#[test]
+fn transfer_should_fail_when_source_account_is_frozen() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			freeze_account(X_TOKEN_ID, ALICE);
+
+			assert_noop!(
+				Currencies::transfer(Some(ALICE).into(), BOB, X_TOKEN_ID, 50),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_noop!(
+				<Currencies as MultiCurrency<AccountId>>::withdraw(X_TOKEN_ID, &ALICE, 50),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_noop!(
+				<Currencies as MultiCurrency<AccountId>>::ensure_can_withdraw(X_TOKEN_ID, &ALICE, 50),
+				Error::<Runtime>::AccountFrozen
+			);
+		});
+}
+
+#[test]
+fn transfer_should_work_when_only_other_currency_or_account_is_frozen() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			freeze_account(X_TOKEN_ID, ALICE);
+
+			// frozen account can still receive the currency
+			assert_ok!(Currencies::transfer(Some(BOB).into(), ALICE, X_TOKEN_ID, 50));
+			assert_ok!(Currencies::transfer_native_currency(Some(ALICE).into(), BOB, 50));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &ALICE), 150);
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &BOB), 150);
+		});
+}
+
+#[test]
+fn transfer_native_currency_should_fail_when_source_account_is_frozen() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			freeze_account(NATIVE_CURRENCY_ID, ALICE);
+
+			assert_noop!(
+				Currencies::transfer_native_currency(Some(ALICE).into(), BOB, 50),
+				Error::<Runtime>::AccountFrozen
+			);
+		});
+}
@@ -2,7 +2,7 @@
 
 use super::*;
 use crate::fungibles::FungibleCurrencies;
-use frame_support::traits::tokens::Precision;
+use frame_support::traits::tokens::{Fortitude, Precision, WithdrawConsequence};
 use frame_support::{assert_noop, assert_ok, sp_runtime::TokenError, traits::tokens::fungibles::*};
 use mock::*;
 
@@ -147,3 +147,33 @@ fn fungibles_transfer_trait_should_work() {
 			assert_eq!(Tokens::free_balance(X_TOKEN_ID, &ALICE), 10);
 		});
 }
+
+#[test]
+fn fungibles_trait_should_fail_when_account_is_frozen() {
+	ExtBuilder::default()
+		.balances(vec![(BOB, X_TOKEN_ID, 100)])
+		.build()
+		.execute_with(|| {
+			freeze_account(X_TOKEN_ID, BOB);
+
+			assert_noop!(
+				FungibleCurrencies::<Runtime>::transfer(X_TOKEN_ID, &BOB, &ALICE, 10, Preservation::Expendable),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_noop!(
+				FungibleCurrencies::<Runtime>::burn_from(
+					X_TOKEN_ID,
+					&BOB,
+					10,
+					Preservation::Expendable,
+					Precision::Exact,
+					Fortitude::Polite
+				),
+				Error::<Runtime>::AccountFrozen
+			);
+			assert_eq!(
+				FungibleCurrencies::<Runtime>::can_withdraw(X_TOKEN_ID, &BOB, 10),
+				WithdrawConsequence::Frozen
+			);
+		});
+}
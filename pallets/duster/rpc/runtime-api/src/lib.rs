@@ -0,0 +1,36 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the duster pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query dustable accounts.
+	pub trait DusterApi<AccountId, CurrencyId, Balance> where
+		AccountId: Codec,
+		CurrencyId: Codec,
+		Balance: Codec,
+	{
+		/// Returns up to `limit` dustable balances following the `start` entry,
+		/// or from the first entry if `start` is `None`.
+		/// Native currency balances are not included, accounts below its existential deposit are removed
+		/// by the balances pallet.
+		fn dustable_accounts(start: Option<(AccountId, CurrencyId)>, limit: u32) -> Vec<(AccountId, CurrencyId, Balance)>;
+	}
+}
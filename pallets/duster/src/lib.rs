@@ -15,6 +15,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! # Duster pallet
+//!
+//! ## Overview
+//!
+//! Accounts with balance below the minimum deposit of a currency can be dusted by anyone. The remaining balance
+//! is transferred to the dust account and the caller is rewarded with `DustReward` in the native currency
+//! paid from the reward account.
+//!
+//! ## Offchain worker
+//!
+//! The offchain worker scans balances provided by `AccountBalances` in chunks of `MaxOffchainScan` entries
+//! and submits unsigned `dust_accounts` transactions with up to `MaxDustBatch` dustable accounts.
+//! The scan stops once `MaxDustBatch` dustable accounts are found and continues from the last of them in the next run.
+//! Unsigned dusting is not rewarded.
+//!
+//! Native currency balances are not expected to be provided by `AccountBalances`, because accounts
+//! below the existential deposit of the native currency are removed by the balances pallet.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
@@ -30,20 +48,36 @@ pub mod weights;
 
 pub use crate::weights::WeightInfo;
 
-use frame_support::{dispatch::DispatchResult, ensure, traits::Contains, traits::Get};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Contains, traits::Get, BoundedVec};
+use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
+use sp_runtime::{offchain::storage::StorageValueRef, traits::Zero};
+use sp_std::vec::Vec;
 
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	GetByKey, MultiCurrency, MultiCurrencyExtended,
 };
 
-use frame_system::ensure_signed;
+use frame_system::{ensure_signed, ensure_signed_or_none};
 
 use sp_std::convert::{TryFrom, TryInto};
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
+// value taken from https://github.com/substrate-developer-hub/recipes/blob/master/pallets/ocw-demo/src/lib.rs
+pub const UNSIGNED_TXS_PRIORITY: u64 = 100;
+
+/// Last balance entry scanned by the offchain worker.
+pub const OFFCHAIN_WORKER_CURSOR: &[u8] = b"hydradx/duster/cursor/";
+
+/// Provides balances of accounts to scan for dust.
+/// Balances have to be returned in a stable order, so the scan can continue from the `start` entry.
+pub trait AccountBalances<AccountId, CurrencyId, Balance> {
+	/// Returns up to `limit` balances following the `start` entry, or from the first entry if `start` is `None`.
+	fn balances(start: Option<(AccountId, CurrencyId)>, limit: u32) -> Vec<(AccountId, CurrencyId, Balance)>;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -51,7 +85,6 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_support::sp_runtime::traits::AtLeast32BitUnsigned;
 	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
-	use sp_std::vec::Vec;
 
 	/// The current storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -75,11 +108,59 @@ pub mod pallet {
 	/// Account to send dust to.
 	pub type DustAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	#[pallet::type_value]
+	pub fn DefaultDustReward<T: Config>() -> T::Balance {
+		T::Reward::get()
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn dust_reward)]
+	/// Reward in native currency paid for every dusted account.
+	pub type DustReward<T: Config> = StorageValue<_, T::Balance, ValueQuery, DefaultDustReward<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn paid_rewards)]
+	/// Total rewards paid to dusters.
+	pub type PaidRewards<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(_block_number: BlockNumberFor<T>) {
+			// limit the cases when the offchain worker run
+			if sp_io::offchain::is_validator() {
+				Self::dust_offchain();
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match source {
+				TransactionSource::External => {
+					// receiving unsigned transaction from network - disallow
+					return InvalidTransaction::Call.into();
+				}
+				TransactionSource::Local => {}   // produced by off-chain worker
+				TransactionSource::InBlock => {} // some other node included it in a block
+			};
+
+			match call {
+				Call::dust_accounts { accounts } => ValidTransaction::with_tag_prefix("duster")
+					.priority(UNSIGNED_TXS_PRIORITY)
+					.and_provides(accounts)
+					.longevity(3)
+					.propagate(false)
+					.build(),
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+	}
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Balance type
@@ -116,7 +197,7 @@ pub mod pallet {
 		/// The minimum amount required to keep an account.
 		type MinCurrencyDeposits: GetByKey<Self::CurrencyId, Self::Balance>;
 
-		/// Reward amount
+		/// Default reward amount, used until `DustReward` is set.
 		#[pallet::constant]
 		type Reward: Get<Self::Balance>;
 
@@ -124,13 +205,24 @@ pub mod pallet {
 		#[pallet::constant]
 		type NativeCurrencyId: Get<Self::CurrencyId>;
 
-		/// The origin which can manage whiltelist.
+		/// The origin which can manage whiltelist and dust reward.
 		type BlacklistUpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// Default account for `reward_account` and `dust_account` in genesis config.
 		#[pallet::constant]
 		type TreasuryAccountId: Get<Self::AccountId>;
 
+		/// Balances of accounts scanned by the offchain worker.
+		type AccountBalances: AccountBalances<Self::AccountId, Self::CurrencyId, Self::Balance>;
+
+		/// Maximum number of accounts dusted by one `dust_accounts` call.
+		#[pallet::constant]
+		type MaxDustBatch: Get<u32>;
+
+		/// Maximum number of balances scanned by the offchain worker in one run.
+		#[pallet::constant]
+		type MaxOffchainScan: Get<u32>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -174,6 +266,9 @@ pub mod pallet {
 
 		/// Reserve account is not set.
 		ReserveAccountNotSet,
+
+		/// None of the accounts could be dusted.
+		NothingToDust,
 	}
 
 	#[pallet::event]
@@ -187,6 +282,17 @@ pub mod pallet {
 
 		/// Account removed from non-dustable list.
 		Removed { who: T::AccountId },
+
+		/// Duster was rewarded for dusting an account.
+		DusterRewarded {
+			who: T::AccountId,
+			currency_id: T::CurrencyId,
+			dust: T::Balance,
+			reward: T::Balance,
+		},
+
+		/// Dust reward was set.
+		DustRewardSet { reward: T::Balance },
 	}
 
 	#[pallet::call]
@@ -258,6 +364,69 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Dust batch of accounts.
+		/// Accounts which are blacklisted or whose balance is sufficient are skipped.
+		///
+		/// Signed caller is rewarded for every dusted account. Unsigned origin is used by the offchain
+		/// worker and is not rewarded.
+		///
+		/// Fails with `NothingToDust` if none of the accounts was dusted.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::dust_account().saturating_mul(accounts.len() as u64))]
+		pub fn dust_accounts(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<(T::AccountId, T::CurrencyId), T::MaxDustBatch>,
+		) -> DispatchResult {
+			let maybe_duster = ensure_signed_or_none(origin)?;
+
+			let dust_dest_account = Self::dust_dest_account().ok_or(Error::<T>::DustAccountNotSet)?;
+
+			let mut dusted = false;
+			for (account, currency_id) in accounts {
+				if Self::blacklisted(&account).is_some() {
+					continue;
+				}
+
+				let (dustable, dust) = Self::is_dustable(&account, currency_id);
+				if !dustable || dust.is_zero() {
+					continue;
+				}
+
+				if Self::transfer_dust(&account, &dust_dest_account, currency_id, dust).is_err() {
+					continue;
+				}
+
+				Self::deposit_event(Event::Dusted {
+					who: account,
+					amount: dust,
+				});
+
+				if let Some(duster) = maybe_duster.as_ref() {
+					// Ignore the result, it fails - no problem.
+					let _ = Self::reward_duster(duster, currency_id, dust);
+				}
+
+				dusted = true;
+			}
+
+			ensure!(dusted, Error::<T>::NothingToDust);
+
+			Ok(())
+		}
+
+		/// Set reward paid for every dusted account.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_dust_reward())]
+		pub fn set_dust_reward(origin: OriginFor<T>, reward: T::Balance) -> DispatchResult {
+			T::BlacklistUpdateOrigin::ensure_origin(origin)?;
+
+			DustReward::<T>::put(reward);
+
+			Self::deposit_event(Event::DustRewardSet { reward });
+
+			Ok(())
+		}
 	}
 }
 impl<T: Config> Pallet<T> {
@@ -271,16 +440,115 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Send reward to account which did the dusting.
-	fn reward_duster(_duster: &T::AccountId, _currency_id: T::CurrencyId, _dust: T::Balance) -> DispatchResult {
+	fn reward_duster(duster: &T::AccountId, currency_id: T::CurrencyId, dust: T::Balance) -> DispatchResult {
+		let reward = Self::dust_reward();
+		if reward.is_zero() {
+			return Ok(());
+		}
+
 		// Error should never occur here
 		let reserve_account = Self::reward_account().ok_or(Error::<T>::ReserveAccountNotSet)?;
-		let reward = T::Reward::get();
 
-		T::MultiCurrency::transfer(T::NativeCurrencyId::get(), &reserve_account, _duster, reward)?;
+		T::MultiCurrency::transfer(T::NativeCurrencyId::get(), &reserve_account, duster, reward)?;
+
+		PaidRewards::<T>::mutate(duster, |paid| *paid = paid.saturating_add(reward));
+
+		Self::deposit_event(Event::DusterRewarded {
+			who: duster.clone(),
+			currency_id,
+			dust,
+			reward,
+		});
 
 		Ok(())
 	}
 
+	/// Scan up to `limit` balances following `start` and return up to `max_dustable` dustable ones.
+	/// The scan stops at the `max_dustable`-th dustable entry, so no dustable entry is skipped.
+	/// Returns also the last scanned entry, or `None` if there are no more balances to scan.
+	#[allow(clippy::type_complexity)]
+	fn scan_dustable(
+		start: Option<(T::AccountId, T::CurrencyId)>,
+		limit: u32,
+		max_dustable: usize,
+	) -> (
+		Vec<(T::AccountId, T::CurrencyId, T::Balance)>,
+		Option<(T::AccountId, T::CurrencyId)>,
+	) {
+		let balances = T::AccountBalances::balances(start, limit);
+		let scanned = balances.len();
+
+		let mut dustable = Vec::new();
+		let mut last = None;
+		for (who, currency_id, balance) in balances {
+			last = Some((who.clone(), currency_id));
+
+			if !balance.is_zero() && Self::blacklisted(&who).is_none() && Self::is_dustable(&who, currency_id).0 {
+				dustable.push((who, currency_id, balance));
+				if dustable.len() >= max_dustable {
+					return (dustable, last);
+				}
+			}
+		}
+
+		if scanned < limit as usize {
+			last = None;
+		}
+
+		(dustable, last)
+	}
+
+	/// Returns up to `limit` dustable balances following the `start` entry.
+	/// Only balances provided by `AccountBalances` are scanned.
+	pub fn dustable_accounts(
+		start: Option<(T::AccountId, T::CurrencyId)>,
+		limit: u32,
+	) -> Vec<(T::AccountId, T::CurrencyId, T::Balance)> {
+		let mut result = Vec::new();
+		let mut cursor = start;
+		while result.len() < limit as usize {
+			let (dustable, last) = Self::scan_dustable(
+				cursor,
+				T::MaxOffchainScan::get(),
+				(limit as usize).saturating_sub(result.len()),
+			);
+			result.extend(dustable);
+			if last.is_none() {
+				break;
+			}
+			cursor = last;
+		}
+		result
+	}
+
+	/// Scan next chunk of balances and submit unsigned transaction dusting the dustable accounts.
+	fn dust_offchain() {
+		let cursor_ref = StorageValueRef::persistent(OFFCHAIN_WORKER_CURSOR);
+		let cursor = cursor_ref.get::<(T::AccountId, T::CurrencyId)>().ok().flatten();
+
+		let (dustable, last) = Self::scan_dustable(cursor, T::MaxOffchainScan::get(), T::MaxDustBatch::get() as usize);
+
+		match last {
+			Some(last) => cursor_ref.set(&last),
+			None => cursor_ref.clear(),
+		}
+
+		let accounts = BoundedVec::truncate_from(
+			dustable
+				.into_iter()
+				.map(|(who, currency_id, _)| (who, currency_id))
+				.collect(),
+		);
+		if accounts.is_empty() {
+			return;
+		}
+
+		let call = Call::dust_accounts { accounts };
+		if let Err(e) = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()) {
+			log::error!(target: "duster", "failed to submit dust_accounts transaction: {:?}", e);
+		}
+	}
+
 	/// Transfer dust amount to selected DustAccount ( usually treasury)
 	fn transfer_dust(
 		from: &T::AccountId,
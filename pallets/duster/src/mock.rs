@@ -134,9 +134,41 @@ impl Config for Test {
 	type NativeCurrencyId = NativeCurrencyId;
 	type BlacklistUpdateOrigin = EnsureRoot<AccountId>;
 	type TreasuryAccountId = TreasuryAccount;
+	type AccountBalances = TokenBalances;
+	type MaxDustBatch = MaxDustBatch;
+	type MaxOffchainScan = MaxOffchainScan;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxDustBatch: u32 = 2;
+	pub const MaxOffchainScan: u32 = 3;
+}
+
+pub struct TokenBalances;
+impl crate::AccountBalances<AccountId, AssetId, Balance> for TokenBalances {
+	fn balances(start: Option<(AccountId, AssetId)>, limit: u32) -> Vec<(AccountId, AssetId, Balance)> {
+		let iter = match start {
+			Some((who, asset)) => {
+				orml_tokens::Accounts::<Test>::iter_from(orml_tokens::Accounts::<Test>::hashed_key_for(who, asset))
+			}
+			None => orml_tokens::Accounts::<Test>::iter(),
+		};
+		iter.take(limit as usize)
+			.map(|(who, asset, data)| (who, asset, data.total()))
+			.collect()
+	}
+}
+
+pub(crate) type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
 impl orml_tokens::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -157,6 +189,7 @@ impl pallet_currencies::Config for Test {
 	type NativeCurrency = BasicCurrencyAdapter<Test, Balances, Amount, u32>;
 	type Erc20Currency = MockErc20Currency<Test>;
 	type BoundErc20 = MockBoundErc20<Test>;
+	type AccountFreeze = ();
 	type ReserveAccount = ();
 	type GetNativeCurrencyId = NativeCurrencyId;
 	type WeightInfo = ();
@@ -1,7 +1,7 @@
 use super::*;
 use crate::mock::{
-	AssetId, Currencies, Duster, ExtBuilder, RuntimeEvent as TestEvent, RuntimeOrigin, System, Test, Tokens, ALICE,
-	BOB, DUSTER, KILLED, TREASURY,
+	AssetId, Currencies, Duster, ExtBuilder, Extrinsic, RuntimeEvent as TestEvent, RuntimeOrigin, System, Test, Tokens,
+	ALICE, BOB, DUSTER, KILLED, TREASURY,
 };
 
 use frame_support::{assert_noop, assert_ok};
@@ -317,3 +317,241 @@ fn remove_nondustable_account_works() {
 			assert_ok!(Duster::dust_account(RuntimeOrigin::signed(*DUSTER), *ALICE, 0),);
 		});
 }
+
+#[test]
+fn dust_accounts_works() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.with_balance(*BOB, 1, 50)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Duster::dust_accounts(
+				RuntimeOrigin::signed(*DUSTER),
+				vec![(*ALICE, 1), (*BOB, 1)].try_into().unwrap()
+			));
+
+			assert_eq!(Tokens::free_balance(1, &*TREASURY), 150);
+			assert_eq!(Currencies::free_balance(0, &*DUSTER), 20_000);
+			assert_eq!(Duster::paid_rewards(*DUSTER), 20_000);
+
+			expect_events(vec![
+				Event::Dusted {
+					who: *ALICE,
+					amount: 100,
+				}
+				.into(),
+				Event::DusterRewarded {
+					who: *DUSTER,
+					currency_id: 1,
+					dust: 100,
+					reward: 10_000,
+				}
+				.into(),
+				Event::Dusted { who: *BOB, amount: 50 }.into(),
+			]);
+		});
+}
+
+#[test]
+fn dust_accounts_should_skip_accounts_which_cannot_be_dusted() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.with_balance(*BOB, 1, 1_000_000)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Duster::dust_accounts(
+				RuntimeOrigin::signed(*DUSTER),
+				vec![(*BOB, 1), (*ALICE, 1)].try_into().unwrap()
+			));
+
+			assert_eq!(Tokens::free_balance(1, &*TREASURY), 100);
+			assert_eq!(Tokens::free_balance(1, &*BOB), 1_000_000);
+			assert_eq!(Currencies::free_balance(0, &*DUSTER), 10_000);
+		});
+}
+
+#[test]
+fn dust_accounts_should_fail_when_nothing_is_dusted() {
+	ExtBuilder::default()
+		.with_balance(*BOB, 1, 1_000_000)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Duster::dust_accounts(
+					RuntimeOrigin::signed(*DUSTER),
+					vec![(*BOB, 1), (*TREASURY, 1), (*ALICE, 1)].try_into().unwrap()
+				),
+				Error::<Test>::NothingToDust
+			);
+		});
+}
+
+#[test]
+fn dust_accounts_should_not_reward_unsigned_origin() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Duster::dust_accounts(
+				RuntimeOrigin::none(),
+				vec![(*ALICE, 1)].try_into().unwrap()
+			));
+
+			assert_eq!(Tokens::free_balance(1, &*TREASURY), 100);
+			assert_eq!(Currencies::free_balance(0, &*TREASURY), 1_000_000);
+		});
+}
+
+#[test]
+fn set_dust_reward_works() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_eq!(Duster::dust_reward(), 10_000);
+			assert_noop!(
+				Duster::set_dust_reward(RuntimeOrigin::signed(*DUSTER), 5_000),
+				BadOrigin
+			);
+
+			assert_ok!(Duster::set_dust_reward(RuntimeOrigin::root(), 5_000));
+			expect_events(vec![Event::DustRewardSet { reward: 5_000 }.into()]);
+
+			assert_ok!(Duster::dust_account(RuntimeOrigin::signed(*DUSTER), *ALICE, 1));
+			assert_eq!(Currencies::free_balance(0, &*DUSTER), 5_000);
+			assert_eq!(Duster::paid_rewards(*DUSTER), 5_000);
+		});
+}
+
+#[test]
+fn zero_dust_reward_should_not_be_paid() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Duster::set_dust_reward(RuntimeOrigin::root(), 0));
+
+			assert_ok!(Duster::dust_account(RuntimeOrigin::signed(*DUSTER), *ALICE, 1));
+
+			assert_eq!(Tokens::free_balance(1, &*TREASURY), 100);
+			assert_eq!(Currencies::free_balance(0, &*DUSTER), 0);
+			assert!(!PaidRewards::<Test>::contains_key(*DUSTER));
+		});
+}
+
+#[test]
+fn dustable_accounts_should_return_only_dustable_balances() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.with_balance(*ALICE, 2, 100)
+		.with_balance(*BOB, 1, 50)
+		.with_balance(*BOB, 3, 1_000_000)
+		.with_balance(*DUSTER, 1, 1_000_000)
+		.with_balance(*TREASURY, 1, 10)
+		.build()
+		.execute_with(|| {
+			let mut dustable = Duster::dustable_accounts(None, 10);
+			dustable.sort();
+
+			// currency 2 and 3 have zero minimum deposit
+			assert_eq!(dustable, vec![(*ALICE, 1, 100), (*BOB, 1, 50)]);
+			assert_eq!(Duster::dustable_accounts(None, 1).len(), 1);
+		});
+}
+
+#[test]
+fn offchain_worker_should_submit_unsigned_dust_transaction() {
+	use codec::Decode;
+	use frame_support::traits::Hooks;
+	use sp_core::offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	};
+
+	let mut ext = ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.with_balance(*BOB, 1, 1_000_000)
+		.build();
+	let (offchain, _offchain_state) = TestOffchainExt::with_offchain_db(ext.offchain_db());
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		<Duster as Hooks<u64>>::offchain_worker(1);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+		assert_eq!(
+			tx.call,
+			crate::mock::RuntimeCall::Duster(crate::Call::dust_accounts {
+				accounts: vec![(*ALICE, 1)].try_into().unwrap(),
+			})
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_should_reject_external_transactions() {
+	use frame_support::{pallet_prelude::TransactionSource, unsigned::ValidateUnsigned};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let call = crate::Call::dust_accounts {
+			accounts: vec![(*ALICE, 1)].try_into().unwrap(),
+		};
+
+		assert!(Duster::validate_unsigned(TransactionSource::External, &call).is_err());
+		assert!(Duster::validate_unsigned(TransactionSource::Local, &call).is_ok());
+	});
+}
+
+#[test]
+fn offchain_worker_should_continue_from_last_submitted_account_when_batch_is_full() {
+	use codec::Decode;
+	use frame_support::traits::Hooks;
+	use sp_core::offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	};
+
+	let mut ext = ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.with_balance(*BOB, 1, 50)
+		.with_balance(*DUSTER, 1, 10)
+		.build();
+	let (offchain, _offchain_state) = TestOffchainExt::with_offchain_db(ext.offchain_db());
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let submitted_accounts = || {
+			let tx = pool_state.write().transactions.pop().unwrap();
+			match Extrinsic::decode(&mut &*tx).unwrap().call {
+				crate::mock::RuntimeCall::Duster(crate::Call::dust_accounts { accounts }) => accounts.into_inner(),
+				_ => panic!("unexpected call"),
+			}
+		};
+
+		// all three balances are scanned in one run, but only `MaxDustBatch` accounts fit into the batch
+		<Duster as Hooks<u64>>::offchain_worker(1);
+		let mut accounts = submitted_accounts();
+		assert_eq!(accounts.len(), 2);
+
+		// the remaining account is submitted in the next run
+		<Duster as Hooks<u64>>::offchain_worker(2);
+		accounts.extend(submitted_accounts());
+		assert!(pool_state.read().transactions.is_empty());
+
+		accounts.sort();
+		assert_eq!(accounts, vec![(*ALICE, 1), (*BOB, 1), (*DUSTER, 1)]);
+	});
+}
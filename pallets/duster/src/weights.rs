@@ -14,6 +14,7 @@ pub trait WeightInfo {
 	fn dust_account() -> Weight;
 	fn add_nondustable_account() -> Weight;
 	fn remove_nondustable_account() -> Weight;
+	fn set_dust_reward() -> Weight;
 }
 
 /// Weights for claims using the hydraDX node and recommended hardware.
@@ -66,4 +67,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Duster::DustReward` (r:0 w:1)
+	/// Proof: `Duster::DustReward` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_dust_reward() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(20_702_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
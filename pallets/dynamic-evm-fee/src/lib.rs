@@ -42,6 +42,21 @@
 //! - When HDX decreases in value against ETH, the evm fee is decreased accordingly.
 //!
 //! This dual-criteria approach ensures that transaction fees remain fair and reflective of both market conditions and network demand.
+//!
+//! ### EIP-1559 Adjustment Based on EVM Gas Utilization
+//!
+//! The fee derived above serves as a floor. On top of it, a utilization multiplier follows the EIP-1559 rule:
+//!
+//! UtilizationMultiplier = UtilizationMultiplier * (1 + Elasticity * (GasUsed - TargetGas) / TargetGas)
+//!
+//! - `GasUsed`: EVM gas used in the previous block.
+//! - `TargetGas`: `TargetGasUtilization` of the block gas limit.
+//! - `Elasticity`: Maximum relative change of the multiplier per block.
+//!
+//! The multiplier never goes below one, so the base fee never goes below the oracle-derived floor,
+//! and it is capped so that the base fee does not exceed `MaxBaseFeePerGas`.
+//!
+//! BaseFeePerGas = Floor * UtilizationMultiplier
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::manual_inspect)]
@@ -65,11 +80,16 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::NativePriceOracle;
 use sp_core::U256;
+use sp_runtime::traits::{One, Saturating, Zero};
 use sp_runtime::FixedPointNumber;
-use sp_runtime::FixedU128;
+use sp_runtime::{FixedU128, Permill};
 
 pub const ETH_HDX_REFERENCE_PRICE: FixedU128 = FixedU128::from_inner(8945857934143137845); //Current onchain ETH price on at block #4,534,103
 
+fn gas_to_u128(gas: U256) -> u128 {
+	gas.min(U256::from(u128::MAX)).low_u128()
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::*;
@@ -108,6 +128,17 @@ pub mod pallet {
 		/// Base fee multiplier to scale fee for test environments
 		type BaseFeePerGasMultiplier: Get<FixedU128>;
 
+		/// EVM block gas limit.
+		type BlockGasLimit: Get<U256>;
+
+		/// Share of the block gas limit targeted by the EIP-1559 adjustment.
+		#[pallet::constant]
+		type TargetGasUtilization: Get<Permill>;
+
+		/// Maximum relative change of the base fee per block caused by gas utilization.
+		#[pallet::constant]
+		type Elasticity: Get<Permill>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -129,9 +160,27 @@ pub mod pallet {
 	#[pallet::getter(fn base_evm_fee)]
 	pub type BaseFeePerGas<T> = StorageValue<_, U256, ValueQuery, DefaultBaseFeePerGas<T>>;
 
+	#[pallet::type_value]
+	pub fn DefaultUtilizationMultiplier() -> FixedU128 {
+		FixedU128::one()
+	}
+
+	/// Multiplier of the oracle-derived base fee driven by EVM gas utilization of previous blocks.
+	#[pallet::storage]
+	#[pallet::getter(fn utilization_multiplier)]
+	pub type UtilizationMultiplier<T> = StorageValue<_, FixedU128, ValueQuery, DefaultUtilizationMultiplier>;
+
+	/// EVM gas used in the current block by all EVM executions, noted by the EVM runner.
+	/// Consumed at the beginning of the next block to adjust the utilization multiplier.
+	#[pallet::storage]
+	#[pallet::getter(fn block_gas_used)]
+	pub type BlockGasUsed<T> = StorageValue<_, U256, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			let gas_used = BlockGasUsed::<T>::take();
+
 			BaseFeePerGas::<T>::mutate(|old_base_fee_per_gas| {
 				let multiplier = T::FeeMultiplier::get();
 
@@ -161,8 +210,21 @@ pub mod pallet {
 
 				new_base_fee_per_gas = T::BaseFeePerGasMultiplier::get().saturating_mul_int(new_base_fee_per_gas);
 
+				let floor = new_base_fee_per_gas.clamp(T::MinBaseFeePerGas::get(), T::MaxBaseFeePerGas::get());
+
+				let mut multiplier = Self::next_utilization_multiplier(
+					UtilizationMultiplier::<T>::get(),
+					gas_to_u128(gas_used),
+					T::TargetGasUtilization::get() * gas_to_u128(T::BlockGasLimit::get()),
+				);
+				// Cap the multiplier so that it can start decreasing right after the congestion is over
+				if let Some(max_multiplier) = FixedU128::checked_from_rational(T::MaxBaseFeePerGas::get(), floor) {
+					multiplier = multiplier.min(max_multiplier.max(FixedU128::one()));
+				}
+				UtilizationMultiplier::<T>::put(multiplier);
+
 				*old_base_fee_per_gas =
-					U256::from(new_base_fee_per_gas.clamp(T::MinBaseFeePerGas::get(), T::MaxBaseFeePerGas::get()));
+					U256::from(multiplier.saturating_mul_int(floor).min(T::MaxBaseFeePerGas::get()));
 			});
 
 			T::WeightInfo::on_initialize()
@@ -176,6 +238,35 @@ pub mod pallet {
 		}
 	}
 }
+impl<T: Config> Pallet<T> {
+	/// Adds gas used by an EVM execution to the gas used in the current block.
+	pub fn note_gas_used(gas_used: U256) {
+		BlockGasUsed::<T>::mutate(|total| *total = total.saturating_add(gas_used));
+	}
+
+	/// Adjusts the multiplier by the gas utilization of the previous block relative to the target.
+	/// The relative change is bounded by `Elasticity` and the multiplier never goes below one.
+	pub fn next_utilization_multiplier(multiplier: FixedU128, gas_used: u128, target_gas: u128) -> FixedU128 {
+		if target_gas.is_zero() {
+			return multiplier;
+		}
+
+		// Bounded by one to limit the change per block by `Elasticity`
+		let ratio = FixedU128::checked_from_rational(gas_used.abs_diff(target_gas).min(target_gas), target_gas)
+			.unwrap_or_else(FixedU128::zero);
+
+		let change = FixedU128::from(T::Elasticity::get()).saturating_mul(ratio);
+
+		let multiplier = if gas_used >= target_gas {
+			multiplier.saturating_mul(FixedU128::one().saturating_add(change))
+		} else {
+			multiplier.saturating_mul(FixedU128::one().saturating_sub(change))
+		};
+
+		multiplier.max(FixedU128::one())
+	}
+}
+
 impl<T: Config> pallet_evm::FeeCalculator for Pallet<T> {
 	fn min_gas_price() -> (U256, Weight) {
 		let base_fee_per_gas = Self::base_evm_fee();
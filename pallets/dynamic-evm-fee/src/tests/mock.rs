@@ -30,10 +30,10 @@ use hydradx_traits::NativePriceOracle;
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::{BasicCurrencyAdapter, MockBoundErc20, MockErc20Currency};
 use pallet_transaction_payment::Multiplier;
-use sp_core::H256;
+use sp_core::{H256, U256};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup, One},
-	BuildStorage, FixedPointNumber, FixedU128,
+	BuildStorage, FixedPointNumber, FixedU128, Permill,
 };
 use sp_std::cell::RefCell;
 pub type AccountId = u64;
@@ -55,6 +55,8 @@ pub const HIGH_VALUE_CURRENCY: AssetId = 7000;
 
 pub const HIGH_ED: Balance = 5;
 
+pub const BLOCK_GAS_LIMIT: u128 = 1_000_000;
+
 pub const DEFAULT_ETH_HDX_ORACLE_PRICE: Ratio = Ratio::new(8945857934143137845, FixedU128::DIV);
 
 thread_local! {
@@ -91,6 +93,10 @@ parameter_types! {
 
 
 	pub ExchangeFeeRate: (u32, u32) = (2, 1_000);
+
+	pub BlockGasLimit: U256 = U256::from(BLOCK_GAS_LIMIT);
+	pub const TargetGasUtilization: Permill = Permill::from_percent(50);
+	pub const Elasticity: Permill = Permill::from_parts(125_000);
 }
 
 impl system::Config for Test {
@@ -183,6 +189,9 @@ impl Config for Test {
 	type NativePriceOracle = NativePriceOracleMock;
 	type WethAssetId = HdxAssetId;
 	type BaseFeePerGasMultiplier = BaseFeePerGasMultiplier;
+	type BlockGasLimit = BlockGasLimit;
+	type TargetGasUtilization = TargetGasUtilization;
+	type Elasticity = Elasticity;
 	type WeightInfo = ();
 }
 
@@ -237,6 +246,7 @@ impl pallet_currencies::Config for Test {
 	type NativeCurrency = BasicCurrencyAdapter<Test, Balances, Amount, u32>;
 	type Erc20Currency = MockErc20Currency<Test>;
 	type BoundErc20 = MockBoundErc20<Test>;
+	type AccountFreeze = ();
 	type ReserveAccount = ();
 	type GetNativeCurrencyId = HdxAssetId;
 	type WeightInfo = ();
@@ -310,3 +320,6 @@ pub fn set_oracle_price(price: Ratio) {
 		*v.borrow_mut() = price;
 	});
 }
+pub fn set_block_gas_used(gas_used: u128) {
+	crate::BlockGasUsed::<Test>::put(U256::from(gas_used));
+}
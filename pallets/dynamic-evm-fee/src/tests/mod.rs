@@ -1,2 +1,3 @@
 mod mock;
 mod on_initialize;
+mod utilization;
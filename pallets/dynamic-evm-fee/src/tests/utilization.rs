@@ -0,0 +1,139 @@
+use crate::tests::mock::DynamicEvmFee;
+use crate::tests::mock::*;
+use frame_support::traits::OnInitialize;
+use hydra_dx_math::types::Ratio;
+use sp_core::U256;
+use sp_runtime::{FixedPointNumber, FixedU128};
+
+#[test]
+fn should_increase_evm_fee_when_previous_block_is_full() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_block_gas_used(BLOCK_GAS_LIMIT);
+
+		DynamicEvmFee::on_initialize(1);
+
+		assert_eq!(
+			DynamicEvmFee::utilization_multiplier(),
+			FixedU128::from_rational(1125, 1000)
+		);
+		assert_eq!(DynamicEvmFee::base_evm_fee(), U256::from(16925625));
+	});
+}
+
+#[test]
+fn should_not_change_evm_fee_when_gas_used_is_at_target() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_block_gas_used(BLOCK_GAS_LIMIT);
+		DynamicEvmFee::on_initialize(1);
+
+		set_block_gas_used(BLOCK_GAS_LIMIT / 2);
+		DynamicEvmFee::on_initialize(2);
+
+		assert_eq!(
+			DynamicEvmFee::utilization_multiplier(),
+			FixedU128::from_rational(1125, 1000)
+		);
+		assert_eq!(DynamicEvmFee::base_evm_fee(), U256::from(16925625));
+	});
+}
+
+#[test]
+fn should_decrease_evm_fee_back_to_floor_when_blocks_are_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_block_gas_used(BLOCK_GAS_LIMIT);
+		DynamicEvmFee::on_initialize(1);
+		set_block_gas_used(BLOCK_GAS_LIMIT);
+		DynamicEvmFee::on_initialize(2);
+		assert_eq!(DynamicEvmFee::base_evm_fee(), U256::from(19041328));
+
+		DynamicEvmFee::on_initialize(3);
+		assert_eq!(DynamicEvmFee::base_evm_fee(), U256::from(16661162));
+
+		DynamicEvmFee::on_initialize(4);
+		assert_eq!(DynamicEvmFee::utilization_multiplier(), FixedU128::from(1));
+		assert_eq!(DynamicEvmFee::base_evm_fee(), U256::from(15045000));
+	});
+}
+
+#[test]
+fn should_not_decrease_evm_fee_below_oracle_floor() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_block_gas_used(0);
+
+		DynamicEvmFee::on_initialize(1);
+
+		assert_eq!(DynamicEvmFee::utilization_multiplier(), FixedU128::from(1));
+		assert_eq!(DynamicEvmFee::base_evm_fee(), U256::from(15045000));
+	});
+}
+
+#[test]
+fn should_apply_utilization_multiplier_to_updated_oracle_floor() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_block_gas_used(BLOCK_GAS_LIMIT);
+		DynamicEvmFee::on_initialize(1);
+
+		set_block_gas_used(BLOCK_GAS_LIMIT / 2);
+		set_oracle_price(Ratio::new(
+			DEFAULT_ETH_HDX_ORACLE_PRICE.n * 110 / 100,
+			DEFAULT_ETH_HDX_ORACLE_PRICE.d,
+		));
+		DynamicEvmFee::on_initialize(2);
+
+		// 16549499 * 1.125
+		assert_eq!(DynamicEvmFee::base_evm_fee(), U256::from(18618186));
+	});
+}
+
+#[test]
+fn should_bound_evm_fee_change_per_block_when_gas_used_exceeds_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_block_gas_used(BLOCK_GAS_LIMIT * 10);
+
+		DynamicEvmFee::on_initialize(1);
+
+		assert_eq!(
+			DynamicEvmFee::utilization_multiplier(),
+			FixedU128::from_rational(1125, 1000)
+		);
+	});
+}
+
+#[test]
+fn should_cap_utilization_multiplier_at_max_base_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		crate::UtilizationMultiplier::<Test>::put(FixedU128::from(2000));
+		set_block_gas_used(BLOCK_GAS_LIMIT);
+
+		DynamicEvmFee::on_initialize(1);
+
+		assert_eq!(
+			DynamicEvmFee::utilization_multiplier(),
+			FixedU128::checked_from_rational(14415000000u128, 15045000u128).unwrap()
+		);
+		let capped_fee = DynamicEvmFee::base_evm_fee();
+		assert!(capped_fee <= U256::from(14415000000u128));
+
+		set_block_gas_used(0);
+		DynamicEvmFee::on_initialize(2);
+
+		assert!(DynamicEvmFee::base_evm_fee() < capped_fee);
+	});
+}
+
+#[test]
+fn should_accumulate_gas_used_in_block_and_reset_it_on_initialize() {
+	ExtBuilder::default().build().execute_with(|| {
+		DynamicEvmFee::note_gas_used(U256::from(BLOCK_GAS_LIMIT / 2));
+		DynamicEvmFee::note_gas_used(U256::from(BLOCK_GAS_LIMIT / 2));
+		assert_eq!(DynamicEvmFee::block_gas_used(), U256::from(BLOCK_GAS_LIMIT));
+
+		DynamicEvmFee::on_initialize(1);
+
+		assert_eq!(DynamicEvmFee::block_gas_used(), U256::zero());
+		assert_eq!(
+			DynamicEvmFee::utilization_multiplier(),
+			FixedU128::from_rational(1125, 1000)
+		);
+	});
+}
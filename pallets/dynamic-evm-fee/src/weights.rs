@@ -29,13 +29,17 @@ impl WeightInfo for () {
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
 	/// Storage: `Parameters::IsTestnet` (r:1 w:0)
 	/// Proof: `Parameters::IsTestnet` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DynamicEvmFee::BlockGasUsed` (r:1 w:1)
+	/// Proof: `DynamicEvmFee::BlockGasUsed` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `DynamicEvmFee::UtilizationMultiplier` (r:1 w:1)
+	/// Proof: `DynamicEvmFee::UtilizationMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn on_initialize() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2978`
 		//  Estimated: `11666`
 		// Minimum execution time: 98_150_000 picoseconds.
 		Weight::from_parts(98_150_000, 11666)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
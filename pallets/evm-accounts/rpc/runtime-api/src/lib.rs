@@ -34,5 +34,8 @@ sp_api::decl_runtime_apis! {
 		/// Get the Substrate address from the EVM address.
 		/// Returns the truncated version of the address if the address wasn't bind.
 		fn account_id(evm_address: EvmAddress) -> AccountId;
+
+		/// Return the nonce of the EVM address which has to be included in the signed binding message.
+		fn binding_nonce(evm_address: EvmAddress) -> u64;
 	}
 }
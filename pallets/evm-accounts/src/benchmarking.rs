@@ -19,9 +19,74 @@ use super::*;
 use crate::Pallet as EVMAccounts;
 
 use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::Currency;
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
+use libsecp256k1::{sign, Message, PublicKey, SecretKey};
+use sp_core::ecdsa;
+use sp_io::hashing::blake2_256;
 use sp_std::prelude::*;
 
+fn evm_signer() -> (SecretKey, EvmAddress) {
+	let secret_key = SecretKey::parse(&[1; 32]).expect("valid secret key");
+	let public_key = PublicKey::from_secret_key(&secret_key).serialize();
+	(secret_key, EvmAddress::from_slice(&keccak_256(&public_key[1..])[12..]))
+}
+
+fn sign_binding<T: Config>(
+	secret_key: &SecretKey,
+	account: &T::AccountId,
+	nonce: u64,
+	deadline: BlockNumberFor<T>,
+) -> EvmSignature
+where
+	T::AccountId: AsRef<[u8; 32]> + frame_support::pallet_prelude::IsType<AccountId32>,
+{
+	let message = Pallet::<T>::binding_message(account, nonce, deadline);
+	let (rs, v) = sign(&Message::parse(&message), secret_key);
+
+	let mut signature = [0u8; 65];
+	signature[..64].copy_from_slice(&rs.serialize());
+	signature[64] = v.serialize();
+	signature
+}
+
+/// Funds the account with enough balance to hold the binding deposit.
+fn fund_binding_deposit<T: Config>(account: &T::AccountId) {
+	let amount = T::BindingDeposit::get()
+		.saturating_add(T::DepositCurrency::minimum_balance())
+		.saturating_mul(2);
+	T::DepositCurrency::make_free_balance_be(account, amount);
+}
+
+/// Substrate account controlled by an ECDSA key, so it can sign in the benchmarks without a keystore.
+fn account_signer<T: Config>(seed: u8) -> (SecretKey, T::AccountId)
+where
+	T::AccountId: frame_support::pallet_prelude::IsType<AccountId32>,
+{
+	let secret_key = SecretKey::parse(&[seed; 32]).expect("valid secret key");
+	let public_key = PublicKey::from_secret_key(&secret_key).serialize_compressed();
+	(secret_key, AccountId32::from(blake2_256(&public_key)).into())
+}
+
+fn sign_account_binding<T: Config>(
+	secret_key: &SecretKey,
+	evm_address: EvmAddress,
+	nonce: u64,
+	deadline: BlockNumberFor<T>,
+) -> MultiSignature
+where
+	T::AccountId: AsRef<[u8; 32]> + frame_support::pallet_prelude::IsType<AccountId32>,
+{
+	let message = Pallet::<T>::account_binding_message(evm_address, nonce, deadline);
+	let (rs, v) = sign(&Message::parse(&blake2_256(&message)), secret_key);
+
+	let mut signature = [0u8; 65];
+	signature[..64].copy_from_slice(&rs.serialize());
+	signature[64] = v.serialize();
+	MultiSignature::Ecdsa(ecdsa::Signature::from_raw(signature))
+}
+
 benchmarks! {
 	where_clause {
 		where T::AccountId: AsRef<[u8; 32]> + frame_support::pallet_prelude::IsType<AccountId32>,
@@ -97,5 +162,74 @@ benchmarks! {
 		assert!(!ApprovedContract::<T>::contains_key(evm_address));
 	}
 
+	bind_evm_address_with_signature {
+		let (user_secret_key, user) = account_signer::<T>(2);
+		fund_binding_deposit::<T>(&user);
+		let (secret_key, evm_address) = evm_signer();
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let signature = sign_binding::<T>(&secret_key, &user, 0, deadline);
+		let account_signature = sign_account_binding::<T>(&user_secret_key, evm_address, 0, deadline);
+
+	}: _(RawOrigin::None, user.clone(), evm_address, deadline, signature, account_signature)
+	verify {
+		assert_eq!(BoundAccount::<T>::get(evm_address), Some(user));
+	}
+
+	rebind_evm_address {
+		let (user_secret_key, user) = account_signer::<T>(2);
+		let new_user: T::AccountId = account("user", 1, 1);
+		fund_binding_deposit::<T>(&user);
+		fund_binding_deposit::<T>(&new_user);
+		let (secret_key, evm_address) = evm_signer();
+		let deadline = frame_system::Pallet::<T>::block_number();
+
+		let signature = sign_binding::<T>(&secret_key, &user, 0, deadline);
+		let account_signature = sign_account_binding::<T>(&user_secret_key, evm_address, 0, deadline);
+		EVMAccounts::<T>::bind_evm_address_with_signature(RawOrigin::None.into(), user, evm_address, deadline, signature, account_signature)?;
+
+		let signature = sign_binding::<T>(&secret_key, &new_user, 1, deadline);
+
+	}: _(RawOrigin::Signed(new_user.clone()), evm_address, deadline, signature)
+	verify {
+		assert_eq!(BoundAccount::<T>::get(evm_address), Some(new_user));
+	}
+
+	approve_contract_code {
+		let code_hash = H256::repeat_byte(1);
+		let audit_reference: BoundedVec<u8, T::MaxAuditReferenceLength> =
+			vec![1; T::MaxAuditReferenceLength::get() as usize].try_into().expect("audit reference within limit");
+		assert!(!ApprovedContractCode::<T>::contains_key(code_hash));
+
+	}: _(RawOrigin::Root, code_hash, audit_reference)
+	verify {
+		assert!(ApprovedContractCode::<T>::contains_key(code_hash));
+	}
+
+	disapprove_contract_code {
+		let code_hash = H256::repeat_byte(1);
+		EVMAccounts::<T>::approve_contract_code(RawOrigin::Root.into(), code_hash, BoundedVec::default())?;
+
+		assert!(ApprovedContractCode::<T>::contains_key(code_hash));
+
+	}: _(RawOrigin::Root, code_hash)
+	verify {
+		assert!(!ApprovedContractCode::<T>::contains_key(code_hash));
+	}
+
+	unbind_evm_address {
+		let (user_secret_key, user) = account_signer::<T>(2);
+		fund_binding_deposit::<T>(&user);
+		let (secret_key, evm_address) = evm_signer();
+		let deadline = frame_system::Pallet::<T>::block_number();
+
+		let signature = sign_binding::<T>(&secret_key, &user, 0, deadline);
+		let account_signature = sign_account_binding::<T>(&user_secret_key, evm_address, 0, deadline);
+		EVMAccounts::<T>::bind_evm_address_with_signature(RawOrigin::None.into(), user.clone(), evm_address, deadline, signature, account_signature)?;
+
+	}: _(RawOrigin::Signed(user))
+	verify {
+		assert!(!BoundAccount::<T>::contains_key(evm_address));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
@@ -36,11 +36,36 @@
 //! With binding, we store the last 12 bytes of the Substrate address. Then we can get the original
 //! Substrate address by concatenating these 12 bytes stored in the storage to the EVM address.
 //!
+//! ### Binding with EVM signature
+//! Users who only have an EVM wallet can bind their EVM address to a full Substrate address by signing
+//! an EIP-712 message `BindEvmAddress(bytes32 account,uint256 nonce,uint256 deadline)` with the EVM key.
+//! The Substrate account has to accept the binding by signing the message returned by `account_binding_message`,
+//! so nobody can bind an EVM address to an account without its consent.
+//! The signed binding is submitted as an unsigned extrinsic, so the EVM address doesn't need to hold native currency.
+//! Instead, `BindingDeposit` is reserved from the Substrate account for the stored binding and returned when the binding
+//! is removed. Binding is rejected if the Substrate account can't hold the deposit.
+//!
+//! While the binding exists, the bound EVM address is used as the EVM address of the Substrate account everywhere,
+//! including balances of ERC20 assets in `Currencies`. Balances held by the EVM address derived from the first 20 bytes
+//! of the Substrate address are not reachable by the Substrate account until the binding is removed.
+//! Every EVM address lookup of a Substrate account reads `BoundEvmAddress`.
+//! Because the Substrate address doesn't share the first 20 bytes with the EVM address, the whole Substrate address
+//! is stored. Assets held by the truncated address are not moved and should be transferred before binding.
+//!
+//! Bound EVM address can be rebound to a different Substrate address. Rebinding requires a signature of the EVM key
+//! and has to be submitted by the new Substrate address.
+//!
+//! Substrate address can remove its binding by `unbind_evm_address`.
+//!
 //! ### Smart contract deployment
 //! This pallet also allows granting a permission to deploy smart contracts.
 //! `ControllerOrigin` can add this permission to EVM addresses.
 //! The list of whitelisted accounts is stored in the storage of this pallet.
 //!
+//! ### Approving smart contract code
+//! `ControllerOrigin` can approve init code hash of audited contracts. Contracts with approved init code hash
+//! can be deployed by any address, not only by whitelisted deployers. Each approval records the audit reference.
+//!
 //! ### Approving smart contracts
 //! This pallet is also used to control which contracts are allowed to manage balances and tokens.
 //! `ApprovedContract` storage is used by the currencies precompile to determine whenever contract is allowed to transfer or not.
@@ -48,6 +73,12 @@
 //! ### Dispatchable Functions
 //!
 //! * `bind_evm_address` - Binds a Substrate address to EVM address.
+//! * `bind_evm_address_with_signature` - Binds an EVM address to a Substrate address using signatures of the EVM key
+//!   and the Substrate account.
+//! * `rebind_evm_address` - Rebinds an EVM address to the caller using a signature of the EVM key.
+//! * `unbind_evm_address` - Removes the binding of the caller's EVM address.
+//! * `approve_contract_code` - Approves init code hash of a contract for deployment by any address.
+//! * `disapprove_contract_code` - Removes init code hash from the list of approved contract code.
 //! * `add_contract_deployer` - Adds a permission to deploy smart contracts.
 //! * `remove_contract_deployer` - Removes a permission of whitelisted address to deploy smart contracts.
 //! * `renounce_contract_deployer` - Renounce caller's permission to deploy smart contracts.
@@ -57,13 +88,21 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::manual_inspect)]
 
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::ensure;
-use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::pallet_prelude::{DispatchResult, Get, RuntimeDebug};
+use frame_support::sp_runtime::traits::{UniqueSaturatedInto, Verify};
+use frame_support::sp_runtime::MultiSignature;
+use frame_support::traits::ReservableCurrency;
+use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::evm::InspectEvmAccounts;
+use scale_info::TypeInfo;
 use sp_core::{
 	crypto::{AccountId32, ByteArray},
-	H160, U256,
+	H160, H256, U256,
 };
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod mock;
@@ -79,6 +118,22 @@ pub use weights::WeightInfo;
 pub type Balance = u128;
 pub type EvmAddress = H160;
 pub type AccountIdLast12Bytes = [u8; 12];
+pub type EvmSignature = [u8; 65];
+
+pub const EIP712_DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version,uint256 chainId)";
+pub const EIP712_DOMAIN_NAME: &[u8] = b"HydraDX EVM Accounts";
+pub const EIP712_DOMAIN_VERSION: &[u8] = b"1";
+pub const BIND_EVM_ADDRESS_TYPE: &[u8] = b"BindEvmAddress(bytes32 account,uint256 nonce,uint256 deadline)";
+pub const ACCEPT_EVM_ADDRESS_PREFIX: &[u8] = b"HydraDX EVM Accounts: accept binding of";
+
+/// Approval of contract init code.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ApprovedCodeInfo<AuditReference, BlockNumber> {
+	/// Reference to the audit of the contract, e.g. link or identifier of the audit report.
+	pub audit_reference: AuditReference,
+	/// Block in which the code was approved.
+	pub approved_at: BlockNumber,
+}
 
 pub trait EvmNonceProvider {
 	fn get_nonce(evm_address: H160) -> U256;
@@ -88,6 +143,7 @@ pub trait EvmNonceProvider {
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
+	use frame_support::sp_runtime::ModuleError;
 	use frame_system::pallet_prelude::*;
 
 	#[pallet::pallet]
@@ -108,6 +164,20 @@ pub mod pallet {
 		/// Origin that can whitelist addresses for smart contract deployment.
 		type ControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// EVM chain id used in the EIP-712 domain of binding signatures.
+		type ChainId: Get<u64>;
+
+		/// Max length of the audit reference of approved contract code.
+		#[pallet::constant]
+		type MaxAuditReferenceLength: Get<u32>;
+
+		/// Currency used to hold binding deposits.
+		type DepositCurrency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+		/// Deposit held from the Substrate account bound by the signature of the EVM key.
+		#[pallet::constant]
+		type BindingDeposit: Get<Balance>;
+
 		/// Weight information for extrinsic in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -117,19 +187,53 @@ pub mod pallet {
 	#[pallet::getter(fn account)]
 	pub(super) type AccountExtension<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, AccountIdLast12Bytes>;
 
+	/// Maps an EVM address to a full substrate account bound by a signature of the EVM address.
+	#[pallet::storage]
+	pub(super) type BoundAccount<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, T::AccountId>;
+
+	/// Maps a substrate account to the EVM address bound by a signature of the EVM address.
+	#[pallet::storage]
+	pub(super) type BoundEvmAddress<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, EvmAddress>;
+
+	/// Nonce of the EVM address included in the signed binding message. Increased with every signed binding.
+	#[pallet::storage]
+	#[pallet::getter(fn binding_nonce)]
+	pub(super) type BindingNonce<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, u64, ValueQuery>;
+
 	/// Whitelisted addresses that are allowed to deploy smart contracts.
 	#[pallet::storage]
 	pub(super) type ContractDeployer<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, ()>;
 
+	/// Approved init code hashes of contracts that can be deployed by any address.
+	#[pallet::storage]
+	#[pallet::getter(fn approved_contract_code)]
+	pub(super) type ApprovedContractCode<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		H256,
+		ApprovedCodeInfo<BoundedVec<u8, T::MaxAuditReferenceLength>, BlockNumberFor<T>>,
+	>;
+
 	/// Whitelisted contracts that are allowed to manage balances and tokens.
 	#[pallet::storage]
 	pub(super) type ApprovedContract<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, ()>;
 
+	/// Deposit held from the Substrate account bound by the signature of the EVM key.
+	#[pallet::storage]
+	#[pallet::getter(fn binding_deposit_of)]
+	pub(super) type BindingDepositOf<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Binding was created.
 		Bound { account: T::AccountId, address: EvmAddress },
+		/// EVM address was rebound to a different account.
+		Rebound {
+			old_account: T::AccountId,
+			account: T::AccountId,
+			address: EvmAddress,
+		},
 		/// Deployer was added.
 		DeployerAdded { who: EvmAddress },
 		/// Deployer was removed.
@@ -138,6 +242,15 @@ pub mod pallet {
 		ContractApproved { address: EvmAddress },
 		/// Contract was disapproved.
 		ContractDisapproved { address: EvmAddress },
+		/// Contract init code hash was approved.
+		ContractCodeApproved {
+			code_hash: H256,
+			audit_reference: BoundedVec<u8, T::MaxAuditReferenceLength>,
+		},
+		/// Contract init code hash was disapproved.
+		ContractCodeDisapproved { code_hash: H256 },
+		/// Binding was removed.
+		Unbound { account: T::AccountId, address: EvmAddress },
 	}
 
 	#[pallet::error]
@@ -151,6 +264,18 @@ pub mod pallet {
 		BoundAddressCannotBeUsed,
 		/// Address not whitelisted
 		AddressNotWhitelisted,
+		/// Account is already bound to an EVM address
+		AccountAlreadyBound,
+		/// Address is not bound
+		AddressNotBound,
+		/// Signature is not valid or is not signed by the EVM address
+		InvalidSignature,
+		/// Signature deadline has passed
+		SignatureExpired,
+		/// Signature is not valid or is not signed by the Substrate account
+		InvalidAccountSignature,
+		/// Substrate account can't hold the binding deposit
+		InsufficientBalanceForBindingDeposit,
 	}
 
 	#[pallet::hooks]
@@ -203,7 +328,7 @@ pub mod pallet {
 			// on the nonce. So it's better to prevent any confusion and throw an error when address is
 			// already bound.
 			ensure!(
-				!AccountExtension::<T>::contains_key(evm_address),
+				Self::bound_account_id(evm_address).is_none(),
 				Error::<T>::AddressAlreadyBound
 			);
 
@@ -314,6 +439,231 @@ pub mod pallet {
 			Self::deposit_event(Event::ContractDisapproved { address });
 			Ok(())
 		}
+
+		/// Binds an EVM address to a full Substrate address using signatures of both the EVM key and
+		/// the Substrate account. It allows users to bind their EVM address without submitting
+		/// a signed Substrate extrinsic.
+		///
+		/// The EVM key signs the EIP-712 message `BindEvmAddress(bytes32 account,uint256 nonce,uint256 deadline)`.
+		/// The Substrate account accepts the binding by signing `account_binding_message`, either as raw bytes
+		/// or wrapped in `<Bytes></Bytes>`.
+		/// Current nonce of the EVM address can be queried by `binding_nonce`.
+		///
+		/// `BindingDeposit` is reserved from `account` and returned when the binding is removed or rebound.
+		/// Transaction is rejected by `validate_unsigned` if `account` can't hold the deposit.
+		///
+		/// After binding, `evm_address` is the EVM address of `account`. ERC20 balances of the EVM address derived
+		/// from `account` are not accessible through `Currencies` until the binding is removed.
+		///
+		/// Parameters:
+		/// - `origin`: None. Signatures are verified in `validate_unsigned`.
+		/// - `account`: Substrate address the EVM address is bound to
+		/// - `evm_address`: EVM address that is bound
+		/// - `deadline`: last block in which the signatures are valid
+		/// - `signature`: EIP-712 signature of the EVM key
+		/// - `account_signature`: signature of the Substrate account
+		///
+		/// Emits `Bound` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::bind_evm_address_with_signature())]
+		pub fn bind_evm_address_with_signature(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			evm_address: EvmAddress,
+			deadline: BlockNumberFor<T>,
+			signature: EvmSignature,
+			account_signature: MultiSignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			Self::ensure_can_bind_with_signature(&account, evm_address, deadline, &signature, &account_signature)?;
+
+			Self::do_bind_with_signature(&account, evm_address)?;
+
+			Self::deposit_event(Event::Bound {
+				account,
+				address: evm_address,
+			});
+
+			Ok(())
+		}
+
+		/// Rebinds a bound EVM address to the caller using a signature of the EVM key.
+		///
+		/// The EVM key signs the same EIP-712 message as in `bind_evm_address_with_signature` with the caller
+		/// as the `account`. Previous binding of the EVM address is removed and its deposit is returned
+		/// to the previous account. `BindingDeposit` is reserved from the caller.
+		///
+		/// Parameters:
+		/// - `origin`: Substrate address the EVM address is rebound to
+		/// - `evm_address`: EVM address that is rebound
+		/// - `deadline`: last block in which the signature is valid
+		/// - `signature`: EIP-712 signature of the EVM key
+		///
+		/// Emits `Rebound` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::rebind_evm_address())]
+		pub fn rebind_evm_address(
+			origin: OriginFor<T>,
+			evm_address: EvmAddress,
+			deadline: BlockNumberFor<T>,
+			signature: EvmSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::verify_binding_signature(&who, evm_address, deadline, &signature)?;
+
+			ensure!(
+				!Self::is_evm_account(who.clone()),
+				Error::<T>::TruncatedAccountAlreadyUsed
+			);
+			let old_account = Self::bound_account_id(evm_address).ok_or(Error::<T>::AddressNotBound)?;
+			ensure!(!Self::has_bound_evm_address(&who), Error::<T>::AccountAlreadyBound);
+
+			<AccountExtension<T>>::remove(evm_address);
+			<BoundEvmAddress<T>>::remove(&old_account);
+			Self::release_binding_deposit(&old_account);
+			Self::do_bind_with_signature(&who, evm_address)?;
+
+			Self::deposit_event(Event::Rebound {
+				old_account,
+				account: who,
+				address: evm_address,
+			});
+
+			Ok(())
+		}
+
+		/// Removes the binding of the caller's EVM address.
+		/// After unbinding, the EVM converts the EVM address to its truncated Substrate address again.
+		///
+		/// Binding nonce of the EVM address is kept, so signatures of previous bindings can't be replayed.
+		/// Deposit held for a binding by the signature of the EVM key is returned.
+		/// Address bound by `bind_evm_address` can't be bound by `bind_evm_address` again once it has been used in the EVM.
+		///
+		/// Parameters:
+		/// - `origin`: Substrate address that is bound
+		///
+		/// Emits `Unbound` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::unbind_evm_address())]
+		pub fn unbind_evm_address(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let address = if let Some(address) = <BoundEvmAddress<T>>::take(&who) {
+				<BoundAccount<T>>::remove(address);
+				Self::release_binding_deposit(&who);
+				address
+			} else {
+				let address = EvmAddress::from_slice(&who.as_ref()[..20]);
+				ensure!(
+					Self::bound_account_id(address).as_ref() == Some(&who),
+					Error::<T>::AddressNotBound
+				);
+				<AccountExtension<T>>::remove(address);
+				address
+			};
+
+			Self::deposit_event(Event::Unbound { account: who, address });
+
+			Ok(())
+		}
+
+		/// Approves init code hash of a contract. Contracts with approved init code hash can be deployed
+		/// by any address. Approving already approved code hash updates its audit reference.
+		///
+		/// Init code hash is `keccak256` of the whole init code, including constructor arguments.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `ControllerOrigin`.
+		/// - `code_hash`: init code hash that is approved
+		/// - `audit_reference`: reference to the audit of the contract
+		///
+		/// Emits `ContractCodeApproved` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_contract_code())]
+		pub fn approve_contract_code(
+			origin: OriginFor<T>,
+			code_hash: H256,
+			audit_reference: BoundedVec<u8, T::MaxAuditReferenceLength>,
+		) -> DispatchResult {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			<ApprovedContractCode<T>>::insert(
+				code_hash,
+				ApprovedCodeInfo {
+					audit_reference: audit_reference.clone(),
+					approved_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::ContractCodeApproved {
+				code_hash,
+				audit_reference,
+			});
+
+			Ok(())
+		}
+
+		/// Removes init code hash from the list of approved contract code.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `ControllerOrigin`.
+		/// - `code_hash`: init code hash that is disapproved
+		///
+		/// Emits `ContractCodeDisapproved` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::disapprove_contract_code())]
+		pub fn disapprove_contract_code(origin: OriginFor<T>, code_hash: H256) -> DispatchResult {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			<ApprovedContractCode<T>>::remove(code_hash);
+
+			Self::deposit_event(Event::ContractCodeDisapproved { code_hash });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T>
+	where
+		T::AccountId: AsRef<[u8; 32]> + frame_support::traits::IsType<AccountId32>,
+	{
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::bind_evm_address_with_signature {
+					account,
+					evm_address,
+					deadline,
+					signature,
+					account_signature,
+				} => match Self::ensure_can_bind_with_signature(
+					account,
+					*evm_address,
+					*deadline,
+					signature,
+					account_signature,
+				) {
+					Ok(()) => ValidTransaction::with_tag_prefix("EVMAccountsBind")
+						.and_provides((BindingNonce::<T>::get(evm_address), evm_address))
+						.priority(0)
+						.longevity(64)
+						.propagate(true)
+						.build(),
+					Err(e) => {
+						let error_number = match e {
+							DispatchError::Module(ModuleError { error, .. }) => error[0],
+							_ => 0,
+						};
+						InvalidTransaction::Custom(error_number).into()
+					}
+				},
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
 	}
 }
 
@@ -323,6 +673,156 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T>
+where
+	T::AccountId: AsRef<[u8; 32]> + frame_support::traits::IsType<AccountId32>,
+{
+	/// EIP-712 hash of the message signed by the EVM key to bind `account` to the EVM address.
+	pub fn binding_message(account: &T::AccountId, nonce: u64, deadline: BlockNumberFor<T>) -> [u8; 32] {
+		let domain_separator = keccak_256(
+			&[
+				keccak_256(EIP712_DOMAIN_TYPE),
+				keccak_256(EIP712_DOMAIN_NAME),
+				keccak_256(EIP712_DOMAIN_VERSION),
+				Self::encode_uint(T::ChainId::get()),
+			]
+			.concat(),
+		);
+
+		let struct_hash = keccak_256(
+			&[
+				keccak_256(BIND_EVM_ADDRESS_TYPE),
+				*account.as_ref(),
+				Self::encode_uint(nonce),
+				Self::encode_uint(deadline.unique_saturated_into()),
+			]
+			.concat(),
+		);
+
+		let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+		pre_digest.extend_from_slice(b"\x19\x01");
+		pre_digest.extend_from_slice(&domain_separator);
+		pre_digest.extend_from_slice(&struct_hash);
+		keccak_256(&pre_digest)
+	}
+
+	/// Message signed by the Substrate account to accept binding of the EVM address.
+	pub fn account_binding_message(evm_address: EvmAddress, nonce: u64, deadline: BlockNumberFor<T>) -> Vec<u8> {
+		(
+			ACCEPT_EVM_ADDRESS_PREFIX,
+			T::ChainId::get(),
+			evm_address,
+			nonce,
+			deadline,
+		)
+			.encode()
+	}
+
+	/// ABI encoding of an unsigned integer as `uint256`.
+	fn encode_uint(value: u64) -> [u8; 32] {
+		let mut encoded = [0u8; 32];
+		encoded[24..].copy_from_slice(&value.to_be_bytes());
+		encoded
+	}
+
+	fn verify_binding_signature(
+		account: &T::AccountId,
+		evm_address: EvmAddress,
+		deadline: BlockNumberFor<T>,
+		signature: &EvmSignature,
+	) -> DispatchResult {
+		ensure!(
+			deadline >= frame_system::Pallet::<T>::block_number(),
+			Error::<T>::SignatureExpired
+		);
+
+		let message = Self::binding_message(account, BindingNonce::<T>::get(evm_address), deadline);
+		let signer = secp256k1_ecdsa_recover(signature, &message).map_err(|_| Error::<T>::InvalidSignature)?;
+		let signer = EvmAddress::from_slice(&keccak_256(&signer)[12..]);
+		ensure!(
+			signer != EvmAddress::zero() && signer == evm_address,
+			Error::<T>::InvalidSignature
+		);
+
+		Ok(())
+	}
+
+	/// Verifies that the Substrate account accepted binding of the EVM address.
+	/// Signature of the raw message and of the message wrapped in `<Bytes></Bytes>` by wallets are accepted.
+	fn verify_account_signature(
+		account: &T::AccountId,
+		evm_address: EvmAddress,
+		deadline: BlockNumberFor<T>,
+		signature: &MultiSignature,
+	) -> DispatchResult {
+		let message = Self::account_binding_message(evm_address, BindingNonce::<T>::get(evm_address), deadline);
+		let wrapped_message = [b"<Bytes>".as_slice(), message.as_slice(), b"</Bytes>".as_slice()].concat();
+		let account: AccountId32 = account.clone().into();
+
+		ensure!(
+			signature.verify(message.as_slice(), &account) || signature.verify(wrapped_message.as_slice(), &account),
+			Error::<T>::InvalidAccountSignature
+		);
+
+		Ok(())
+	}
+
+	fn ensure_can_bind_with_signature(
+		account: &T::AccountId,
+		evm_address: EvmAddress,
+		deadline: BlockNumberFor<T>,
+		signature: &EvmSignature,
+		account_signature: &MultiSignature,
+	) -> DispatchResult {
+		Self::verify_binding_signature(account, evm_address, deadline, signature)?;
+
+		ensure!(
+			!Self::is_evm_account(account.clone()),
+			Error::<T>::TruncatedAccountAlreadyUsed
+		);
+
+		Self::verify_account_signature(account, evm_address, deadline, account_signature)?;
+
+		ensure!(
+			Self::bound_account_id(evm_address).is_none(),
+			Error::<T>::AddressAlreadyBound
+		);
+		ensure!(!Self::has_bound_evm_address(account), Error::<T>::AccountAlreadyBound);
+		ensure!(
+			T::DepositCurrency::can_reserve(account, T::BindingDeposit::get()),
+			Error::<T>::InsufficientBalanceForBindingDeposit
+		);
+
+		Ok(())
+	}
+
+	fn do_bind_with_signature(account: &T::AccountId, evm_address: EvmAddress) -> DispatchResult {
+		let deposit = T::BindingDeposit::get();
+		T::DepositCurrency::reserve(account, deposit).map_err(|_| Error::<T>::InsufficientBalanceForBindingDeposit)?;
+		<BindingDepositOf<T>>::insert(account, deposit);
+
+		<BoundAccount<T>>::insert(evm_address, account.clone());
+		<BoundEvmAddress<T>>::insert(account.clone(), evm_address);
+		<BindingNonce<T>>::mutate(evm_address, |nonce| *nonce = nonce.saturating_add(1));
+
+		Ok(())
+	}
+
+	/// Returns the deposit held for the binding of the account by the signature of the EVM key.
+	fn release_binding_deposit(account: &T::AccountId) {
+		if let Some(deposit) = <BindingDepositOf<T>>::take(account) {
+			T::DepositCurrency::unreserve(account, deposit);
+		}
+	}
+
+	/// Returns `True` if the account is bound to an EVM address, either by the signature of the EVM address
+	/// or by binding its own EVM address.
+	fn has_bound_evm_address(account: &T::AccountId) -> bool {
+		BoundEvmAddress::<T>::contains_key(account)
+			|| Self::bound_account_id(EvmAddress::from_slice(&account.as_ref()[..20])).as_ref() == Some(account)
+	}
+}
+
 impl<T: Config> InspectEvmAccounts<T::AccountId> for Pallet<T>
 where
 	T::AccountId: AsRef<[u8; 32]> + frame_support::traits::IsType<AccountId32>,
@@ -334,8 +834,13 @@ where
 	}
 
 	/// Get the EVM address from the substrate address.
+	/// Reads `BoundEvmAddress` to check whether the account was bound by the signature of the EVM key.
 	fn evm_address(account_id: &impl AsRef<[u8; 32]>) -> EvmAddress {
 		let acc = account_id.as_ref();
+		if let Some(evm_address) = BoundEvmAddress::<T>::get(T::AccountId::from(AccountId32::from(*acc))) {
+			return evm_address;
+		}
+
 		if Self::_is_evm_account(acc) {
 			EvmAddress::from_slice(&acc[4..24])
 		} else {
@@ -353,7 +858,9 @@ where
 
 	/// Return the Substrate address bound to the EVM account. If not bound, returns `None`.
 	fn bound_account_id(evm_address: EvmAddress) -> Option<T::AccountId> {
-		let last_12_bytes = AccountExtension::<T>::get(evm_address)?;
+		let Some(last_12_bytes) = AccountExtension::<T>::get(evm_address) else {
+			return BoundAccount::<T>::get(evm_address);
+		};
 		let mut data: [u8; 32] = [0u8; 32];
 		data[..20].copy_from_slice(evm_address.0.as_ref());
 		data[20..32].copy_from_slice(&last_12_bytes);
@@ -375,4 +882,9 @@ where
 	fn is_approved_contract(evm_address: EvmAddress) -> bool {
 		ApprovedContract::<T>::contains_key(evm_address)
 	}
+
+	/// Returns `True` if contracts with the given init code hash can be deployed by any address.
+	fn is_approved_contract_code(code_hash: H256) -> bool {
+		ApprovedContractCode::<T>::contains_key(code_hash)
+	}
 }
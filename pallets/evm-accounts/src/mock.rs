@@ -1,5 +1,5 @@
 use crate as pallet_evm_accounts;
-use crate::{Balance, Config, EvmNonceProvider};
+use crate::{Balance, Config, EvmAddress, EvmNonceProvider, EvmSignature};
 use frame_support::parameter_types;
 use frame_support::sp_runtime::{
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
@@ -8,8 +8,10 @@ use frame_support::sp_runtime::{
 use frame_support::traits::Everything;
 use frame_system::EnsureRoot;
 use hydradx_traits::evm::InspectEvmAccounts;
+use libsecp256k1::{sign, Message, PublicKey, SecretKey};
 use orml_traits::parameter_type_with_key;
-pub use sp_core::{H160, H256, U256};
+pub use sp_core::{sr25519, Pair, H160, H256, U256};
+use sp_io::hashing::keccak_256;
 use std::cell::RefCell;
 use std::collections::HashMap;
 
@@ -22,6 +24,7 @@ pub const ONE: Balance = 1_000_000_000_000;
 pub const INITIAL_BALANCE: Balance = 1_000_000_000_000 * ONE;
 
 pub const ALICE: AccountId = AccountId::new([1; 32]);
+pub const CHARLIE: AccountId = AccountId::new([3; 32]);
 
 pub const HDX: AssetId = 0;
 
@@ -43,6 +46,7 @@ parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 63;
 	pub const NativeAssetId: AssetId = HDX;
+	pub const BindingDeposit: Balance = ONE;
 }
 
 pub struct EvmNonceProviderMock;
@@ -59,6 +63,10 @@ impl Config for Test {
 	type FeeMultiplier = sp_core::ConstU32<10>;
 	type EvmNonceProvider = EvmNonceProviderMock;
 	type ControllerOrigin = EnsureRoot<AccountId>;
+	type ChainId = sp_core::ConstU64<222_222>;
+	type MaxAuditReferenceLength = sp_core::ConstU32<128>;
+	type DepositCurrency = orml_tokens::CurrencyAdapter<Test, NativeAssetId>;
+	type BindingDeposit = BindingDeposit;
 	type WeightInfo = ();
 }
 
@@ -125,7 +133,12 @@ impl Default for ExtBuilder {
 		});
 
 		Self {
-			endowed_accounts: vec![(ALICE, HDX, INITIAL_BALANCE)],
+			endowed_accounts: vec![
+				(ALICE, HDX, INITIAL_BALANCE),
+				(CHARLIE, HDX, INITIAL_BALANCE),
+				(account_of(&account_pair(2)), HDX, INITIAL_BALANCE),
+				(account_of(&account_pair(3)), HDX, INITIAL_BALANCE),
+			],
 		}
 	}
 }
@@ -158,3 +171,40 @@ impl ExtBuilder {
 pub fn expect_events(e: Vec<RuntimeEvent>) {
 	test_utils::expect_events::<RuntimeEvent, Test>(e);
 }
+
+pub fn evm_secret_key(seed: u8) -> SecretKey {
+	SecretKey::parse(&[seed; 32]).unwrap()
+}
+
+pub fn evm_address_of(secret_key: &SecretKey) -> EvmAddress {
+	let public_key = PublicKey::from_secret_key(secret_key).serialize();
+	EvmAddress::from_slice(&keccak_256(&public_key[1..])[12..])
+}
+
+pub fn sign_binding(secret_key: &SecretKey, account: &AccountId, nonce: u64, deadline: u64) -> EvmSignature {
+	let message = EVMAccounts::binding_message(account, nonce, deadline);
+	let (rs, v) = sign(&Message::parse(&message), secret_key);
+
+	let mut signature = [0u8; 65];
+	signature[..64].copy_from_slice(&rs.serialize());
+	signature[64] = v.serialize();
+	signature
+}
+
+pub fn account_pair(seed: u8) -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[seed; 32])
+}
+
+pub fn account_of(pair: &sr25519::Pair) -> AccountId {
+	pair.public().into()
+}
+
+pub fn sign_account_binding(
+	pair: &sr25519::Pair,
+	evm_address: EvmAddress,
+	nonce: u64,
+	deadline: u64,
+) -> MultiSignature {
+	pair.sign(&EVMAccounts::account_binding_message(evm_address, nonce, deadline))
+		.into()
+}
@@ -18,7 +18,9 @@
 use super::*;
 use mock::*;
 
+use frame_support::pallet_prelude::{TransactionSource, ValidateUnsigned};
 use frame_support::{assert_noop, assert_ok};
+use frame_support::{traits::ConstU32, BoundedVec};
 use hex_literal::hex;
 
 #[test]
@@ -199,3 +201,626 @@ fn renounce_contract_deployer_should_remove_address_from_the_storage() {
 		assert_ok!(EVMAccounts::renounce_contract_deployer(RuntimeOrigin::signed(ALICE)));
 	});
 }
+
+#[test]
+fn bind_evm_address_with_signature_should_bind_evm_address_to_the_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+
+		// Act
+		assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+			RuntimeOrigin::none(),
+			bob_account.clone(),
+			evm_address,
+			10,
+			sign_binding(&secret_key, &bob_account, 0, 10),
+			sign_account_binding(&bob, evm_address, 0, 10)
+		));
+
+		// Assert
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), Some(bob_account.clone()));
+		assert_eq!(EVMAccounts::account_id(evm_address), bob_account);
+		assert_eq!(EVMAccounts::evm_address(&bob_account), evm_address);
+		assert_eq!(EVMAccounts::binding_nonce(evm_address), 1);
+		assert_eq!(
+			EVMAccounts::binding_deposit_of(&bob_account),
+			Some(BindingDeposit::get())
+		);
+		assert_eq!(Tokens::accounts(&bob_account, HDX).reserved, BindingDeposit::get());
+		expect_events(vec![Event::Bound {
+			account: bob_account,
+			address: evm_address,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn binding_message_should_match_eip712_typed_data_hash() {
+	// The vector was computed independently of the pallet: the EIP-712 hash with `keccak-256` of OpenSSL
+	// and the signature by RFC 6979 ECDSA with low `s` and `v` of 27/28, as returned by `signTypedData`.
+	// Domain: { name: "HydraDX EVM Accounts", version: "1", chainId: 222222 }
+	// Message: { account: 0x0101..01, nonce: 0, deadline: 10 }, signed by the private key 0x0101..01
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = EvmAddress::from(hex!["1a642f0e3c3af545e7acbd38b07251b3990914f1"]);
+		let signature = hex![
+			"e55725a5bed4739e8a582ae64eccfbece66531bdc8fea722c1a4c2e5d2ea32f0"
+			"2c805f7aa4315806ddacda86f20e8f63ec254d56bc6dd14967e7d043126393591c"
+		];
+
+		assert_eq!(evm_address_of(&evm_secret_key(1)), evm_address);
+		assert_eq!(
+			EVMAccounts::binding_message(&ALICE, 0, 10),
+			hex!["4964b0382bdbebb19e82761a993530985cf186359ff91a4f3a6b7fb5ddf11850"]
+		);
+		assert_ok!(EVMAccounts::verify_binding_signature(
+			&ALICE,
+			evm_address,
+			10,
+			&signature
+		));
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_work_when_account_signed_wrapped_message() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+
+		let message = EVMAccounts::account_binding_message(evm_address, 0, 10);
+		let wrapped_message = [b"<Bytes>".as_slice(), message.as_slice(), b"</Bytes>".as_slice()].concat();
+
+		// Act
+		assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+			RuntimeOrigin::none(),
+			bob_account.clone(),
+			evm_address,
+			10,
+			sign_binding(&secret_key, &bob_account, 0, 10),
+			bob.sign(&wrapped_message).into()
+		));
+
+		// Assert
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), Some(bob_account));
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_signed_by_different_evm_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = evm_address_of(&evm_secret_key(1));
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				bob_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&evm_secret_key(2), &bob_account, 0, 10),
+				sign_account_binding(&bob, evm_address, 0, 10)
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_signed_for_different_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				account_of(&bob),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &CHARLIE, 0, 10),
+				sign_account_binding(&bob, evm_address, 0, 10)
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_not_signed_by_the_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob_account = account_of(&account_pair(2));
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				bob_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &bob_account, 0, 10),
+				sign_account_binding(&account_pair(3), evm_address, 0, 10)
+			),
+			Error::<Test>::InvalidAccountSignature
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_account_accepted_different_evm_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				bob_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &bob_account, 0, 10),
+				sign_account_binding(&bob, evm_address_of(&evm_secret_key(2)), 0, 10)
+			),
+			Error::<Test>::InvalidAccountSignature
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_deadline_passed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+
+		System::set_block_number(11);
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				bob_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &bob_account, 0, 10),
+				sign_account_binding(&bob, evm_address, 0, 10)
+			),
+			Error::<Test>::SignatureExpired
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_address_already_bound() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+		let charlie = account_pair(3);
+		let charlie_account = account_of(&charlie);
+		assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+			RuntimeOrigin::none(),
+			bob_account.clone(),
+			evm_address,
+			10,
+			sign_binding(&secret_key, &bob_account, 0, 10),
+			sign_account_binding(&bob, evm_address, 0, 10)
+		));
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				charlie_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &charlie_account, 1, 10),
+				sign_account_binding(&charlie, evm_address, 1, 10)
+			),
+			Error::<Test>::AddressAlreadyBound
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_account_already_bound() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice = account_pair(1);
+		let alice_account = account_of(&alice);
+		assert_ok!(EVMAccounts::bind_evm_address(RuntimeOrigin::signed(
+			alice_account.clone()
+		)));
+
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				alice_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &alice_account, 0, 10),
+				sign_account_binding(&alice, evm_address, 0, 10)
+			),
+			Error::<Test>::AccountAlreadyBound
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_binding_evm_truncated_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let truncated_account = EVMAccounts::truncated_account_id(evm_address);
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				truncated_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &truncated_account, 0, 10),
+				sign_account_binding(&account_pair(2), evm_address, 0, 10)
+			),
+			Error::<Test>::TruncatedAccountAlreadyUsed
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_origin_is_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::signed(bob_account.clone()),
+				bob_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &bob_account, 0, 10),
+				sign_account_binding(&bob, evm_address, 0, 10)
+			),
+			frame_support::sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn bind_evm_address_with_signature_should_fail_when_account_cannot_hold_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let dave = account_pair(4);
+		let dave_account = account_of(&dave);
+
+		let call = Call::<Test>::bind_evm_address_with_signature {
+			account: dave_account.clone(),
+			evm_address,
+			deadline: 10,
+			signature: sign_binding(&secret_key, &dave_account, 0, 10),
+			account_signature: sign_account_binding(&dave, evm_address, 0, 10),
+		};
+		assert!(EVMAccounts::validate_unsigned(TransactionSource::External, &call).is_err());
+
+		assert_noop!(
+			EVMAccounts::bind_evm_address_with_signature(
+				RuntimeOrigin::none(),
+				dave_account.clone(),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &dave_account, 0, 10),
+				sign_account_binding(&dave, evm_address, 0, 10)
+			),
+			Error::<Test>::InsufficientBalanceForBindingDeposit
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_should_accept_only_valid_binding_signature() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+
+		let valid_call = Call::<Test>::bind_evm_address_with_signature {
+			account: bob_account.clone(),
+			evm_address,
+			deadline: 10,
+			signature: sign_binding(&secret_key, &bob_account, 0, 10),
+			account_signature: sign_account_binding(&bob, evm_address, 0, 10),
+		};
+		assert!(EVMAccounts::validate_unsigned(TransactionSource::External, &valid_call).is_ok());
+
+		let invalid_call = Call::<Test>::bind_evm_address_with_signature {
+			account: bob_account.clone(),
+			evm_address,
+			deadline: 10,
+			signature: sign_binding(&evm_secret_key(2), &bob_account, 0, 10),
+			account_signature: sign_account_binding(&bob, evm_address, 0, 10),
+		};
+		assert!(EVMAccounts::validate_unsigned(TransactionSource::External, &invalid_call).is_err());
+
+		let not_accepted_call = Call::<Test>::bind_evm_address_with_signature {
+			account: bob_account.clone(),
+			evm_address,
+			deadline: 10,
+			signature: sign_binding(&secret_key, &bob_account, 0, 10),
+			account_signature: sign_account_binding(&account_pair(3), evm_address, 0, 10),
+		};
+		assert!(EVMAccounts::validate_unsigned(TransactionSource::External, &not_accepted_call).is_err());
+	});
+}
+
+#[test]
+fn rebind_evm_address_should_bind_evm_address_to_the_caller() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+		assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+			RuntimeOrigin::none(),
+			bob_account.clone(),
+			evm_address,
+			10,
+			sign_binding(&secret_key, &bob_account, 0, 10),
+			sign_account_binding(&bob, evm_address, 0, 10)
+		));
+
+		// Act
+		assert_ok!(EVMAccounts::rebind_evm_address(
+			RuntimeOrigin::signed(CHARLIE),
+			evm_address,
+			10,
+			sign_binding(&secret_key, &CHARLIE, 1, 10)
+		));
+
+		// Assert
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), Some(CHARLIE));
+		assert_eq!(EVMAccounts::evm_address(&CHARLIE), evm_address);
+		assert_ne!(EVMAccounts::evm_address(&bob_account), evm_address);
+		assert_eq!(EVMAccounts::binding_nonce(evm_address), 2);
+		assert_eq!(EVMAccounts::binding_deposit_of(&bob_account), None);
+		assert_eq!(Tokens::accounts(&bob_account, HDX).reserved, 0);
+		assert_eq!(EVMAccounts::binding_deposit_of(&CHARLIE), Some(BindingDeposit::get()));
+		assert_eq!(Tokens::accounts(&CHARLIE, HDX).reserved, BindingDeposit::get());
+		expect_events(vec![Event::Rebound {
+			old_account: bob_account,
+			account: CHARLIE,
+			address: evm_address,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn rebind_evm_address_should_fail_when_signature_is_replayed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+		assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+			RuntimeOrigin::none(),
+			bob_account.clone(),
+			evm_address,
+			10,
+			sign_binding(&secret_key, &bob_account, 0, 10),
+			sign_account_binding(&bob, evm_address, 0, 10)
+		));
+
+		assert_noop!(
+			EVMAccounts::rebind_evm_address(
+				RuntimeOrigin::signed(CHARLIE),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &CHARLIE, 0, 10)
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn rebind_evm_address_should_fail_when_address_is_not_bound() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+
+		assert_noop!(
+			EVMAccounts::rebind_evm_address(
+				RuntimeOrigin::signed(CHARLIE),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &CHARLIE, 0, 10)
+			),
+			Error::<Test>::AddressNotBound
+		);
+	});
+}
+
+#[test]
+fn rebind_evm_address_should_fail_when_caller_is_already_bound() {
+	ExtBuilder::default().build().execute_with(|| {
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+		assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+			RuntimeOrigin::none(),
+			bob_account.clone(),
+			evm_address,
+			10,
+			sign_binding(&secret_key, &bob_account, 0, 10),
+			sign_account_binding(&bob, evm_address, 0, 10)
+		));
+
+		assert_noop!(
+			EVMAccounts::rebind_evm_address(
+				RuntimeOrigin::signed(bob_account.clone()),
+				evm_address,
+				10,
+				sign_binding(&secret_key, &bob_account, 1, 10)
+			),
+			Error::<Test>::AccountAlreadyBound
+		);
+	});
+}
+
+#[test]
+fn unbind_evm_address_should_remove_binding_created_with_signature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let secret_key = evm_secret_key(1);
+		let evm_address = evm_address_of(&secret_key);
+		let bob = account_pair(2);
+		let bob_account = account_of(&bob);
+		assert_ok!(EVMAccounts::bind_evm_address_with_signature(
+			RuntimeOrigin::none(),
+			bob_account.clone(),
+			evm_address,
+			10,
+			sign_binding(&secret_key, &bob_account, 0, 10),
+			sign_account_binding(&bob, evm_address, 0, 10)
+		));
+
+		// Act
+		assert_ok!(EVMAccounts::unbind_evm_address(RuntimeOrigin::signed(
+			bob_account.clone()
+		)));
+
+		// Assert
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), None);
+		assert_eq!(
+			EVMAccounts::account_id(evm_address),
+			EVMAccounts::truncated_account_id(evm_address)
+		);
+		assert_ne!(EVMAccounts::evm_address(&bob_account), evm_address);
+		assert_eq!(EVMAccounts::binding_nonce(evm_address), 1);
+		assert_eq!(EVMAccounts::binding_deposit_of(&bob_account), None);
+		assert_eq!(Tokens::accounts(&bob_account, HDX).reserved, 0);
+		expect_events(vec![Event::Unbound {
+			account: bob_account,
+			address: evm_address,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn unbind_evm_address_should_remove_binding_of_own_evm_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(EVMAccounts::bind_evm_address(RuntimeOrigin::signed(ALICE)));
+		let evm_address = EVMAccounts::evm_address(&ALICE);
+
+		// Act
+		assert_ok!(EVMAccounts::unbind_evm_address(RuntimeOrigin::signed(ALICE)));
+
+		// Assert
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), None);
+		expect_events(vec![Event::Unbound {
+			account: ALICE,
+			address: evm_address,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn unbind_evm_address_should_fail_when_account_is_not_bound() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EVMAccounts::unbind_evm_address(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::AddressNotBound
+		);
+	});
+}
+
+#[test]
+fn approve_contract_code_should_store_code_hash_with_audit_reference() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code_hash = H256::repeat_byte(1);
+		let audit_reference: BoundedVec<u8, ConstU32<128>> = b"audit-2025-01".to_vec().try_into().unwrap();
+		assert!(!EVMAccounts::is_approved_contract_code(code_hash));
+
+		// Act
+		assert_ok!(EVMAccounts::approve_contract_code(
+			RuntimeOrigin::root(),
+			code_hash,
+			audit_reference.clone()
+		));
+
+		// Assert
+		assert!(EVMAccounts::is_approved_contract_code(code_hash));
+		assert_eq!(
+			EVMAccounts::approved_contract_code(code_hash),
+			Some(ApprovedCodeInfo {
+				audit_reference: audit_reference.clone(),
+				approved_at: 1,
+			})
+		);
+		expect_events(vec![Event::ContractCodeApproved {
+			code_hash,
+			audit_reference,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn approve_contract_code_should_fail_when_origin_is_not_controller() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EVMAccounts::approve_contract_code(
+				RuntimeOrigin::signed(ALICE),
+				H256::repeat_byte(1),
+				BoundedVec::default()
+			),
+			frame_support::sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn disapprove_contract_code_should_remove_code_hash_from_the_storage() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code_hash = H256::repeat_byte(1);
+		assert_ok!(EVMAccounts::approve_contract_code(
+			RuntimeOrigin::root(),
+			code_hash,
+			BoundedVec::default()
+		));
+
+		// Act
+		assert_ok!(EVMAccounts::disapprove_contract_code(RuntimeOrigin::root(), code_hash));
+
+		// Assert
+		assert!(!EVMAccounts::is_approved_contract_code(code_hash));
+		assert_eq!(EVMAccounts::approved_contract_code(code_hash), None);
+		expect_events(vec![Event::ContractCodeDisapproved { code_hash }.into()]);
+	});
+}
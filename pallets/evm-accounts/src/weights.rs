@@ -16,6 +16,11 @@ pub trait WeightInfo {
 	fn renounce_contract_deployer() -> Weight;
 	fn approve_contract() -> Weight;
 	fn disapprove_contract() -> Weight;
+	fn bind_evm_address_with_signature() -> Weight;
+	fn rebind_evm_address() -> Weight;
+	fn approve_contract_code() -> Weight;
+	fn disapprove_contract_code() -> Weight;
+	fn unbind_evm_address() -> Weight;
 }
 
 /// Weights for `pallet_evm_accounts` using the HydraDX node and recommended hardware.
@@ -91,4 +96,70 @@ impl WeightInfo for () {
 		Weight::from_parts(10_348_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `EVMAccounts::BindingNonce` (r:1 w:1)
+	/// Proof: `EVMAccounts::BindingNonce` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::AccountExtension` (r:2 w:0)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundAccount` (r:2 w:1)
+	/// Proof: `EVMAccounts::BoundAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundEvmAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::BoundEvmAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BindingDepositOf` (r:0 w:1)
+	/// Proof: `EVMAccounts::BindingDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn bind_evm_address_with_signature() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(112_389_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `EVMAccounts::BindingNonce` (r:1 w:1)
+	/// Proof: `EVMAccounts::BindingNonce` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::AccountExtension` (r:2 w:1)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundAccount` (r:2 w:1)
+	/// Proof: `EVMAccounts::BoundAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundEvmAddress` (r:1 w:2)
+	/// Proof: `EVMAccounts::BoundEvmAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BindingDepositOf` (r:1 w:2)
+	/// Proof: `EVMAccounts::BindingDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn rebind_evm_address() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(69_547_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `EVMAccounts::ApprovedContractCode` (r:0 w:1)
+	/// Proof: `EVMAccounts::ApprovedContractCode` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	fn approve_contract_code() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(11_702_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EVMAccounts::ApprovedContractCode` (r:0 w:1)
+	/// Proof: `EVMAccounts::ApprovedContractCode` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	fn disapprove_contract_code() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(10_478_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EVMAccounts::BoundEvmAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::BoundEvmAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::AccountExtension` (r:1 w:1)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundAccount` (r:0 w:1)
+	/// Proof: `EVMAccounts::BoundAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BindingDepositOf` (r:1 w:1)
+	/// Proof: `EVMAccounts::BindingDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn unbind_evm_address() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(24_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -0,0 +1,39 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the HSM pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_hsm::types::CollateralState;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query state of HSM collaterals.
+	pub trait HsmApi<AssetId> where
+		AssetId: Codec,
+	{
+		/// Returns prices, limits and arbitrage opportunity of all approved collateral assets.
+		fn collaterals() -> Vec<CollateralState<AssetId>>;
+
+		/// Returns prices, limits and arbitrage opportunity of the collateral asset.
+		/// Returns `None` if the asset is not an approved collateral.
+		fn collateral(asset_id: AssetId) -> Option<CollateralState<AssetId>>;
+
+		/// Returns amount of Hollar that HSM can still mint.
+		fn free_bucket_capacity() -> u128;
+	}
+}
@@ -286,6 +286,47 @@ benchmarks! {
 		assert!(acc_balance < 10 * ONE);
 	}
 
+	execute_arbitrage_signed {
+		// Set up a scenario for arbitrage (worst case)
+		let hollar = T::HollarId::get();
+		seed_asset::<T>(hollar, DECIMALS)?;
+		let (pool_id, assets) = seed_pool::<T>(222_222u32.into(), hollar, ASSET_ID_OFFSET)?;
+		let purchase_fee = Permill::from_percent(1);
+		let max_buy_price_coefficient = FixedU128::from_rational(4, 1);
+		let buy_back_fee = Permill::from_percent(1);
+		let b = Perbill::from_percent(50);
+		let max_in_holding: Option<Balance> = None; // No limit for arbitrage test
+
+		let collateral = assets[1];
+
+		Pallet::<T>::add_collateral_asset(
+			RawOrigin::Root.into(),
+			collateral,
+			pool_id,
+			purchase_fee,
+			max_buy_price_coefficient,
+			buy_back_fee,
+			b,
+			max_in_holding
+		)?;
+
+		let flash_minter: EvmAddress = hex!["1212121212121212121212121212121212121212"].into();
+		Pallet::<T>::set_flash_minter(
+			RawOrigin::Root.into(),
+			flash_minter,
+		)?;
+
+		<T as Config>::Currency::set_balance(collateral, &Pallet::<T>::account_id(), 10 * ONE);
+		<pallet_stableswap::Pallet<T> as frame_support::traits::OnFinalize<BlockNumberFor<T>>>::on_finalize(0u32.into()); // should not matter what block number it is
+
+		let caller: T::AccountId = account("keeper", 0, 0);
+	}: _(RawOrigin::Signed(caller), collateral)
+	verify {
+		let acc_balance = <T as Config>::Currency::balance(collateral, &Pallet::<T>::account_id());
+		assert!(acc_balance < 10 * ONE);
+		assert_eq!(SignedArbitragesExecuted::<T>::get(collateral), 1);
+	}
+
 	on_finalize {
 		let block_num: BlockNumberFor<T> = 5u32.into();
 		frame_system::Pallet::<T>::set_block_number(block_num);
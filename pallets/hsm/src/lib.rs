@@ -24,10 +24,11 @@
 //! * `sell` - Sell Hollar in exchange for collateral, or sell collateral for Hollar.
 //! * `buy` - Buy Hollar with collateral, or buy collateral with Hollar.
 //! * `execute_arbitrage` - Execute arbitrage opportunity between HSM and collateral stable pool (called by offchain worker).
+//! * `execute_arbitrage_signed` - Execute arbitrage opportunity by any account, which receives a share of the profit.
 
 pub use pallet::*;
 
-use crate::types::{Balance, CollateralInfo};
+use crate::types::{Balance, CollateralInfo, CollateralState};
 pub use crate::weights::WeightInfo;
 use ethabi::ethereum_types::BigEndianHash;
 use evm::{ExitReason, ExitSucceed};
@@ -161,6 +162,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type GasLimit: Get<u64>;
 
+		/// Share of the arbitrage profit paid to the account which executed the arbitrage via signed call
+		#[pallet::constant]
+		type KeeperRewardShare: Get<Permill>;
+
+		/// Maximum number of arbitrages that can be executed by offchain workers for a single collateral asset in a block
+		#[pallet::constant]
+		type MaxArbitragesPerBlock: Get<u32>;
+
+		/// Maximum number of arbitrages that can be executed via signed call for a single collateral asset in a block
+		#[pallet::constant]
+		type MaxSignedArbitragesPerBlock: Get<u32>;
+
 		/// Gas to Weight conversion.
 		type GasWeightMapping: GasWeightMapping;
 
@@ -192,6 +205,22 @@ pub mod pallet {
 	#[pallet::getter(fn hollar_amount_received)]
 	pub type HollarAmountReceived<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, Balance, ValueQuery>;
 
+	/// Number of arbitrages executed by offchain workers with a collateral asset in a single block
+	///
+	/// Used to limit the number of unsigned arbitrages per collateral in a block.
+	/// Values are reset to zero at the end of each block in on_finalize.
+	#[pallet::storage]
+	#[pallet::getter(fn arbitrages_executed)]
+	pub type ArbitragesExecuted<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, u32, ValueQuery>;
+
+	/// Number of arbitrages executed via signed call with a collateral asset in a single block
+	///
+	/// Tracked separately from `ArbitragesExecuted`, so keepers can't use up the limit of offchain workers.
+	/// Values are reset to zero at the end of each block in on_finalize.
+	#[pallet::storage]
+	#[pallet::getter(fn signed_arbitrages_executed)]
+	pub type SignedArbitragesExecuted<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, u32, ValueQuery>;
+
 	/// Address of the flash loan receiver.
 	#[pallet::storage]
 	#[pallet::getter(fn flash_minter)]
@@ -257,6 +286,18 @@ pub mod pallet {
 		/// Parameters:
 		/// - `flash_minter`: The EVM address of the flash minter contract
 		FlashMinterSet { flash_minter: EvmAddress },
+
+		/// Keeper was rewarded for executing arbitrage
+		///
+		/// Parameters:
+		/// - `who`: The account which executed the arbitrage
+		/// - `asset_id`: The collateral asset in which the reward was paid
+		/// - `amount`: Amount of the reward
+		KeeperRewarded {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -343,6 +384,8 @@ pub mod pallet {
 		FlashMinterNotSet,
 		/// Provided arbitrage data is invalid
 		InvalidArbitrageData,
+		/// Maximum number of arbitrages for the collateral asset in a single block reached
+		MaxArbitragesPerBlockReached,
 	}
 
 	#[pallet::hooks]
@@ -352,14 +395,18 @@ pub mod pallet {
 	{
 		/// Accounting for weight in on finalize
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-			<T as Config>::WeightInfo::on_finalize() * MAX_COLLATERALS as u64
+			// ArbitragesExecuted and SignedArbitragesExecuted hold at most one entry per collateral
+			(<T as Config>::WeightInfo::on_finalize() * MAX_COLLATERALS as u64)
+				.saturating_add(T::DbWeight::get().writes(2 * MAX_COLLATERALS as u64))
 		}
 
-		/// Cleans up the HollarAmountReceived storage at the end of each block
+		/// Cleans up the HollarAmountReceived, ArbitragesExecuted and SignedArbitragesExecuted storage at the end of each block
 		///
-		/// This ensures that the rate limiting for Hollar buybacks is reset for the next block.
+		/// This ensures that the rate limiting for Hollar buybacks and arbitrages is reset for the next block.
 		fn on_finalize(_n: BlockNumberFor<T>) {
 			let _ = <HollarAmountReceived<T>>::clear(u32::MAX, None);
+			let _ = <ArbitragesExecuted<T>>::clear(u32::MAX, None);
+			let _ = <SignedArbitragesExecuted<T>>::clear(u32::MAX, None);
 		}
 
 		/// Offchain worker entry point that processes arbitrage opportunities
@@ -809,6 +856,7 @@ pub mod pallet {
 		/// - `AssetNotApproved` if the asset is not a registered collateral
 		/// - `NoArbitrageOpportunity` if there's no profitable arbitrage opportunity
 		/// - `MaxBuyPriceExceeded` if the arbitrage would exceed the maximum buy price
+		/// - `MaxArbitragesPerBlockReached` if the arbitrage limit for the collateral in current block was reached
 		/// - `InvalidEVMInteraction` if there's an error interacting with the Hollar ERC20 contract
 		/// - Other errors from underlying calls
 		#[pallet::call_index(5)]
@@ -822,9 +870,6 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_none(origin)?;
 
-			let (flash_minter, loan_receiver) =
-				GetFlashMinterSupport::<T>::get().ok_or(Error::<T>::FlashMinterNotSet)?;
-
 			let collateral_info = Self::collaterals(collateral_asset_id).ok_or(Error::<T>::AssetNotApproved)?;
 
 			let (arb_direction, flash_loan_amount) = if let Some(arb_amount) = flash_amount {
@@ -841,50 +886,69 @@ pub mod pallet {
 				Self::find_arbitrage_opportunity(collateral_asset_id).ok_or(Error::<T>::NoArbitrageOpportunity)?
 			};
 
-			ensure!(flash_loan_amount > 0, Error::<T>::NoArbitrageOpportunity);
+			ArbitragesExecuted::<T>::try_mutate(collateral_asset_id, |count| {
+				Self::increase_arbitrage_count(count, T::MaxArbitragesPerBlock::get())
+			})?;
+
+			Self::do_execute_arbitrage(collateral_asset_id, &collateral_info, arb_direction, flash_loan_amount)?;
+
+			Ok(())
+		}
+
+		/// Execute arbitrage opportunity between HSM and collateral stable pool by any account
+		///
+		/// Permissionless version of `execute_arbitrage`, so the peg is maintained even when offchain workers
+		/// are not running. The arbitrage direction and size are determined by `find_arbitrage_opportunity`.
+		/// The caller receives `KeeperRewardShare` of the realized profit as a reward.
+		///
+		/// Parameters:
+		/// - `origin`: Any signed account
+		/// - `collateral_asset_id`: The ID of the collateral asset to check for arbitrage
+		///
+		/// Emits:
+		/// - `ArbitrageExecuted` when the arbitrage is successful
+		/// - `KeeperRewarded` when a non-zero reward is paid to the caller
+		///
+		/// Errors:
+		/// - `AssetNotApproved` if the asset is not a registered collateral
+		/// - `NoArbitrageOpportunity` if there's no profitable arbitrage opportunity
+		/// - `MaxArbitragesPerBlockReached` if the signed arbitrage limit for the collateral in current block was reached
+		/// - `InvalidEVMInteraction` if there's an error interacting with the Hollar ERC20 contract
+		/// - Other errors from underlying calls
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_arbitrage_signed()
+			.saturating_add(<T as Config>::GasWeightMapping::gas_to_weight(<T as Config>::GasLimit::get(), true))
+		)]
+		pub fn execute_arbitrage_signed(origin: OriginFor<T>, collateral_asset_id: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			let hsm_address = T::EvmAccounts::evm_address(&Self::account_id());
+			let collateral_info = Self::collaterals(collateral_asset_id).ok_or(Error::<T>::AssetNotApproved)?;
+			let (arb_direction, flash_loan_amount) =
+				Self::find_arbitrage_opportunity(collateral_asset_id).ok_or(Error::<T>::NoArbitrageOpportunity)?;
 
-			let context = CallContext::new_call(flash_minter, hsm_address);
-			let hollar_address = Self::get_hollar_contract_address()?;
+			SignedArbitragesExecuted::<T>::try_mutate(collateral_asset_id, |count| {
+				Self::increase_arbitrage_count(count, T::MaxSignedArbitragesPerBlock::get())
+			})?;
 
-			let c_asset_id: u32 = collateral_asset_id.into();
-			let pool_id_u32: u32 = collateral_info.pool_id.into();
-			let arb_data = EvmDataWriter::new()
-				.write(0u8)
-				.write(arb_direction)
-				.write(c_asset_id)
-				.write(pool_id_u32)
-				.build();
-			let data = EvmDataWriter::new_with_selector(ERC20Function::FlashLoan)
-				.write(loan_receiver)
-				.write(hollar_address)
-				.write(flash_loan_amount)
-				.write(Bytes(arb_data))
-				.build();
+			let profit =
+				Self::do_execute_arbitrage(collateral_asset_id, &collateral_info, arb_direction, flash_loan_amount)?;
 
-			let (exit_reason, value) = T::Evm::call(context, data, U256::zero(), T::GasLimit::get());
+			let reward = T::KeeperRewardShare::get().mul_floor(profit);
+			if !reward.is_zero() {
+				<T as Config>::Currency::transfer(
+					collateral_asset_id,
+					&T::ArbitrageProfitReceiver::get(),
+					&who,
+					reward,
+					Preservation::Expendable,
+				)?;
 
-			let receiver_balance_initial = <T as crate::pallet::Config>::Currency::total_balance(
-				collateral_asset_id,
-				&T::ArbitrageProfitReceiver::get(),
-			);
-			if exit_reason != ExitReason::Succeed(ExitSucceed::Returned) {
-				log::error!(target: "hsm", "Flash loan Hollar EVM execution failed - {:?}. Reason: {:?}", exit_reason, value);
-				return Err(Error::<T>::InvalidEVMInteraction.into());
+				Self::deposit_event(Event::<T>::KeeperRewarded {
+					who,
+					asset_id: collateral_asset_id,
+					amount: reward,
+				});
 			}
-			let receiver_balance_final = <T as crate::pallet::Config>::Currency::total_balance(
-				collateral_asset_id,
-				&T::ArbitrageProfitReceiver::get(),
-			);
-			let profit = receiver_balance_final.saturating_sub(receiver_balance_initial);
-
-			Self::deposit_event(Event::<T>::ArbitrageExecuted {
-				arbitrage: arb_direction,
-				asset_id: collateral_asset_id,
-				hollar_amount: flash_loan_amount,
-				profit,
-			});
 
 			Ok(())
 		}
@@ -1249,6 +1313,73 @@ where
 		Ok(amount_in)
 	}
 
+	/// Increases the number of arbitrages executed in current block, up to the `limit`
+	fn increase_arbitrage_count(count: &mut u32, limit: u32) -> DispatchResult {
+		ensure!(*count < limit, Error::<T>::MaxArbitragesPerBlockReached);
+		*count = count.saturating_add(1);
+		Ok(())
+	}
+
+	/// Executes arbitrage between HSM and collateral stable pool using Hollar flash loan
+	///
+	/// Returns the realized profit received by `ArbitrageProfitReceiver`.
+	fn do_execute_arbitrage(
+		collateral_asset_id: T::AssetId,
+		collateral_info: &CollateralInfo<T::AssetId>,
+		arb_direction: u8,
+		flash_loan_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		ensure!(flash_loan_amount > 0, Error::<T>::NoArbitrageOpportunity);
+
+		let (flash_minter, loan_receiver) = GetFlashMinterSupport::<T>::get().ok_or(Error::<T>::FlashMinterNotSet)?;
+
+		let hsm_address = T::EvmAccounts::evm_address(&Self::account_id());
+
+		let context = CallContext::new_call(flash_minter, hsm_address);
+		let hollar_address = Self::get_hollar_contract_address()?;
+
+		let c_asset_id: u32 = collateral_asset_id.into();
+		let pool_id_u32: u32 = collateral_info.pool_id.into();
+		let arb_data = EvmDataWriter::new()
+			.write(0u8)
+			.write(arb_direction)
+			.write(c_asset_id)
+			.write(pool_id_u32)
+			.build();
+		let data = EvmDataWriter::new_with_selector(ERC20Function::FlashLoan)
+			.write(loan_receiver)
+			.write(hollar_address)
+			.write(flash_loan_amount)
+			.write(Bytes(arb_data))
+			.build();
+
+		let receiver_balance_initial = <T as crate::pallet::Config>::Currency::total_balance(
+			collateral_asset_id,
+			&T::ArbitrageProfitReceiver::get(),
+		);
+
+		let (exit_reason, value) = T::Evm::call(context, data, U256::zero(), T::GasLimit::get());
+
+		if exit_reason != ExitReason::Succeed(ExitSucceed::Returned) {
+			log::error!(target: "hsm", "Flash loan Hollar EVM execution failed - {:?}. Reason: {:?}", exit_reason, value);
+			return Err(Error::<T>::InvalidEVMInteraction.into());
+		}
+		let receiver_balance_final = <T as crate::pallet::Config>::Currency::total_balance(
+			collateral_asset_id,
+			&T::ArbitrageProfitReceiver::get(),
+		);
+		let profit = receiver_balance_final.saturating_sub(receiver_balance_initial);
+
+		Self::deposit_event(Event::<T>::ArbitrageExecuted {
+			arbitrage: arb_direction,
+			asset_id: collateral_asset_id,
+			hollar_amount: flash_loan_amount,
+			profit,
+		});
+
+		Ok(profit)
+	}
+
 	/// Process arbitrage opportunities for all collateral assets
 	///
 	/// This function:
@@ -1768,6 +1899,83 @@ where
 		}
 	}
 
+	/// Returns current state of all approved collateral assets.
+	///
+	/// Used by the runtime API to give keepers the same view of HSM as the offchain worker has.
+	pub fn collaterals_state() -> Vec<CollateralState<T::AssetId>> {
+		let free_bucket_capacity = Self::get_hsm_bucket_free_capacity();
+		Collaterals::<T>::iter_keys()
+			.filter_map(|asset_id| Self::collateral_state(asset_id, free_bucket_capacity).ok())
+			.collect()
+	}
+
+	/// Returns current state of a collateral asset.
+	///
+	/// `free_bucket_capacity` is passed in to avoid repeated EVM calls when querying multiple collaterals.
+	pub fn collateral_state(
+		asset_id: T::AssetId,
+		free_bucket_capacity: Balance,
+	) -> Result<CollateralState<T::AssetId>, DispatchError> {
+		let collateral_info = Collaterals::<T>::get(asset_id).ok_or(Error::<T>::AssetNotApproved)?;
+		let pool_state = Self::get_stablepool_state(collateral_info.pool_id)?;
+		let peg = Self::get_asset_peg(asset_id, collateral_info.pool_id, &pool_state)?;
+
+		let sell_price = hydra_dx_math::hsm::calculate_purchase_price(peg, collateral_info.purchase_fee);
+		let max_buy_price = hydra_dx_math::hsm::calculate_max_buy_price(peg, collateral_info.max_buy_price_coefficient);
+		let hollar_amount_received = HollarAmountReceived::<T>::get(asset_id);
+		let buy_price =
+			Self::calculate_current_buy_price(asset_id, &collateral_info, &pool_state, peg, hollar_amount_received)
+				.filter(|buy_price| Self::ensure_max_price(*buy_price, max_buy_price));
+
+		Ok(CollateralState {
+			asset_id,
+			pool_id: collateral_info.pool_id,
+			sell_price,
+			buy_price,
+			max_buy_price,
+			collateral_holding: <T as Config>::Currency::balance(asset_id, &Self::account_id()),
+			hollar_amount_received,
+			free_bucket_capacity,
+			arbitrage: Self::find_arbitrage_opportunity(asset_id),
+		})
+	}
+
+	/// Calculates the price at which HSM would buy back the rest of the Hollar buyback limit in current block.
+	///
+	/// Returns `None` if the buyback limit has been already reached or the swap simulation fails.
+	fn calculate_current_buy_price(
+		asset_id: T::AssetId,
+		collateral_info: &CollateralInfo<T::AssetId>,
+		pool_state: &PoolSnapshot<T::AssetId>,
+		peg: PegType,
+		hollar_amount_received: Balance,
+	) -> Option<Price> {
+		let hollar_reserve = pool_state.asset_reserve_at(pool_state.asset_idx(T::HollarId::get())?)?;
+		let collateral_reserve = pool_state.asset_reserve_at(pool_state.asset_idx(asset_id)?)?;
+
+		let imbalance = hydra_dx_math::hsm::calculate_imbalance(hollar_reserve, peg, collateral_reserve)?;
+		let buyback_limit = hydra_dx_math::hsm::calculate_buyback_limit(imbalance, collateral_info.buyback_rate);
+		let hollar_amount = buyback_limit.saturating_sub(hollar_amount_received);
+		if hollar_amount.is_zero() {
+			return None;
+		}
+
+		let (collateral_amount, _) = Self::simulate_out_given_in(
+			collateral_info.pool_id,
+			T::HollarId::get(),
+			asset_id,
+			hollar_amount,
+			0,
+			pool_state,
+		)
+		.ok()?;
+
+		hydra_dx_math::hsm::calculate_buy_price_with_fee(
+			(collateral_amount, hollar_amount),
+			collateral_info.buy_back_fee,
+		)
+	}
+
 	pub fn is_flash_loan_account(account: &T::AccountId) -> bool {
 		GetFlashMinterSupport::<T>::get().map_or(false, |(_, loan_receiver)| {
 			T::EvmAccounts::account_id(loan_receiver) == *account
@@ -1,6 +1,6 @@
 use crate::tests::mock::*;
-use crate::ARBITRAGE_DIRECTION_BUY;
-use frame_support::assert_ok;
+use crate::{ArbitragesExecuted, Error, Event, SignedArbitragesExecuted, ARBITRAGE_DIRECTION_BUY};
+use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
 use hydra_dx_math::hsm::PegType;
 use hydradx_traits::evm::EvmAddress;
@@ -352,3 +352,175 @@ fn find_opportunity() {
 			assert_eq!(opportunity, Some((1, 78321364099875978581618)));
 		});
 }
+
+fn with_less_hollar_in_pool() -> sp_io::TestExternalities {
+	let pool_id = 100u32;
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, DAI, 1_000 * ONE)])
+		.with_registered_assets(vec![(DAI, 18), (HOLLAR, 18), (pool_id, 18)])
+		.with_pool(
+			pool_id,
+			vec![DAI, HOLLAR],
+			22,
+			Permill::from_percent(0),
+			vec![PegSource::Value((1, 1)), PegSource::Value((1, 1))],
+		)
+		.with_initial_pool_liquidity(
+			100,
+			vec![
+				AssetAmount {
+					asset_id: HOLLAR,
+					amount: 999_000 * ONE,
+				},
+				AssetAmount {
+					asset_id: DAI,
+					amount: 1_000_000 * ONE,
+				},
+			],
+		)
+		.with_collateral_buyback_limit(
+			DAI,
+			pool_id,
+			Permill::from_float(0.),
+			FixedU128::from_rational(99, 100),
+			Permill::from_float(0.),
+			Perbill::from_float(0.0001),
+		)
+		.build();
+	ext.execute_with(|| {
+		move_block();
+		let flash_minter: EvmAddress = hex!["8F3aC7f6482ABc1A5c48a95D97F7A235186dBb68"].into();
+		assert_ok!(HSM::set_flash_minter(RuntimeOrigin::root(), flash_minter,));
+	});
+	ext
+}
+
+#[test]
+fn signed_arbitrage_should_reward_keeper_with_share_of_profit() {
+	with_less_hollar_in_pool().execute_with(|| {
+		let opportunity = HSM::find_arbitrage_opportunity(DAI).expect("No arbitrage opportunity");
+
+		assert_ok!(HSM::execute_arbitrage_signed(RuntimeOrigin::signed(BOB), DAI));
+
+		let profit = 10_875_005_266_593_893;
+		let reward = KeeperRewardShare::get().mul_floor(profit);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), reward);
+		assert_eq!(Tokens::free_balance(DAI, &HsmArbProfitReceiver::get()), profit - reward);
+		assert_eq!(HSM::arbitrages_executed(DAI), 1);
+
+		System::assert_has_event(
+			Event::ArbitrageExecuted {
+				arbitrage: ARBITRAGE_DIRECTION_BUY,
+				asset_id: DAI,
+				hollar_amount: opportunity.1,
+				profit,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::KeeperRewarded {
+				who: BOB,
+				asset_id: DAI,
+				amount: reward,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn signed_arbitrage_should_fail_when_limit_per_block_reached() {
+	with_less_hollar_in_pool().execute_with(|| {
+		SignedArbitragesExecuted::<Test>::insert(DAI, MaxSignedArbitragesPerBlock::get());
+
+		assert_noop!(
+			HSM::execute_arbitrage_signed(RuntimeOrigin::signed(BOB), DAI),
+			Error::<Test>::MaxArbitragesPerBlockReached
+		);
+
+		// limit is reset in the next block
+		move_block();
+		assert_eq!(HSM::signed_arbitrages_executed(DAI), 0);
+		assert_ok!(HSM::execute_arbitrage_signed(RuntimeOrigin::signed(BOB), DAI));
+	});
+}
+
+#[test]
+fn unsigned_arbitrage_should_fail_when_limit_per_block_reached() {
+	with_less_hollar_in_pool().execute_with(|| {
+		ArbitragesExecuted::<Test>::insert(DAI, MaxArbitragesPerBlock::get());
+
+		assert_noop!(
+			HSM::execute_arbitrage(RuntimeOrigin::none(), DAI, None),
+			Error::<Test>::MaxArbitragesPerBlockReached
+		);
+
+		// limit is reset in the next block
+		move_block();
+		assert_eq!(HSM::arbitrages_executed(DAI), 0);
+		assert_ok!(HSM::execute_arbitrage(RuntimeOrigin::none(), DAI, None));
+	});
+}
+
+#[test]
+fn signed_arbitrage_should_not_use_up_unsigned_arbitrage_limit() {
+	with_less_hollar_in_pool().execute_with(|| {
+		assert_ok!(HSM::execute_arbitrage_signed(RuntimeOrigin::signed(BOB), DAI));
+
+		assert_eq!(HSM::signed_arbitrages_executed(DAI), 1);
+		assert_eq!(HSM::arbitrages_executed(DAI), 0);
+	});
+}
+
+#[test]
+fn signed_arbitrage_should_fail_when_there_is_no_opportunity() {
+	let pool_id = 100u32;
+	ExtBuilder::default()
+		.with_registered_assets(vec![(DAI, 18), (HOLLAR, 18), (pool_id, 18)])
+		.with_pool(
+			pool_id,
+			vec![DAI, HOLLAR],
+			22,
+			Permill::from_percent(0),
+			vec![PegSource::Value((1, 1)), PegSource::Value((1, 1))],
+		)
+		.with_initial_pool_liquidity(
+			100,
+			vec![
+				AssetAmount {
+					asset_id: HOLLAR,
+					amount: 1_000 * ONE,
+				},
+				AssetAmount {
+					asset_id: DAI,
+					amount: 1_000 * ONE,
+				},
+			],
+		)
+		.with_collateral_buyback_limit(
+			DAI,
+			pool_id,
+			Permill::from_percent(0),
+			FixedU128::one(),
+			Permill::from_float(0.),
+			Perbill::from_percent(10),
+		)
+		.build()
+		.execute_with(|| {
+			move_block();
+			assert_noop!(
+				HSM::execute_arbitrage_signed(RuntimeOrigin::signed(BOB), DAI),
+				Error::<Test>::NoArbitrageOpportunity
+			);
+		});
+}
+
+#[test]
+fn signed_arbitrage_should_fail_when_asset_is_not_collateral() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			HSM::execute_arbitrage_signed(RuntimeOrigin::signed(BOB), DAI),
+			Error::<Test>::AssetNotApproved
+		);
+	});
+}
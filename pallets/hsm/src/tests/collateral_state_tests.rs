@@ -0,0 +1,188 @@
+use crate::tests::mock::*;
+use crate::{Collaterals, HollarAmountReceived, ARBITRAGE_DIRECTION_BUY, ARBITRAGE_DIRECTION_SELL};
+use frame_support::assert_ok;
+use hex_literal::hex;
+use hydradx_traits::evm::EvmAddress;
+use hydradx_traits::stableswap::AssetAmount;
+use num_traits::One;
+use orml_traits::MultiCurrencyExtended;
+use pallet_stableswap::types::PegSource;
+use sp_runtime::{FixedU128, Perbill, Permill};
+
+const FREE_BUCKET_CAPACITY: Balance = 1_000_000_000_000_000_000_000_000;
+
+fn to_fixed(price: (Balance, Balance)) -> FixedU128 {
+	FixedU128::from_rational(price.0, price.1)
+}
+
+#[test]
+fn collaterals_state_should_be_empty_when_no_collateral_approved() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(HSM::collaterals_state().is_empty());
+	});
+}
+
+#[test]
+fn collateral_state_should_contain_buy_price_and_sell_opportunity_when_more_hollar_in_pool() {
+	let pool_id = 100u32;
+	ExtBuilder::default()
+		.with_registered_assets(vec![(DAI, 18), (HOLLAR, 18), (pool_id, 18)])
+		.with_pool(
+			pool_id,
+			vec![DAI, HOLLAR],
+			22,
+			Permill::from_percent(0),
+			vec![PegSource::Value((1, 1)), PegSource::Value((1, 1))],
+		)
+		.with_initial_pool_liquidity(
+			100,
+			vec![
+				AssetAmount {
+					asset_id: HOLLAR,
+					amount: 1_000 * ONE,
+				},
+				AssetAmount {
+					asset_id: DAI,
+					amount: 900 * ONE,
+				},
+			],
+		)
+		.with_collateral_buyback_limit(
+			DAI,
+			pool_id,
+			Permill::from_percent(1),
+			FixedU128::one(),
+			Permill::from_float(0.),
+			Perbill::from_percent(10),
+		)
+		.build()
+		.execute_with(|| {
+			move_block();
+			let flash_minter: EvmAddress = hex!["8F3aC7f6482ABc1A5c48a95D97F7A235186dBb68"].into();
+			assert_ok!(HSM::set_flash_minter(RuntimeOrigin::root(), flash_minter,));
+			assert_ok!(Tokens::update_balance(DAI, &HSM::account_id(), 100 * ONE as i128));
+
+			let states = HSM::collaterals_state();
+			assert_eq!(states.len(), 1);
+			let state = states[0].clone();
+
+			assert_eq!(state.asset_id, DAI);
+			assert_eq!(state.pool_id, pool_id);
+			assert_eq!(to_fixed(state.sell_price), FixedU128::from_rational(101, 100));
+			assert_eq!(to_fixed(state.max_buy_price), FixedU128::one());
+			let buy_price = state.buy_price.expect("HSM should buy back Hollar");
+			assert!(to_fixed(buy_price) < FixedU128::one());
+			assert_eq!(state.collateral_holding, 100 * ONE);
+			assert_eq!(state.hollar_amount_received, 0);
+			assert_eq!(state.free_bucket_capacity, FREE_BUCKET_CAPACITY);
+			assert_eq!(state.arbitrage, HSM::find_arbitrage_opportunity(DAI));
+			assert_eq!(
+				state.arbitrage.map(|(direction, _)| direction),
+				Some(ARBITRAGE_DIRECTION_SELL)
+			);
+
+			assert_eq!(HSM::collateral_state(DAI, FREE_BUCKET_CAPACITY), Ok(state));
+		});
+}
+
+#[test]
+fn collateral_state_should_not_contain_buy_price_when_buyback_limit_reached() {
+	let pool_id = 100u32;
+	ExtBuilder::default()
+		.with_registered_assets(vec![(DAI, 18), (HOLLAR, 18), (pool_id, 18)])
+		.with_pool(
+			pool_id,
+			vec![DAI, HOLLAR],
+			22,
+			Permill::from_percent(0),
+			vec![PegSource::Value((1, 1)), PegSource::Value((1, 1))],
+		)
+		.with_initial_pool_liquidity(
+			100,
+			vec![
+				AssetAmount {
+					asset_id: HOLLAR,
+					amount: 1_000 * ONE,
+				},
+				AssetAmount {
+					asset_id: DAI,
+					amount: 900 * ONE,
+				},
+			],
+		)
+		.with_collateral_buyback_limit(
+			DAI,
+			pool_id,
+			Permill::from_percent(0),
+			FixedU128::one(),
+			Permill::from_float(0.),
+			Perbill::from_percent(10),
+		)
+		.build()
+		.execute_with(|| {
+			move_block();
+			// imbalance is 50 Hollar, so the buyback limit is 5 Hollar
+			HollarAmountReceived::<Test>::insert(DAI, 5 * ONE);
+
+			let state = HSM::collateral_state(DAI, 0).unwrap();
+			assert_eq!(state.buy_price, None);
+			assert_eq!(state.hollar_amount_received, 5 * ONE);
+		});
+}
+
+#[test]
+fn collateral_state_should_contain_buy_opportunity_when_less_hollar_in_pool() {
+	let pool_id = 100u32;
+	ExtBuilder::default()
+		.with_registered_assets(vec![(DAI, 18), (HOLLAR, 18), (pool_id, 18)])
+		.with_pool(
+			pool_id,
+			vec![DAI, HOLLAR],
+			22,
+			Permill::from_percent(0),
+			vec![PegSource::Value((1, 1)), PegSource::Value((1, 1))],
+		)
+		.with_initial_pool_liquidity(
+			100,
+			vec![
+				AssetAmount {
+					asset_id: HOLLAR,
+					amount: 999_000 * ONE,
+				},
+				AssetAmount {
+					asset_id: DAI,
+					amount: 1_000_000 * ONE,
+				},
+			],
+		)
+		.with_collateral_buyback_limit(
+			DAI,
+			pool_id,
+			Permill::from_float(0.),
+			FixedU128::from_rational(99, 100),
+			Permill::from_float(0.),
+			Perbill::from_float(0.0001),
+		)
+		.build()
+		.execute_with(|| {
+			move_block();
+			let flash_minter: EvmAddress = hex!["8F3aC7f6482ABc1A5c48a95D97F7A235186dBb68"].into();
+			assert_ok!(HSM::set_flash_minter(RuntimeOrigin::root(), flash_minter,));
+
+			let state = HSM::collateral_state(DAI, FREE_BUCKET_CAPACITY).unwrap();
+			// no excess Hollar in the pool - nothing to buy back
+			assert_eq!(state.buy_price, None);
+			assert_eq!(state.arbitrage, Some((ARBITRAGE_DIRECTION_BUY, 499994562497366512583)));
+		});
+}
+
+#[test]
+fn collateral_state_should_fail_when_asset_is_not_collateral() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(!Collaterals::<Test>::contains_key(DAI));
+		assert_eq!(
+			HSM::collateral_state(DAI, 0),
+			Err(crate::Error::<Test>::AssetNotApproved.into())
+		);
+	});
+}
@@ -195,6 +195,9 @@ parameter_types! {
 	pub const GasLimit: u64 = 1_000_000;
 	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
 	pub HsmArbProfitReceiver: AccountId =  PROFIT_RECEIVER.into();
+	pub const KeeperRewardShare: Permill = Permill::from_percent(10);
+	pub const MaxArbitragesPerBlock: u32 = 1;
+	pub const MaxSignedArbitragesPerBlock: u32 = 1;
 }
 
 pub struct DummyRegistry;
@@ -494,6 +497,10 @@ impl InspectEvmAccounts<AccountId> for MockEvmAccounts {
 	fn is_approved_contract(_address: EvmAddress) -> bool {
 		unimplemented!()
 	}
+
+	fn is_approved_contract_code(_code_hash: H256) -> bool {
+		unimplemented!()
+	}
 }
 
 pub struct GhoContractAddress;
@@ -552,6 +559,9 @@ impl Config for Test {
 	type Evm = MockEvm;
 	type EvmAccounts = MockEvmAccounts;
 	type GasLimit = GasLimit;
+	type KeeperRewardShare = KeeperRewardShare;
+	type MaxArbitragesPerBlock = MaxArbitragesPerBlock;
+	type MaxSignedArbitragesPerBlock = MaxSignedArbitragesPerBlock;
 	type GasWeightMapping = MockGasWeightMapping;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use evm::ExitReason;
-use hydra_dx_math::hsm::CoefficientRatio;
+use hydra_dx_math::hsm::{CoefficientRatio, Price};
 use scale_info::TypeInfo;
 use sp_core::RuntimeDebug;
 use sp_runtime::{Perbill, Permill};
@@ -33,3 +33,29 @@ pub struct CollateralInfo<AssetId> {
 	/// Maximum amount of collateral that HSM can hold
 	pub max_in_holding: Option<Balance>,
 }
+
+/// Current state of a collateral asset in HSM
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CollateralState<AssetId> {
+	/// Collateral asset ID
+	pub asset_id: AssetId,
+	/// Pool ID - asset ID where the stable asset belongs
+	pub pool_id: AssetId,
+	/// Price at which HSM sells Hollar, including purchase fee (collateral per Hollar)
+	pub sell_price: Price,
+	/// Price at which HSM buys back the remaining buyback limit of Hollar, including buy back fee (collateral per Hollar).
+	/// `None` if HSM cannot buy back any Hollar in current block.
+	pub buy_price: Option<Price>,
+	/// Maximum price at which HSM is allowed to buy back Hollar (collateral per Hollar)
+	pub max_buy_price: Price,
+	/// Amount of collateral held by HSM
+	pub collateral_holding: Balance,
+	/// Amount of Hollar bought back by HSM in current block
+	pub hollar_amount_received: Balance,
+	/// Free capacity of HSM facilitator bucket - amount of Hollar that HSM can still mint
+	pub free_bucket_capacity: Balance,
+	/// Arbitrage opportunity between HSM and the stable pool - direction and amount of Hollar.
+	/// See `ARBITRAGE_DIRECTION_BUY` and `ARBITRAGE_DIRECTION_SELL`.
+	pub arbitrage: Option<(u8, Balance)>,
+}
@@ -15,6 +15,7 @@ pub trait WeightInfo {
 	fn sell() -> Weight;
 	fn buy() -> Weight;
 	fn execute_arbitrage() -> Weight;
+	fn execute_arbitrage_signed() -> Weight;
 	fn on_finalize() -> Weight;
 	fn calculate_sell() -> Weight;
 	fn calculate_buy() -> Weight;
@@ -51,6 +52,10 @@ impl WeightInfo for () {
 		Weight::zero()
 	}
 
+	fn execute_arbitrage_signed() -> Weight {
+		Weight::zero()
+	}
+
 	fn on_finalize() -> Weight {
 		Weight::zero()
 	}
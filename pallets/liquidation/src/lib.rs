@@ -21,8 +21,13 @@
 //! ## Notes
 //! The pallet requires the money market contract to be deployed and enabled.
 //!
+//! Received collateral is sold for the debt asset via the router. The route can be provided explicitly
+//! and can contain multiple hops. The sale is executed with the minimum amount out equal to the repaid debt,
+//! so the liquidation is reverted instead of selling the collateral at a loss.
+//!
 //! ## Dispatchable functions
 //! * `liquidate` - Liquidates an existing MM position. Performs flash loan to get funds.
+//! * `liquidate_multiple` - Liquidates several MM positions of one user in a single call.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::manual_inspect)]
@@ -44,7 +49,7 @@ use frame_system::{pallet_prelude::OriginFor, RawOrigin};
 use hydradx_traits::evm::Erc20Mapping;
 use hydradx_traits::{
 	evm::{CallContext, EvmAddress, InspectEvmAccounts, EVM},
-	router::{AmmTradeWeights, AmountInAndOut, Route, RouteProvider, RouterT, Trade},
+	router::{AmmTradeWeights, AmountInAndOut, AssetPair, Route, RouteProvider, RouterT, Trade},
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use pallet_evm::GasWeightMapping;
@@ -81,6 +86,19 @@ pub enum Function {
 	FlashLoan = "flashLoan(address,address,uint256,bytes)",
 }
 
+/// Parameters of a single liquidation executed by `liquidate_multiple`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LiquidationParams {
+	/// Asset ID used as collateral in the MM position.
+	pub collateral_asset: AssetId,
+	/// Asset ID used as debt in the MM position.
+	pub debt_asset: AssetId,
+	/// Amount of debt we want to liquidate.
+	pub debt_to_cover: Balance,
+	/// The route the collateral is sold through. Default route is used if empty.
+	pub route: Route<AssetId>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -137,6 +155,10 @@ pub mod pallet {
 		/// The origin which can update transaction priorities, allowed signers and call addresses
 		/// for the liquidation worker.
 		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of positions that can be liquidated in one `liquidate_multiple` call.
+		#[pallet::constant]
+		type MaxLiquidations: Get<u32>;
 	}
 
 	#[pallet::type_value]
@@ -177,6 +199,7 @@ pub mod pallet {
 
 			match call {
 				Call::liquidate { .. } => valid_tx(b"liquidate_unsigned".to_vec()),
+				Call::liquidate_multiple { .. } => valid_tx(b"liquidate_multiple_unsigned".to_vec()),
 				_ => InvalidTransaction::Call.into(),
 			}
 		}
@@ -209,6 +232,8 @@ pub mod pallet {
 		FlashMinterNotSet,
 		/// Invalid liquidation data provided
 		InvalidLiquidationData,
+		/// No liquidations provided
+		NoLiquidations,
 	}
 
 	#[pallet::call]
@@ -221,6 +246,7 @@ pub mod pallet {
 		///
 		/// Performs a flash loan to get funds to pay for the debt.
 		/// Received collateral is swapped and the profit is transferred to `FeeReceiver`.
+		/// The swap fails if the received amount of debt asset is lower than `debt_to_cover`.
 		///
 		/// Parameters:
 		/// - `origin`: Signed origin.
@@ -228,15 +254,12 @@ pub mod pallet {
 		/// - `debt_asset`: Asset ID used as debt in the MM position.
 		/// - `user`: EVM address of the MM position that we want to liquidate.
 		/// - `debt_to_cover`: Amount of debt we want to liquidate.
-		/// - `route`: The route we trade against. Required for the fee calculation.
+		/// - `route`: The route we trade against. Can contain multiple hops. Default route is used if empty.
 		///
 		/// Emits `Liquidated` event when successful.
 		///
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::liquidate()
-			.saturating_add(<T as Config>::RouterWeightInfo::sell_weight(route))
-			.saturating_add(<T as Config>::GasWeightMapping::gas_to_weight(<T as Config>::GasLimit::get(), true))
-		)]
+		#[pallet::weight(Pallet::<T>::liquidation_weight(route))]
 		pub fn liquidate(
 			_origin: OriginFor<T>,
 			collateral_asset: AssetId,
@@ -245,54 +268,7 @@ pub mod pallet {
 			debt_to_cover: Balance,
 			route: Route<AssetId>,
 		) -> DispatchResult {
-			log::trace!(target: "liquidation","liquidating debt asset: {:?} for amount: {:?}", debt_asset, debt_to_cover);
-
-			if debt_asset == T::HollarId::get() {
-				let (flash_minter, loan_receiver) = T::FlashMinter::get().ok_or(Error::<T>::FlashMinterNotSet)?;
-				let pallet_address = T::EvmAccounts::evm_address(&Self::account_id());
-				let context = CallContext::new_call(flash_minter, pallet_address);
-				let hollar_address = T::Erc20Mapping::asset_address(T::HollarId::get());
-
-				let liquidation_data = Self::encode_liquidation_data(collateral_asset, debt_asset, user, &route);
-
-				let data = EvmDataWriter::new_with_selector(Function::FlashLoan)
-					.write(loan_receiver)
-					.write(hollar_address)
-					.write(debt_to_cover)
-					.write(Bytes(liquidation_data))
-					.build();
-
-				let (exit_reason, value) = T::Evm::call(context, data, U256::zero(), T::GasLimit::get());
-
-				if exit_reason != ExitReason::Succeed(ExitSucceed::Returned) {
-					log::debug!(target: "liquidation", "Flash loan Hollar EVM execution failed - {:?}. Reason: {:?}", exit_reason, value);
-					return Err(Error::<T>::LiquidationCallFailed.into());
-				}
-			} else {
-				let pallet_acc = Self::account_id();
-				<T as Config>::Currency::mint_into(debt_asset, &pallet_acc, debt_to_cover)?;
-				let pallet_address = T::EvmAccounts::evm_address(&pallet_acc);
-
-				Self::liquidate_position_internal(
-					pallet_address,
-					collateral_asset,
-					debt_asset,
-					debt_to_cover,
-					user,
-					route.clone(),
-				)?;
-
-				let _ = <T as Config>::Currency::burn_from(
-					debt_asset,
-					&pallet_acc,
-					debt_to_cover,
-					Preservation::Expendable,
-					Precision::Exact,
-					Fortitude::Force,
-				)?;
-			}
-
-			Ok(())
+			Self::do_liquidate(collateral_asset, debt_asset, user, debt_to_cover, route)
 		}
 
 		/// Set the borrowing market contract address.
@@ -305,6 +281,41 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Liquidates several positions of one money market user in a single call.
+		/// Can be both signed and unsigned.
+		///
+		/// Liquidations are executed in the provided order, the same way as in `liquidate`.
+		/// If any of the liquidations fails, the whole call is reverted.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin.
+		/// - `user`: EVM address of the MM user whose positions we want to liquidate.
+		/// - `liquidations`: Collateral asset, debt asset, debt to cover and route of each liquidation.
+		///
+		/// Emits `Liquidated` event for each liquidation when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(liquidations.iter().fold(Weight::zero(), |acc, l| acc.saturating_add(Pallet::<T>::liquidation_weight(&l.route))))]
+		pub fn liquidate_multiple(
+			_origin: OriginFor<T>,
+			user: EvmAddress,
+			liquidations: BoundedVec<LiquidationParams, T::MaxLiquidations>,
+		) -> DispatchResult {
+			ensure!(!liquidations.is_empty(), Error::<T>::NoLiquidations);
+
+			for LiquidationParams {
+				collateral_asset,
+				debt_asset,
+				debt_to_cover,
+				route,
+			} in liquidations.into_iter()
+			{
+				Self::do_liquidate(collateral_asset, debt_asset, user, debt_to_cover, route)?;
+			}
+
+			Ok(())
+		}
 	}
 }
 
@@ -313,6 +324,73 @@ impl<T: Config> Pallet<T> {
 		PalletId(*b"lqdation").into_account_truncating()
 	}
 
+	/// Weight of a single liquidation.
+	pub fn liquidation_weight(route: &Route<AssetId>) -> Weight {
+		<T as Config>::WeightInfo::liquidate()
+			.saturating_add(<T as Config>::RouterWeightInfo::sell_weight(route))
+			.saturating_add(<T as Config>::GasWeightMapping::gas_to_weight(
+				<T as Config>::GasLimit::get(),
+				true,
+			))
+	}
+
+	fn do_liquidate(
+		collateral_asset: AssetId,
+		debt_asset: AssetId,
+		user: EvmAddress,
+		debt_to_cover: Balance,
+		route: Route<AssetId>,
+	) -> DispatchResult {
+		log::trace!(target: "liquidation","liquidating debt asset: {:?} for amount: {:?}", debt_asset, debt_to_cover);
+
+		if debt_asset == T::HollarId::get() {
+			let (flash_minter, loan_receiver) = T::FlashMinter::get().ok_or(Error::<T>::FlashMinterNotSet)?;
+			let pallet_address = T::EvmAccounts::evm_address(&Self::account_id());
+			let context = CallContext::new_call(flash_minter, pallet_address);
+			let hollar_address = T::Erc20Mapping::asset_address(T::HollarId::get());
+
+			let liquidation_data = Self::encode_liquidation_data(collateral_asset, debt_asset, user, &route);
+
+			let data = EvmDataWriter::new_with_selector(Function::FlashLoan)
+				.write(loan_receiver)
+				.write(hollar_address)
+				.write(debt_to_cover)
+				.write(Bytes(liquidation_data))
+				.build();
+
+			let (exit_reason, value) = T::Evm::call(context, data, U256::zero(), T::GasLimit::get());
+
+			if exit_reason != ExitReason::Succeed(ExitSucceed::Returned) {
+				log::debug!(target: "liquidation", "Flash loan Hollar EVM execution failed - {:?}. Reason: {:?}", exit_reason, value);
+				return Err(Error::<T>::LiquidationCallFailed.into());
+			}
+		} else {
+			let pallet_acc = Self::account_id();
+			<T as Config>::Currency::mint_into(debt_asset, &pallet_acc, debt_to_cover)?;
+			let pallet_address = T::EvmAccounts::evm_address(&pallet_acc);
+
+			Self::liquidate_position_internal(
+				pallet_address,
+				collateral_asset,
+				debt_asset,
+				debt_to_cover,
+				user,
+				route.clone(),
+			)?;
+
+			let _ = <T as Config>::Currency::burn_from(
+				debt_asset,
+				&pallet_acc,
+				debt_to_cover,
+				Preservation::Expendable,
+				Precision::Exact,
+				Fortitude::Force,
+			)?;
+		}
+
+		Ok(())
+	}
+
 	pub fn encode_liquidation_call_data(
 		collateral_asset: AssetId,
 		debt_asset: AssetId,
@@ -368,12 +446,24 @@ impl<T: Config> Pallet<T> {
 			log::trace!(target: "liquidation",
 				"Collateral earned: {:?} for asset: {:?}", collateral_earned, collateral_asset);
 
+			let route = Self::resolve_route(collateral_asset, debt_asset, route)?;
+
+			// The money market doesn't have to use the whole `debt_to_cover`.
+			// The sale has to cover at least the part of the debt that was repaid, otherwise the liquidation is not profitable.
+			let debt_left =
+				<T as Config>::Currency::balance(debt_asset, &liquidator_account).saturating_sub(debt_original_balance);
+			let min_amount_out = debt_to_cover.saturating_sub(debt_left);
+
+			let amounts = T::Router::calculate_sell_trade_amounts(&route, collateral_earned)?;
+			let amount_out = amounts.last().defensive_ok_or(Error::<T>::InvalidRoute)?.amount_out;
+			ensure!(amount_out >= min_amount_out, Error::<T>::NotProfitable);
+
 			T::Router::sell(
 				RawOrigin::Signed(liquidator_account.clone()).into(),
 				collateral_asset,
 				debt_asset,
 				collateral_earned,
-				1,
+				min_amount_out,
 				route,
 			)?;
 		}
@@ -409,6 +499,35 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Returns the route the collateral is sold through.
+	/// If the route is not provided, the default route of the asset pair is used.
+	/// Provided route has to start with the collateral asset, end with the debt asset and all trades have to be connected.
+	fn resolve_route(
+		collateral_asset: AssetId,
+		debt_asset: AssetId,
+		route: Route<AssetId>,
+	) -> Result<Route<AssetId>, DispatchError> {
+		if route.is_empty() {
+			return Ok(T::Router::get_route(AssetPair {
+				asset_in: collateral_asset,
+				asset_out: debt_asset,
+			}));
+		}
+
+		let starts_with_collateral = route.first().map(|trade| trade.asset_in) == Some(collateral_asset);
+		let ends_with_debt = route.last().map(|trade| trade.asset_out) == Some(debt_asset);
+		let is_connected = route
+			.iter()
+			.zip(route.iter().skip(1))
+			.all(|(trade, next_trade)| trade.asset_out == next_trade.asset_in);
+		ensure!(
+			starts_with_collateral && ends_with_debt && is_connected,
+			Error::<T>::InvalidRoute
+		);
+
+		Ok(route)
+	}
+
 	/// Liquidates an existing money market position.
 	pub fn liquidate_position(liquidator: EvmAddress, loan_amount: Balance, data: &[u8]) -> DispatchResult {
 		let (collateral_asset_id, debt_asset_id, user, route) = Self::decode_liquidation_data(data)?;
@@ -3,11 +3,11 @@
 #![allow(clippy::bool_assert_comparison)]
 
 pub use crate::tests::mock::*;
-use crate::{Error, Event};
+use crate::{Error, Event, LiquidationParams};
 use frame_support::{assert_noop, assert_ok};
 use hydradx_traits::{
 	evm::InspectEvmAccounts,
-	router::{AssetPair, RouteProvider},
+	router::{AssetPair, PoolType, RouteProvider, Trade},
 };
 use orml_traits::parameters::sp_runtime::BoundedVec;
 use orml_traits::MultiCurrency;
@@ -275,3 +275,245 @@ fn set_borrowing_contract_should_work() {
 		assert_eq!(Liquidation::borrowing_contract(), EvmAddress::from_slice(&[1; 20]));
 	});
 }
+
+#[test]
+fn liquidation_should_work_with_multi_hop_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Liquidation::set_borrowing_contract(
+			RuntimeOrigin::root(),
+			EvmAddress::from_slice(&[9; 20])
+		));
+		let bob_evm_address = EvmAccounts::evm_address(&BOB);
+		let debt_to_cover = 1_000 * ONE;
+
+		let route = BoundedVec::truncate_from(vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: DOT,
+			},
+		]);
+
+		let hdx_total_issuance = Currencies::total_issuance(HDX);
+		let dot_total_issuance = Currencies::total_issuance(DOT);
+
+		assert_ok!(EvmAccounts::bind_evm_address(RuntimeOrigin::signed(
+			Liquidation::account_id()
+		),));
+		assert_ok!(EvmAccounts::bind_evm_address(RuntimeOrigin::signed(MONEY_MARKET),));
+
+		// Act
+		assert_ok!(Liquidation::liquidate(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // collateral
+			DOT, // debt
+			bob_evm_address,
+			debt_to_cover,
+			route,
+		));
+
+		// Assert
+		assert_eq!(hdx_total_issuance, Currencies::total_issuance(HDX));
+		assert_eq!(dot_total_issuance, Currencies::total_issuance(DOT));
+
+		assert!(Currencies::free_balance(HDX, &Liquidation::account_id()) == 0);
+		assert!(Currencies::free_balance(DAI, &Liquidation::account_id()) == 0);
+		assert!(Currencies::free_balance(DOT, &Liquidation::account_id()) == 0);
+
+		assert_eq!(Currencies::free_balance(HDX, &TreasuryAccount::get()), 0);
+		assert!(Currencies::free_balance(DOT, &TreasuryAccount::get()) > 0);
+	});
+}
+
+#[test]
+fn liquidation_should_fail_when_route_does_not_match_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Liquidation::set_borrowing_contract(
+			RuntimeOrigin::root(),
+			EvmAddress::from_slice(&[9; 20])
+		));
+		let bob_evm_address = EvmAccounts::evm_address(&BOB);
+		let debt_to_cover = 1_000 * ONE;
+
+		assert_ok!(EvmAccounts::bind_evm_address(RuntimeOrigin::signed(
+			Liquidation::account_id()
+		),));
+		assert_ok!(EvmAccounts::bind_evm_address(RuntimeOrigin::signed(MONEY_MARKET),));
+
+		// route doesn't end with the debt asset
+		let route = Router::get_route(AssetPair {
+			asset_in: HDX,
+			asset_out: DAI,
+		});
+		assert_noop!(
+			Liquidation::liquidate(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DOT,
+				bob_evm_address,
+				debt_to_cover,
+				route,
+			),
+			Error::<Test>::InvalidRoute
+		);
+
+		// trades of the route are not connected
+		let route = BoundedVec::truncate_from(vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: LRNA,
+				asset_out: DOT,
+			},
+		]);
+		assert_noop!(
+			Liquidation::liquidate(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DOT,
+				bob_evm_address,
+				debt_to_cover,
+				route,
+			),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn liquidate_multiple_should_liquidate_all_positions() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Liquidation::set_borrowing_contract(
+			RuntimeOrigin::root(),
+			EvmAddress::from_slice(&[9; 20])
+		));
+		let bob_evm_address = EvmAccounts::evm_address(&BOB);
+		let debt_to_cover = 1_000 * ONE;
+
+		let route = Router::get_route(AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		});
+
+		let hdx_total_issuance = Currencies::total_issuance(HDX);
+		let dot_total_issuance = Currencies::total_issuance(DOT);
+
+		assert_ok!(EvmAccounts::bind_evm_address(RuntimeOrigin::signed(
+			Liquidation::account_id()
+		),));
+		assert_ok!(EvmAccounts::bind_evm_address(RuntimeOrigin::signed(MONEY_MARKET),));
+
+		// Act
+		assert_ok!(Liquidation::liquidate_multiple(
+			RuntimeOrigin::signed(ALICE),
+			bob_evm_address,
+			BoundedVec::truncate_from(vec![
+				LiquidationParams {
+					collateral_asset: HDX,
+					debt_asset: DOT,
+					debt_to_cover,
+					route,
+				},
+				LiquidationParams {
+					collateral_asset: HDX,
+					debt_asset: HDX,
+					debt_to_cover,
+					route: BoundedVec::new(),
+				},
+			]),
+		));
+
+		// Assert
+		assert_eq!(hdx_total_issuance, Currencies::total_issuance(HDX));
+		assert_eq!(dot_total_issuance, Currencies::total_issuance(DOT));
+
+		assert!(Currencies::free_balance(HDX, &Liquidation::account_id()) == 0);
+		assert!(Currencies::free_balance(DOT, &Liquidation::account_id()) == 0);
+
+		expect_last_events(vec![
+			Event::Liquidated {
+				user: bob_evm_address,
+				debt_asset: DOT,
+				collateral_asset: HDX,
+				debt_to_cover,
+				profit: 2_976_143_141_153_081,
+			}
+			.into(),
+			Event::Liquidated {
+				user: bob_evm_address,
+				debt_asset: HDX,
+				collateral_asset: HDX,
+				debt_to_cover,
+				profit: debt_to_cover,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn liquidate_multiple_should_revert_all_liquidations_when_one_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Liquidation::set_borrowing_contract(
+			RuntimeOrigin::root(),
+			EvmAddress::from_slice(&[9; 20])
+		));
+		let bob_evm_address = EvmAccounts::evm_address(&BOB);
+		let debt_to_cover = 1_000 * ONE;
+
+		assert_ok!(EvmAccounts::bind_evm_address(RuntimeOrigin::signed(
+			Liquidation::account_id()
+		),));
+		assert_ok!(EvmAccounts::bind_evm_address(RuntimeOrigin::signed(MONEY_MARKET),));
+
+		// Act & Assert
+		assert_noop!(
+			Liquidation::liquidate_multiple(
+				RuntimeOrigin::signed(ALICE),
+				bob_evm_address,
+				BoundedVec::truncate_from(vec![
+					LiquidationParams {
+						collateral_asset: HDX,
+						debt_asset: DOT,
+						debt_to_cover,
+						route: BoundedVec::new(),
+					},
+					LiquidationParams {
+						collateral_asset: DOT,
+						debt_asset: HDX,
+						debt_to_cover,
+						route: BoundedVec::new(),
+					},
+				]),
+			),
+			Error::<Test>::NotProfitable
+		);
+	});
+}
+
+#[test]
+fn liquidate_multiple_should_fail_when_no_liquidations_provided() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Liquidation::liquidate_multiple(
+				RuntimeOrigin::signed(ALICE),
+				EvmAccounts::evm_address(&BOB),
+				BoundedVec::new(),
+			),
+			Error::<Test>::NoLiquidations
+		);
+	});
+}
@@ -66,6 +66,7 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const LiquidationGasLimit: u64 = 1_000_000;
 	pub const HollarId: u32 = 222;
+	pub const MaxLiquidations: u32 = 5;
 }
 
 parameter_type_with_key! {
@@ -211,6 +212,7 @@ impl Config for Test {
 	type HollarId = HollarId;
 	type FlashMinter = ();
 	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MaxLiquidations = MaxLiquidations;
 }
 
 parameter_types! {
@@ -346,6 +348,7 @@ impl pallet_currencies::Config for Test {
 	type NativeCurrency = BasicCurrencyAdapter<Test, Balances, Amount, u32>;
 	type Erc20Currency = MockErc20Currency<Test>;
 	type BoundErc20 = MockBoundErc20<Test>;
+	type AccountFreeze = ();
 	type ReserveAccount = TreasuryAccount;
 	type GetNativeCurrencyId = HDXAssetId;
 	type WeightInfo = ();
@@ -367,6 +370,15 @@ parameter_types! {
 
 type AssetLocation = u8;
 
+parameter_types! {
+	#[derive(PartialEq, Debug)]
+	pub const MetadataFieldLimit: u32 = 64;
+	pub const MetadataDepositBase: Balance = 0;
+	pub const MetadataDepositPerByte: Balance = 0;
+	pub const RegistrationDeposit: Balance = 0;
+	pub const MinOwnedAssetExistentialDeposit: Balance = 1;
+}
+
 impl pallet_asset_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RegistryOrigin = EnsureRoot<AccountId>;
@@ -377,8 +389,15 @@ impl pallet_asset_registry::Config for Test {
 	type StringLimit = RegistryStringLimit;
 	type MinStringLimit = MinRegistryStringLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
+	type NativeAssetId = HDXAssetId;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
 	type RegisterAssetHook = ();
+	type DepositCurrency = Balances;
+	type MetadataFieldLimit = MetadataFieldLimit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type RegistrationDeposit = RegistrationDeposit;
+	type MinOwnedAssetExistentialDeposit = MinOwnedAssetExistentialDeposit;
 	type WeightInfo = ();
 }
 
@@ -478,6 +497,10 @@ impl pallet_evm_accounts::Config for Test {
 	type FeeMultiplier = ConstU32<10>;
 	type EvmNonceProvider = EvmNonceProviderMock;
 	type ControllerOrigin = EnsureRoot<AccountId>;
+	type ChainId = frame_support::traits::ConstU64<222_222>;
+	type MaxAuditReferenceLength = ConstU32<128>;
+	type DepositCurrency = Balances;
+	type BindingDeposit = frame_support::traits::ConstU128<0>;
 	type WeightInfo = ();
 }
 
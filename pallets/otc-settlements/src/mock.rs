@@ -244,6 +244,7 @@ impl pallet_currencies::Config for Test {
 	type NativeCurrency = BasicCurrencyAdapter<Test, Balances, Amount, u32>;
 	type Erc20Currency = MockErc20Currency<Test>;
 	type BoundErc20 = MockBoundErc20<Test>;
+	type AccountFreeze = ();
 	type ReserveAccount = TreasuryAccount;
 	type GetNativeCurrencyId = HDXAssetId;
 	type WeightInfo = ();
@@ -269,6 +270,15 @@ parameter_types! {
 
 type AssetLocation = u8;
 
+parameter_types! {
+	#[derive(PartialEq, Debug)]
+	pub const MetadataFieldLimit: u32 = 64;
+	pub const MetadataDepositBase: Balance = 0;
+	pub const MetadataDepositPerByte: Balance = 0;
+	pub const RegistrationDeposit: Balance = 0;
+	pub const MinOwnedAssetExistentialDeposit: Balance = 1;
+}
+
 impl pallet_asset_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RegistryOrigin = EnsureRoot<AccountId>;
@@ -279,8 +289,15 @@ impl pallet_asset_registry::Config for Test {
 	type StringLimit = RegistryStringLimit;
 	type MinStringLimit = MinRegistryStringLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
+	type NativeAssetId = HDXAssetId;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
 	type RegisterAssetHook = ();
+	type DepositCurrency = Balances;
+	type MetadataFieldLimit = MetadataFieldLimit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type RegistrationDeposit = RegistrationDeposit;
+	type MinOwnedAssetExistentialDeposit = MinOwnedAssetExistentialDeposit;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,34 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the referrals pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_referrals::{Level, ReferrerInfo};
+
+sp_api::decl_runtime_apis! {
+	/// The API to query referrer analytics.
+	pub trait ReferralsApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Returns pending rewards in reward asset, number of linked accounts, current level
+		/// and volume needed to reach the next level of the referrer.
+		///
+		/// Assets waiting for conversion are valued via the router.
+		fn referrer_info(who: AccountId) -> Option<ReferrerInfo>;
+	}
+}
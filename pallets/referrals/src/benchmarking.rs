@@ -22,6 +22,7 @@ use frame_benchmarking::benchmarks;
 use frame_support::traits::tokens::fungibles::{Inspect, Mutate};
 use frame_system::RawOrigin;
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 benchmarks! {
 	where_clause { where
@@ -111,6 +112,78 @@ benchmarks! {
 			external: external_percentage,
 		}));
 	}
+
+	transfer_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let to: T::AccountId = account("to", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		ReferrerVolumeUpdatedAt::<T>::insert(&caller, frame_system::Pallet::<T>::block_number());
+	}: _(RawOrigin::Signed(caller.clone()), to.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert_eq!(Pallet::<T>::referral_account(c), Some(to.clone()));
+		assert_eq!(Pallet::<T>::referrer_level(to), Some((Level::Tier0, 0)));
+		assert!(Pallet::<T>::referrer_level(caller).is_none());
+	}
+
+	set_code_lifetime{
+		let lifetime: BlockNumberFor<T> = 100u32.into();
+	}: _(RawOrigin::Root, Some(lifetime))
+	verify {
+		assert_eq!(Pallet::<T>::code_lifetime(), Some(lifetime));
+	}
+
+	renew_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 3 * fee)?;
+		let lifetime: BlockNumberFor<T> = 100u32.into();
+		Pallet::<T>::set_code_lifetime(RawOrigin::Root.into(), Some(lifetime))?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		let now = frame_system::Pallet::<T>::block_number();
+		assert_eq!(Pallet::<T>::code_expiry(c), Some(now + lifetime + lifetime));
+	}
+
+	reclaim_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let owner: T::AccountId = account("owner", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &owner, 2 * fee)?;
+		let lifetime: BlockNumberFor<T> = 100u32.into();
+		Pallet::<T>::set_code_lifetime(RawOrigin::Root.into(), Some(lifetime))?;
+		Pallet::<T>::register_code(RawOrigin::Signed(owner.clone()).into(), code.clone())?;
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + lifetime);
+	}: _(RawOrigin::Signed(caller), code.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		assert!(Pallet::<T>::referral_account(c.clone()).is_none());
+		assert!(Pallet::<T>::referral_code(owner).is_none());
+		assert_eq!(Pallet::<T>::code_generation(c), 1);
+	}
+
+	set_tier_schedule{
+		let rewards = FeeDistribution{
+			referrer: Permill::from_percent(40),
+			trader: Permill::from_percent(30),
+			external: Permill::from_percent(30),
+		};
+		let tiers: BoundedVec<Tier, T::MaxTiers> = (0..T::MaxTiers::get())
+			.map(|i| Tier { volume: i as Balance, rewards })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(RawOrigin::Root, T::RewardAsset::get(), tiers.clone())
+	verify {
+		assert_eq!(Pallet::<T>::asset_tier_schedule(T::RewardAsset::get()), tiers);
+	}
 }
 
 #[cfg(test)]
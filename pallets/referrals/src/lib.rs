@@ -27,6 +27,13 @@
 //! Pallet also provides support for volume-based tiering. Referrer can reached higher Level based on the total amount generated by users of the referrer code.
 //! The higher level, the better reward.
 //!
+//! Volume can decay over time (see `VolumeHalfLife`) so the level reflects recent activity of the referrer
+//! instead of lifetime totals. Governance can also define asset specific tier schedules which take precedence over
+//! level rewards for referred trades in the asset.
+//!
+//! Referral code can be transferred to another account. Traders linked to the previous owner follow the code.
+//! If code lifetime is set, newly registered codes expire unless renewed and expired codes can be reclaimed by anyone.
+//!
 //! Rewards are accumulated in the pallet's account and if it is not RewardAsset, it is converted to RewardAsset prior to claim.
 //!
 //! ### Terminology
@@ -51,9 +58,9 @@ pub mod traits;
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::{DispatchResult, Get};
-use frame_support::traits::fungibles::Mutate;
+use frame_support::traits::fungibles::{Inspect, Mutate};
 use frame_support::traits::tokens::Preservation;
-use frame_support::{defensive, ensure, transactional};
+use frame_support::{ensure, transactional};
 use frame_system::{
 	ensure_signed,
 	pallet_prelude::{BlockNumberFor, OriginFor},
@@ -64,7 +71,7 @@ use scale_info::TypeInfo;
 use sp_core::bounded::BoundedVec;
 use sp_core::U256;
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Saturating};
 use sp_runtime::Rounding;
 use sp_runtime::{
 	traits::{CheckedAdd, Zero},
@@ -83,7 +90,9 @@ pub type ReferralCode<S> = BoundedVec<u8, S>;
 
 /// Referrer level.
 /// Indicates current level of the referrer to determine which reward percentages are used.
-#[derive(Hash, Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[derive(
+	Hash, Clone, Copy, Default, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
 pub enum Level {
 	None,
 	#[default]
@@ -134,6 +143,15 @@ pub struct FeeDistribution {
 	pub external: Permill,
 }
 
+/// Tier of an asset tier schedule.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Tier {
+	/// Minimum referrer volume required to reach the tier.
+	pub volume: Balance,
+	/// Fee distribution used for referred trades when the tier is reached.
+	pub rewards: FeeDistribution,
+}
+
 #[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
 pub struct AssetAmount<AssetId> {
 	asset_id: AssetId,
@@ -146,6 +164,23 @@ impl<AssetId> AssetAmount<AssetId> {
 	}
 }
 
+/// Referrer details returned by runtime api.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ReferrerInfo {
+	/// Referrer rewards in RewardAsset the account would receive if it claimed now.
+	pub referrer_rewards: Balance,
+	/// Trade rewards in RewardAsset the account would receive if it claimed now.
+	pub trader_rewards: Balance,
+	/// Number of accounts linked to the referrer.
+	pub linked_accounts: u32,
+	/// Current level of the referrer.
+	pub level: Level,
+	/// Current volume of the referrer.
+	pub volume: Balance,
+	/// Volume still needed to reach the next level. `None` if the referrer is at the max level.
+	pub volume_to_next_level: Option<Balance>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -211,6 +246,15 @@ pub mod pallet {
 		#[pallet::constant]
 		type SeedNativeAmount: Get<u128>;
 
+		/// Maximum number of tiers in an asset tier schedule.
+		#[pallet::constant]
+		type MaxTiers: Get<u32>;
+
+		/// Number of blocks in which referrer volume decays to half.
+		/// Zero disables the decay and referrer level is driven by lifetime totals.
+		#[pallet::constant]
+		type VolumeHalfLife: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -273,6 +317,47 @@ pub mod pallet {
 	#[pallet::getter(fn pending_conversions)]
 	pub(super) type PendingConversions<T: Config> = CountedStorageMap<_, Blake2_128Concat, T::AssetId, ()>;
 
+	/// Block number of the last referrer volume update.
+	/// Used to decay referrer volume over time.
+	#[pallet::storage]
+	#[pallet::getter(fn referrer_volume_updated_at)]
+	pub(super) type ReferrerVolumeUpdatedAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Accounts which transferred their referral code.
+	/// Maps former referrer account to (code, code generation at the time of the transfer).
+	/// Traders linked to the former referrer account follow the code to its current owner.
+	#[pallet::storage]
+	#[pallet::getter(fn transferred_code)]
+	pub(super) type TransferredCodes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (ReferralCode<T::CodeLength>, u32)>;
+
+	/// Code generation.
+	/// Increased every time an expired code is reclaimed, which invalidates previous transfers of the code.
+	#[pallet::storage]
+	#[pallet::getter(fn code_generation)]
+	pub(super) type CodeGeneration<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, u32, ValueQuery>;
+
+	/// Lifetime of newly registered codes. Codes do not expire if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn code_lifetime)]
+	pub(super) type CodeLifetime<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Code expiration.
+	/// Maps a referral code to block number at which the code expires.
+	#[pallet::storage]
+	#[pallet::getter(fn code_expiry)]
+	pub(super) type CodeExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, BlockNumberFor<T>, OptionQuery>;
+
+	/// Asset tier schedules.
+	/// Maps asset id to tiers sorted by required volume.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_tier_schedule)]
+	pub(super) type AssetTierSchedules<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BoundedVec<Tier, T::MaxTiers>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -306,6 +391,32 @@ pub mod pallet {
 		},
 		/// Referrer reached new level.
 		LevelUp { who: T::AccountId, level: Level },
+		/// Referrer dropped to lower level due to volume decay.
+		LevelDown { who: T::AccountId, level: Level },
+		/// Referral code has been transferred to another account.
+		CodeTransferred {
+			code: ReferralCode<T::CodeLength>,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
+		/// Lifetime of newly registered codes has been set.
+		CodeLifetimeSet { lifetime: Option<BlockNumberFor<T>> },
+		/// Referral code has been renewed.
+		CodeRenewed {
+			code: ReferralCode<T::CodeLength>,
+			account: T::AccountId,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Expired referral code has been reclaimed.
+		CodeReclaimed {
+			code: ReferralCode<T::CodeLength>,
+			account: T::AccountId,
+		},
+		/// Asset tier schedule has been set.
+		TierScheduleSet {
+			asset_id: T::AssetId,
+			tiers: BoundedVec<Tier, T::MaxTiers>,
+		},
 	}
 
 	#[pallet::error]
@@ -339,6 +450,16 @@ pub mod pallet {
 		ConversionMinTradingAmountNotReached,
 		/// Zero amount received from conversion.
 		ConversionZeroAmountReceived,
+		/// The account has no code registered.
+		NotRegistered,
+		/// Referral code has expired.
+		CodeExpired,
+		/// Referral code has not expired yet.
+		CodeNotExpired,
+		/// Code lifetime is not set.
+		CodeExpiryDisabled,
+		/// Tiers are not sorted by volume or contain duplicate volume.
+		IncorrectTierSchedule,
 	}
 
 	#[pallet::call]
@@ -364,6 +485,7 @@ pub mod pallet {
 				ReferralAccounts::<T>::get(&who).is_none(),
 				Error::<T>::AlreadyRegistered
 			);
+			ensure!(Self::former_code_owner(&who).is_none(), Error::<T>::AlreadyRegistered);
 
 			ensure!(code.len() >= T::MinCodeLength::get() as usize, Error::<T>::TooShort);
 
@@ -384,8 +506,18 @@ pub mod pallet {
 				T::Currency::transfer(fee_asset, &who, &beneficiary, fee_amount, Preservation::Preserve)?;
 
 				*v = Some(who.clone());
-				Referrer::<T>::insert(&who, (Level::default(), Balance::zero()));
+				// Referrer whose code has been reclaimed keeps the level.
+				Referrer::<T>::mutate(&who, |r| {
+					if r.is_none() {
+						*r = Some((Level::default(), Balance::zero()));
+					}
+				});
 				ReferralAccounts::<T>::insert(&who, code.clone());
+				TransferredCodes::<T>::remove(&who);
+				if let Some(lifetime) = Self::code_lifetime() {
+					let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(lifetime);
+					CodeExpiry::<T>::insert(&code, expires_at);
+				}
 				Self::deposit_event(Event::CodeRegistered { code, account: who });
 				Ok(())
 			})
@@ -394,6 +526,7 @@ pub mod pallet {
 		/// Link a code to an account.
 		///
 		/// `Code` must be valid registered code. Otherwise `InvalidCode` is returned.
+		/// Expired code cannot be linked.
 		///
 		/// Signer account is linked to the referral account of the code.
 		///
@@ -407,6 +540,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let code = Self::normalize_code(code);
 			let ref_account = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(!Self::is_code_expired(&code), Error::<T>::CodeExpired);
 
 			LinkedAccounts::<T>::mutate(who.clone(), |v| -> DispatchResult {
 				ensure!(v.is_none(), Error::<T>::AlreadyLinked);
@@ -462,6 +596,7 @@ pub mod pallet {
 		/// Reward amount is calculated based on the shares of the signer account.
 		///
 		/// if the signer account is referrer account, total accumulated rewards is updated as well as referrer level if reached.
+		/// If volume decay is enabled, the volume is decayed first and the level can drop.
 		///
 		/// Emits `Claimed` event when successful.
 		#[pallet::call_index(3)]
@@ -537,19 +672,26 @@ pub mod pallet {
 			TotalShares::<T>::mutate(|v| {
 				*v = v.saturating_sub(total_shares);
 			});
-			Referrer::<T>::mutate(who.clone(), |v| {
-				if let Some((level, total)) = v {
-					*total = total.saturating_add(referrer_rewards);
-					let new_level = level.increase::<T>(*total);
-					if *level != new_level {
-						*level = new_level;
-						Self::deposit_event(Event::LevelUp {
-							who: who.clone(),
-							level: new_level,
-						});
-					}
+			if let Some((level, total)) = Self::referrer_level(&who) {
+				let (current_level, volume) = Self::decay_referrer_level(&who, level, total);
+				let total = volume.saturating_add(referrer_rewards);
+				let new_level = current_level.increase::<T>(total);
+				if new_level > level {
+					Self::deposit_event(Event::LevelUp {
+						who: who.clone(),
+						level: new_level,
+					});
+				} else if new_level < level {
+					Self::deposit_event(Event::LevelDown {
+						who: who.clone(),
+						level: new_level,
+					});
 				}
-			});
+				Referrer::<T>::insert(&who, (new_level, total));
+				if !T::VolumeHalfLife::get().is_zero() {
+					ReferrerVolumeUpdatedAt::<T>::insert(&who, frame_system::Pallet::<T>::block_number());
+				}
+			}
 
 			Self::deposit_event(Event::Claimed {
 				who,
@@ -577,16 +719,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin)?;
 
-			//ensure that total percentage does not exceed 100%
-			ensure!(
-				rewards
-					.referrer
-					.checked_add(&rewards.trader)
-					.ok_or(Error::<T>::IncorrectRewardPercentage)?
-					.checked_add(&rewards.external)
-					.is_some(),
-				Error::<T>::IncorrectRewardPercentage
-			);
+			Self::ensure_valid_rewards(&rewards)?;
 
 			AssetRewards::<T>::mutate(asset_id.clone(), level, |v| {
 				*v = Some(rewards);
@@ -598,6 +731,159 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Transfer referral code of the signer to another account.
+		///
+		/// Referrer level and volume are transferred with the code.
+		/// Traders linked to the signer account follow the code to the new owner.
+		/// Accumulated referrer shares stay with the signer account.
+		///
+		/// `to` must not be a referrer. The signer cannot register new code while the transferred code exists.
+		///
+		/// Parameters:
+		/// - `to`: new owner of the code.
+		///
+		/// Emits `CodeTransferred` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_code())]
+		pub fn transfer_code(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::referral_code(&who).ok_or(Error::<T>::NotRegistered)?;
+
+			ensure!(!Referrer::<T>::contains_key(&to), Error::<T>::AlreadyRegistered);
+			// The code can go back to an account it has been transferred from.
+			if let Some((former_code, _)) = Self::former_code_owner(&to) {
+				ensure!(former_code == code, Error::<T>::AlreadyRegistered);
+			}
+
+			ReferralCodes::<T>::insert(&code, &to);
+			ReferralAccounts::<T>::remove(&who);
+			ReferralAccounts::<T>::insert(&to, code.clone());
+			if let Some(details) = Referrer::<T>::take(&who) {
+				Referrer::<T>::insert(&to, details);
+			}
+			if let Some(updated_at) = ReferrerVolumeUpdatedAt::<T>::take(&who) {
+				ReferrerVolumeUpdatedAt::<T>::insert(&to, updated_at);
+			}
+			TransferredCodes::<T>::remove(&to);
+			TransferredCodes::<T>::insert(&who, (code.clone(), Self::code_generation(&code)));
+
+			Self::deposit_event(Event::CodeTransferred { code, from: who, to });
+			Ok(())
+		}
+
+		/// Set lifetime of newly registered codes.
+		///
+		/// Expiration of already registered codes is not changed.
+		///
+		/// Parameters:
+		/// - `lifetime`: number of blocks after which a code expires. `None` disables expiration.
+		///
+		/// Emits `CodeLifetimeSet` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_code_lifetime())]
+		pub fn set_code_lifetime(origin: OriginFor<T>, lifetime: Option<BlockNumberFor<T>>) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			CodeLifetime::<T>::set(lifetime);
+			Self::deposit_event(Event::CodeLifetimeSet { lifetime });
+			Ok(())
+		}
+
+		/// Renew referral code of the signer.
+		///
+		/// Signer pays the registration fee again and the code expiration is extended by code lifetime.
+		/// Expired code can be renewed until it is reclaimed.
+		///
+		/// Emits `CodeRenewed` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew_code())]
+		pub fn renew_code(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let code = Self::referral_code(&who).ok_or(Error::<T>::NotRegistered)?;
+			let lifetime = Self::code_lifetime().ok_or(Error::<T>::CodeExpiryDisabled)?;
+
+			let (fee_asset, fee_amount, beneficiary) = T::RegistrationFee::get();
+			T::Currency::transfer(fee_asset, &who, &beneficiary, fee_amount, Preservation::Preserve)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = Self::code_expiry(&code)
+				.map_or(now, |expires_at| expires_at.max(now))
+				.saturating_add(lifetime);
+			CodeExpiry::<T>::insert(&code, expires_at);
+
+			Self::deposit_event(Event::CodeRenewed {
+				code,
+				account: who,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Reclaim expired referral code.
+		///
+		/// The code is removed from its owner and can be registered again.
+		/// Owner stays a referrer of already linked traders and keeps the level.
+		///
+		/// Parameters:
+		/// - `code`: expired code.
+		///
+		/// Emits `CodeReclaimed` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::reclaim_code())]
+		pub fn reclaim_code(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
+			ensure_signed(origin)?;
+			let code = Self::normalize_code(code);
+			let owner = Self::referral_account(&code).ok_or(Error::<T>::InvalidCode)?;
+			ensure!(Self::is_code_expired(&code), Error::<T>::CodeNotExpired);
+
+			ReferralCodes::<T>::remove(&code);
+			ReferralAccounts::<T>::remove(&owner);
+			CodeExpiry::<T>::remove(&code);
+			CodeGeneration::<T>::mutate(&code, |generation| {
+				*generation = generation.wrapping_add(1);
+			});
+
+			Self::deposit_event(Event::CodeReclaimed { code, account: owner });
+			Ok(())
+		}
+
+		/// Set asset tier schedule.
+		///
+		/// Tier schedule takes precedence over level rewards for referred trades in the asset.
+		/// The highest tier whose volume is reached by the referrer is used.
+		/// If referrer volume does not reach any tier, level rewards are used.
+		///
+		/// Parameters:
+		/// - `asset_id`: asset id
+		/// - `tiers`: tiers sorted by volume in ascending order. Empty list removes the schedule.
+		///
+		/// Emits `TierScheduleSet` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_tier_schedule())]
+		pub fn set_tier_schedule(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			tiers: BoundedVec<Tier, T::MaxTiers>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				tiers.windows(2).all(|w| w[0].volume < w[1].volume),
+				Error::<T>::IncorrectTierSchedule
+			);
+			for tier in tiers.iter() {
+				Self::ensure_valid_rewards(&tier.rewards)?;
+			}
+
+			if tiers.is_empty() {
+				AssetTierSchedules::<T>::remove(asset_id.clone());
+			} else {
+				AssetTierSchedules::<T>::insert(asset_id.clone(), tiers.clone());
+			}
+			Self::deposit_event(Event::TierScheduleSet { asset_id, tiers });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -636,6 +922,157 @@ impl<T: Config> Pallet<T> {
 		ReferralCode::<T::CodeLength>::truncate_from(r)
 	}
 
+	/// Ensure that total percentage does not exceed 100%.
+	fn ensure_valid_rewards(rewards: &FeeDistribution) -> DispatchResult {
+		ensure!(
+			rewards
+				.referrer
+				.checked_add(&rewards.trader)
+				.ok_or(Error::<T>::IncorrectRewardPercentage)?
+				.checked_add(&rewards.external)
+				.is_some(),
+			Error::<T>::IncorrectRewardPercentage
+		);
+		Ok(())
+	}
+
+	/// Returns true if the code has expiration set and it has been reached.
+	pub fn is_code_expired(code: &ReferralCode<T::CodeLength>) -> bool {
+		Self::code_expiry(code).is_some_and(|expires_at| expires_at <= frame_system::Pallet::<T>::block_number())
+	}
+
+	/// Returns the transferred code and its current owner if the account transferred its code
+	/// and the code has not been reclaimed since.
+	pub(crate) fn former_code_owner(who: &T::AccountId) -> Option<(ReferralCode<T::CodeLength>, T::AccountId)> {
+		let (code, generation) = Self::transferred_code(who)?;
+		if generation != Self::code_generation(&code) {
+			return None;
+		}
+		let owner = Self::referral_account(&code)?;
+		Some((code, owner))
+	}
+
+	/// Returns referrer level and volume with volume decay applied up to the current block.
+	pub fn current_referrer_level(who: &T::AccountId) -> Option<(Level, Balance)> {
+		let (level, volume) = Self::referrer_level(who)?;
+		Some(Self::decay_referrer_level(who, level, volume))
+	}
+
+	fn decay_referrer_level(who: &T::AccountId, level: Level, volume: Balance) -> (Level, Balance) {
+		if T::VolumeHalfLife::get().is_zero() {
+			return (level, volume);
+		}
+		let Some(updated_at) = Self::referrer_volume_updated_at(who) else {
+			return (level, volume);
+		};
+		let elapsed = frame_system::Pallet::<T>::block_number().saturating_sub(updated_at);
+		let volume = Self::decay_volume(volume, elapsed);
+		(Level::default().increase::<T>(volume), volume)
+	}
+
+	/// Decay volume by half every `VolumeHalfLife` blocks.
+	/// Volume decays linearly between the halvings.
+	pub(crate) fn decay_volume(volume: Balance, elapsed: BlockNumberFor<T>) -> Balance {
+		let half_life: u128 = T::VolumeHalfLife::get().saturated_into();
+		if half_life.is_zero() || volume.is_zero() {
+			return volume;
+		}
+		let elapsed: u128 = elapsed.saturated_into();
+		let halvings = elapsed / half_life;
+		if halvings >= Balance::BITS as u128 {
+			return 0;
+		}
+		let volume = volume >> halvings;
+		let reduction =
+			multiply_by_rational_with_rounding(volume, elapsed % half_life, half_life.saturating_mul(2), Rounding::Up)
+				.unwrap_or(volume);
+		volume.saturating_sub(reduction)
+	}
+
+	/// Returns current referrer of the trader together with referrer level and volume.
+	///
+	/// Follows transferred codes and updates the link to the current code owner.
+	/// Removes the link if the referrer no longer exists.
+	fn trader_referrer(trader: &T::AccountId) -> Option<(T::AccountId, Level, Balance)> {
+		let mut acc = Self::linked_referral_account(trader)?;
+		if !Referrer::<T>::contains_key(&acc) {
+			match Self::former_code_owner(&acc) {
+				Some((_, owner)) if owner != *trader => {
+					LinkedAccounts::<T>::insert(trader, &owner);
+					acc = owner;
+				}
+				_ => {
+					LinkedAccounts::<T>::remove(trader);
+					return None;
+				}
+			}
+		}
+		let (level, volume) = Self::current_referrer_level(&acc)?;
+		Some((acc, level, volume))
+	}
+
+	/// Returns referrer details of the account. `None` if the account is not a referrer.
+	///
+	/// Pending rewards include value of assets waiting for conversion in the pot.
+	/// `value_in_reward_asset` returns value of an asset amount in RewardAsset.
+	///
+	/// Note: counts linked accounts by iterating `LinkedAccounts`. Intended to be used off-chain only.
+	pub fn referrer_info(
+		who: &T::AccountId,
+		value_in_reward_asset: impl Fn(T::AssetId, Balance) -> Option<Balance>,
+	) -> Option<ReferrerInfo> {
+		let (level, volume) = Self::current_referrer_level(who)?;
+
+		let pot = Self::pot_account_id();
+		let pending_value = PendingConversions::<T>::iter_keys().fold(Balance::zero(), |acc, asset_id| {
+			let amount = T::Currency::balance(asset_id.clone(), &pot);
+			acc.saturating_add(value_in_reward_asset(asset_id, amount).unwrap_or_default())
+		});
+		let reward_reserve = T::Currency::balance(T::RewardAsset::get(), &pot)
+			.saturating_sub(T::SeedNativeAmount::get())
+			.saturating_add(pending_value);
+		let share_issuance = TotalShares::<T>::get();
+		let convert_shares = |shares: Balance| -> Balance {
+			let r = U256::from(shares)
+				.saturating_mul(U256::from(reward_reserve))
+				.checked_div(U256::from(share_issuance))
+				.unwrap_or_default();
+			Balance::try_from(r).unwrap_or(Balance::MAX)
+		};
+
+		// Traders linked to accounts which transferred their code follow the code.
+		let former_accounts: sp_std::vec::Vec<T::AccountId> = TransferredCodes::<T>::iter_keys()
+			.filter(|acc| Self::former_code_owner(acc).is_some_and(|(_, owner)| owner == *who))
+			.collect();
+		let linked_accounts = LinkedAccounts::<T>::iter_values()
+			.filter(|acc| acc == who || former_accounts.contains(acc))
+			.count();
+
+		let volume_to_next_level = (!level.is_max_level()).then(|| {
+			T::LevelVolumeAndRewardPercentages::get(&level.next_level())
+				.0
+				.saturating_sub(volume)
+		});
+
+		Some(ReferrerInfo {
+			referrer_rewards: convert_shares(Self::referrer_shares(who)),
+			trader_rewards: convert_shares(Self::trader_shares(who)),
+			linked_accounts: linked_accounts.saturated_into(),
+			level,
+			volume,
+			volume_to_next_level,
+		})
+	}
+
+	/// Returns rewards of the highest tier of asset tier schedule reached by given volume.
+	fn scheduled_rewards(asset_id: T::AssetId, volume: Balance) -> Option<FeeDistribution> {
+		Self::asset_tier_schedule(asset_id)
+			.iter()
+			.rev()
+			.find(|tier| volume >= tier.volume)
+			.map(|tier| tier.rewards)
+	}
+
 	/// Process trader fee
 	/// `source`: account to take the fee from
 	/// `trader`: account that does the trade
@@ -653,20 +1090,17 @@ impl<T: Config> Pallet<T> {
 			return Ok(None);
 		};
 
-		let (level, ref_account) = if let Some(acc) = Self::linked_referral_account(&trader) {
-			if let Some((level, _)) = Self::referrer_level(&acc) {
-				// Should not really happen, the ref entry should be always there.
-				(level, Some(acc))
-			} else {
-				defensive!("Referrer details not found");
-				return Ok(None);
-			}
+		let (level, ref_account, scheduled_rewards) = if let Some((acc, level, volume)) = Self::trader_referrer(&trader)
+		{
+			(level, Some(acc), Self::scheduled_rewards(asset_id.clone(), volume))
 		} else {
-			(Level::None, None)
+			(Level::None, None, None)
 		};
 
-		// What is asset fee for this level? if not explicitly set, use global parameter.
-		let rewards = Self::asset_rewards(asset_id.clone(), level)
+		// Asset tier schedule takes precedence.
+		// Otherwise, what is asset fee for this level? if not explicitly set, use global parameter.
+		let rewards = scheduled_rewards
+			.or_else(|| Self::asset_rewards(asset_id.clone(), level))
 			.unwrap_or_else(|| T::LevelVolumeAndRewardPercentages::get(&level).1);
 
 		// Rewards
@@ -17,12 +17,17 @@
 
 mod claim;
 mod convert;
+mod expiry;
 mod flow;
 mod link;
 mod mock_amm;
+mod referrer_info;
 mod register;
+mod tier_schedule;
 mod tiers;
 mod trade_fee;
+mod transfer;
+mod volume_decay;
 
 use crate as pallet_referrals;
 use crate::*;
@@ -71,6 +76,7 @@ thread_local! {
 	pub static TIER_REWARDS: RefCell<HashMap<Level, FeeDistribution>> = RefCell::new(HashMap::default());
 	pub static SEED_AMOUNT: RefCell<Balance> = RefCell::new(Balance::zero());
 	pub static EXTERNAL_ACCOUNT: RefCell<Option<AccountId>> = const { RefCell::new(None) };
+	pub static VOLUME_HALF_LIFE: RefCell<u64> = const { RefCell::new(0) };
 }
 
 construct_runtime!(
@@ -89,6 +95,7 @@ parameter_types! {
 	pub const MinCodeLength: u32 = 4;
 	pub const RegistrationFee: (AssetId,Balance, AccountId) = (HDX, 222 * 1_000_000_000_000, TREASURY) ;
 	pub const RewardAsset: AssetId = HDX;
+	pub const MaxTiers: u32 = 3;
 }
 
 pub struct LevelVolumeAndRewards;
@@ -127,6 +134,14 @@ impl Get<Option<AccountId>> for ExtAccount {
 	}
 }
 
+pub struct VolumeHalfLife;
+
+impl Get<u64> for VolumeHalfLife {
+	fn get() -> u64 {
+		VOLUME_HALF_LIFE.with(|v| *v.borrow())
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
@@ -142,6 +157,8 @@ impl Config for Test {
 	type LevelVolumeAndRewardPercentages = LevelVolumeAndRewards;
 	type ExternalAccount = ExtAccount;
 	type SeedNativeAmount = SeedAmount;
+	type MaxTiers = MaxTiers;
+	type VolumeHalfLife = VolumeHalfLife;
 	type WeightInfo = ();
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -233,6 +250,10 @@ impl Default for ExtBuilder {
 			let mut c = v.borrow_mut();
 			*c = None;
 		});
+		VOLUME_HALF_LIFE.with(|v| {
+			let mut c = v.borrow_mut();
+			*c = 0;
+		});
 
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, INITIAL_ALICE_BALANCE)],
@@ -306,6 +327,14 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_volume_half_life(self, half_life: u64) -> Self {
+		VOLUME_HALF_LIFE.with(|v| {
+			let mut m = v.borrow_mut();
+			*m = half_life;
+		});
+		self
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	pub fn with_default_volumes(self) -> Self {
 		let mut volumes = HashMap::new();
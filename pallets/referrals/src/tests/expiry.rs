@@ -0,0 +1,210 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn set_code_lifetime_should_fail_when_not_correct_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_code_lifetime(RuntimeOrigin::signed(ALICE), Some(100)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn register_code_should_set_expiry_when_code_lifetime_is_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		assert_ok!(Referrals::set_code_lifetime(RuntimeOrigin::root(), Some(100)));
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		// ACT
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// ASSERT
+		assert_eq!(Referrals::code_expiry(code), Some(101));
+		expect_events(vec![Event::CodeLifetimeSet { lifetime: Some(100) }.into()]);
+	});
+}
+
+#[test]
+fn register_code_should_not_set_expiry_when_code_lifetime_is_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		assert_eq!(Referrals::code_expiry(code), None);
+	});
+}
+
+#[test]
+fn link_code_should_fail_when_code_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		assert_ok!(Referrals::set_code_lifetime(RuntimeOrigin::root(), Some(100)));
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		System::set_block_number(101);
+		// ACT & ASSERT
+		assert_noop!(
+			Referrals::link_code(RuntimeOrigin::signed(BOB), code),
+			Error::<Test>::CodeExpired
+		);
+	});
+}
+
+#[test]
+fn renew_code_should_extend_expiry_and_charge_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		assert_ok!(Referrals::set_code_lifetime(RuntimeOrigin::root(), Some(100)));
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		let (_, fee, _) = RegistrationFee::get();
+		let balance = Tokens::free_balance(HDX, &ALICE);
+		System::set_block_number(50);
+		// ACT
+		assert_ok!(Referrals::renew_code(RuntimeOrigin::signed(ALICE)));
+		// ASSERT
+		assert_eq!(Referrals::code_expiry(code.clone()), Some(201));
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), balance - fee);
+		expect_events(vec![Event::CodeRenewed {
+			code,
+			account: ALICE,
+			expires_at: 201,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn renew_code_should_extend_expiry_from_current_block_when_code_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		assert_ok!(Referrals::set_code_lifetime(RuntimeOrigin::root(), Some(100)));
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		System::set_block_number(150);
+		// ACT
+		assert_ok!(Referrals::renew_code(RuntimeOrigin::signed(ALICE)));
+		// ASSERT
+		assert_eq!(Referrals::code_expiry(code.clone()), Some(250));
+		assert!(!Referrals::is_code_expired(&code));
+	});
+}
+
+#[test]
+fn renew_code_should_fail_when_code_lifetime_is_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+		assert_noop!(
+			Referrals::renew_code(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::CodeExpiryDisabled
+		);
+	});
+}
+
+#[test]
+fn reclaim_code_should_fail_when_code_not_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// ACT & ASSERT
+		assert_noop!(
+			Referrals::reclaim_code(RuntimeOrigin::signed(BOB), code.clone()),
+			Error::<Test>::CodeNotExpired
+		);
+		assert_ok!(Referrals::set_code_lifetime(RuntimeOrigin::root(), Some(100)));
+		assert_ok!(Referrals::renew_code(RuntimeOrigin::signed(ALICE)));
+		System::set_block_number(100);
+		assert_noop!(
+			Referrals::reclaim_code(RuntimeOrigin::signed(BOB), code),
+			Error::<Test>::CodeNotExpired
+		);
+	});
+}
+
+#[test]
+fn reclaim_code_should_release_code_when_expired() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			assert_ok!(Referrals::set_code_lifetime(RuntimeOrigin::root(), Some(100)));
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			Referrer::<Test>::insert(ALICE, (Level::Tier1, 1_000));
+			System::set_block_number(101);
+			// ACT
+			assert_ok!(Referrals::reclaim_code(
+				RuntimeOrigin::signed(BOB),
+				b"balls69".to_vec().try_into().unwrap()
+			));
+			// ASSERT
+			assert_eq!(Referrals::referral_account(code.clone()), None);
+			assert_eq!(Referrals::referral_code(ALICE), None);
+			assert_eq!(Referrals::code_expiry(code.clone()), None);
+			assert_eq!(Referrals::code_generation(code.clone()), 1);
+			assert_eq!(Referrals::referrer_level(ALICE), Some((Level::Tier1, 1_000)));
+			expect_events(vec![Event::CodeReclaimed {
+				code: code.clone(),
+				account: ALICE,
+			}
+			.into()]);
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(CHARLIE), code.clone()));
+			assert_eq!(Referrals::referral_account(code), Some(CHARLIE));
+		});
+}
+
+#[test]
+fn register_code_should_keep_level_when_code_of_referrer_was_reclaimed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		assert_ok!(Referrals::set_code_lifetime(RuntimeOrigin::root(), Some(100)));
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		Referrer::<Test>::insert(ALICE, (Level::Tier1, 1_000));
+		System::set_block_number(101);
+		assert_ok!(Referrals::reclaim_code(RuntimeOrigin::signed(BOB), code));
+		// ACT
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"OTHER".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+		// ASSERT
+		assert_eq!(Referrals::referrer_level(ALICE), Some((Level::Tier1, 1_000)));
+	});
+}
+
+#[test]
+fn traders_linked_to_former_owner_should_be_unlinked_when_transferred_code_is_reclaimed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000), (CHARLIE, HDX, 1_000 * ONE)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::zero(),
+				external: Permill::zero(),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			assert_ok!(Referrals::set_code_lifetime(RuntimeOrigin::root(), Some(100)));
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code.clone()));
+			assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), TREASURY));
+			System::set_block_number(101);
+			assert_ok!(Referrals::reclaim_code(RuntimeOrigin::signed(CHARLIE), code.clone()));
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(CHARLIE), code));
+			// ACT
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// ASSERT
+			assert_eq!(ReferrerShares::<Test>::get(CHARLIE), 0);
+			assert_eq!(ReferrerShares::<Test>::get(TREASURY), 0);
+			assert_eq!(Referrals::linked_referral_account(BOB), None);
+		});
+}
@@ -0,0 +1,79 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+fn value_in_hdx(asset_id: AssetId, amount: Balance) -> Option<Balance> {
+	(asset_id == DAI).then_some(amount / 1_000_000)
+}
+
+#[test]
+fn referrer_info_should_return_none_when_account_is_not_referrer() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Referrals::referrer_info(&ALICE, value_in_hdx), None);
+	});
+}
+
+#[test]
+fn referrer_info_should_include_value_of_pending_conversions_in_rewards() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Pallet::<Test>::pot_account_id(), HDX, 10_000_000_000_000),
+			(Pallet::<Test>::pot_account_id(), DAI, 3_000_000_000_000_000_000),
+		])
+		.with_assets(vec![DAI])
+		.with_referrer_shares(vec![(ALICE, 5_000_000_000_000)])
+		.with_trader_shares(vec![(ALICE, 1_000_000_000_000), (BOB, 4_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			// ACT
+			let info = Referrals::referrer_info(&ALICE, value_in_hdx).unwrap();
+			// ASSERT
+			assert_eq!(info.referrer_rewards, 6_500_000_000_000);
+			assert_eq!(info.trader_rewards, 1_300_000_000_000);
+		});
+}
+
+#[test]
+fn referrer_info_should_count_linked_accounts_when_code_was_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code.clone()));
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), TREASURY));
+		assert_ok!(Referrals::link_code(RuntimeOrigin::signed(CHARLIE), code));
+		// ACT & ASSERT
+		assert_eq!(Referrals::referrer_info(&ALICE, value_in_hdx), None);
+		let info = Referrals::referrer_info(&TREASURY, value_in_hdx).unwrap();
+		assert_eq!(info.linked_accounts, 2);
+	});
+}
+
+#[test]
+fn referrer_info_should_return_volume_needed_to_reach_next_level() {
+	let mut volumes = HashMap::new();
+	volumes.insert(Level::Tier0, Some(0));
+	volumes.insert(Level::Tier1, Some(10_000_000_000_000));
+
+	ExtBuilder::default()
+		.with_tier_volumes(volumes)
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			Referrer::<Test>::insert(ALICE, (Level::Tier0, 4_000_000_000_000));
+			// ACT
+			let info = Referrals::referrer_info(&ALICE, value_in_hdx).unwrap();
+			// ASSERT
+			assert_eq!(info.level, Level::Tier0);
+			assert_eq!(info.volume, 4_000_000_000_000);
+			assert_eq!(info.volume_to_next_level, Some(6_000_000_000_000));
+
+			Referrer::<Test>::insert(ALICE, (Level::Tier4, 4_000_000_000_000));
+			let info = Referrals::referrer_info(&ALICE, value_in_hdx).unwrap();
+			assert_eq!(info.volume_to_next_level, None);
+		});
+}
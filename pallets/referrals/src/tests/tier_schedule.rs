@@ -0,0 +1,156 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn tier(volume: Balance, referrer: u32) -> Tier {
+	Tier {
+		volume,
+		rewards: FeeDistribution {
+			referrer: Permill::from_percent(referrer),
+			trader: Permill::zero(),
+			external: Permill::zero(),
+		},
+	}
+}
+
+fn schedule(tiers: Vec<Tier>) -> BoundedVec<Tier, <Test as Config>::MaxTiers> {
+	tiers.try_into().unwrap()
+}
+
+#[test]
+fn set_tier_schedule_should_fail_when_not_correct_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_tier_schedule(RuntimeOrigin::signed(ALICE), DAI, schedule(vec![tier(0, 10)])),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_tier_schedule_should_fail_when_tiers_are_not_sorted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::set_tier_schedule(RuntimeOrigin::root(), DAI, schedule(vec![tier(1_000, 10), tier(0, 20)])),
+			Error::<Test>::IncorrectTierSchedule
+		);
+		assert_noop!(
+			Referrals::set_tier_schedule(
+				RuntimeOrigin::root(),
+				DAI,
+				schedule(vec![tier(1_000, 10), tier(1_000, 20)])
+			),
+			Error::<Test>::IncorrectTierSchedule
+		);
+	});
+}
+
+#[test]
+fn set_tier_schedule_should_fail_when_total_percentage_exceeds_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut invalid = tier(1_000, 60);
+		invalid.rewards.trader = Permill::from_percent(50);
+		assert_noop!(
+			Referrals::set_tier_schedule(RuntimeOrigin::root(), DAI, schedule(vec![tier(0, 10), invalid])),
+			Error::<Test>::IncorrectRewardPercentage
+		);
+	});
+}
+
+#[test]
+fn set_tier_schedule_should_store_schedule_and_emit_event() {
+	ExtBuilder::default().build().execute_with(|| {
+		let tiers = schedule(vec![tier(0, 10), tier(1_000, 20)]);
+		assert_ok!(Referrals::set_tier_schedule(RuntimeOrigin::root(), DAI, tiers.clone()));
+		assert_eq!(Referrals::asset_tier_schedule(DAI), tiers.clone());
+		expect_events(vec![Event::TierScheduleSet { asset_id: DAI, tiers }.into()]);
+	});
+}
+
+#[test]
+fn set_tier_schedule_should_remove_schedule_when_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Referrals::set_tier_schedule(
+			RuntimeOrigin::root(),
+			DAI,
+			schedule(vec![tier(0, 10)])
+		));
+		assert_ok!(Referrals::set_tier_schedule(
+			RuntimeOrigin::root(),
+			DAI,
+			schedule(vec![])
+		));
+		assert!(!AssetTierSchedules::<Test>::contains_key(DAI));
+	});
+}
+
+#[test]
+fn process_trade_fee_should_use_highest_reached_tier_of_asset_schedule() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(30),
+				trader: Permill::zero(),
+				external: Permill::zero(),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			assert_ok!(Referrals::set_tier_schedule(
+				RuntimeOrigin::root(),
+				DAI,
+				schedule(vec![tier(0, 10), tier(1_000, 50)])
+			));
+			// ACT
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// ASSERT
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 1_000_000_000);
+
+			// ARRANGE
+			Referrer::<Test>::insert(ALICE, (Level::Tier0, 1_000));
+			// ACT
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// ASSERT
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 6_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_use_level_rewards_when_no_tier_of_asset_schedule_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::zero(),
+				external: Permill::zero(),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			assert_ok!(Referrals::set_tier_schedule(
+				RuntimeOrigin::root(),
+				DAI,
+				schedule(vec![tier(1_000, 10)])
+			));
+			// ACT
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// ASSERT
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 5_000_000_000);
+		});
+}
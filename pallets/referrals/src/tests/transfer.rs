@@ -0,0 +1,147 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn transfer_code_should_move_code_to_new_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// ACT
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// ASSERT
+		assert_eq!(Referrals::referral_account(code.clone()), Some(CHARLIE));
+		assert_eq!(Referrals::referral_code(CHARLIE), Some(code.clone()));
+		assert_eq!(Referrals::referral_code(ALICE), None);
+		assert_eq!(Referrals::referrer_level(CHARLIE), Some((Level::Tier0, 0)));
+		assert_eq!(Referrals::referrer_level(ALICE), None);
+		assert_eq!(Referrals::transferred_code(ALICE), Some((code, 0)));
+	});
+}
+
+#[test]
+fn transfer_code_should_move_referrer_level_and_volume() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+		Referrer::<Test>::insert(ALICE, (Level::Tier2, 1_000));
+		// ACT
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// ASSERT
+		assert_eq!(Referrals::referrer_level(CHARLIE), Some((Level::Tier2, 1_000)));
+	});
+}
+
+#[test]
+fn transfer_code_should_fail_when_account_has_no_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
+
+#[test]
+fn transfer_code_should_fail_when_receiver_is_referrer() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"OTHER".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(BOB), code));
+			// ACT & ASSERT
+			assert_noop!(
+				Referrals::transfer_code(RuntimeOrigin::signed(ALICE), BOB),
+				Error::<Test>::AlreadyRegistered
+			);
+			assert_noop!(
+				Referrals::transfer_code(RuntimeOrigin::signed(ALICE), ALICE),
+				Error::<Test>::AlreadyRegistered
+			);
+		});
+}
+
+#[test]
+fn linked_traders_should_follow_transferred_code() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::zero(),
+				external: Permill::zero(),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+			// ACT
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// ASSERT
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 0);
+			assert_eq!(ReferrerShares::<Test>::get(CHARLIE), 5_000_000_000);
+			assert_eq!(Referrals::linked_referral_account(BOB), Some(CHARLIE));
+		});
+}
+
+#[test]
+fn register_code_should_fail_when_account_transferred_its_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// ACT & ASSERT
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"OTHER".to_vec().try_into().unwrap();
+		assert_noop!(
+			Referrals::register_code(RuntimeOrigin::signed(ALICE), code),
+			Error::<Test>::AlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn transfer_code_should_work_when_code_is_transferred_back() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// ACT
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(CHARLIE), ALICE));
+		// ASSERT
+		assert_eq!(Referrals::referral_account(code.clone()), Some(ALICE));
+		assert_eq!(Referrals::transferred_code(ALICE), None);
+		assert_eq!(Referrals::transferred_code(CHARLIE), Some((code, 0)));
+	});
+}
+
+#[test]
+fn transfer_code_should_emit_event() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ARRANGE
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// ACT
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// ASSERT
+		expect_events(vec![Event::CodeTransferred {
+			code,
+			from: ALICE,
+			to: CHARLIE,
+		}
+		.into()]);
+	});
+}
@@ -0,0 +1,170 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const TIER1_VOLUME: Balance = 10_000_000_000_000;
+const TIER2_VOLUME: Balance = 20_000_000_000_000;
+
+fn tier_volumes() -> HashMap<Level, Option<Balance>> {
+	let mut volumes = HashMap::new();
+	volumes.insert(Level::Tier0, Some(0));
+	volumes.insert(Level::Tier1, Some(TIER1_VOLUME));
+	volumes.insert(Level::Tier2, Some(TIER2_VOLUME));
+	volumes.insert(Level::Tier3, Some(u128::MAX));
+	volumes.insert(Level::Tier4, Some(u128::MAX));
+	volumes
+}
+
+#[test]
+fn decay_volume_should_halve_volume_every_half_life() {
+	ExtBuilder::default()
+		.with_volume_half_life(100)
+		.build()
+		.execute_with(|| {
+			assert_eq!(Referrals::decay_volume(1_000, 0), 1_000);
+			assert_eq!(Referrals::decay_volume(1_000, 50), 750);
+			assert_eq!(Referrals::decay_volume(1_000, 100), 500);
+			assert_eq!(Referrals::decay_volume(1_000, 250), 187);
+			assert_eq!(Referrals::decay_volume(u128::MAX, 12_800), 0);
+		});
+}
+
+#[test]
+fn decay_volume_should_not_change_volume_when_half_life_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Referrals::decay_volume(1_000, 1_000_000), 1_000);
+	});
+}
+
+#[test]
+fn claim_rewards_should_decrease_referrer_level_when_volume_decayed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 20_000_000_000_000 - 1), (ALICE, 1)])
+		.with_tier_volumes(tier_volumes())
+		.with_volume_half_life(100)
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			Referrer::<Test>::insert(ALICE, (Level::Tier2, TIER2_VOLUME));
+			ReferrerVolumeUpdatedAt::<Test>::insert(ALICE, 1);
+			System::set_block_number(101);
+			// ACT
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// ASSERT
+			assert_eq!(Referrals::referrer_level(ALICE), Some((Level::Tier1, TIER1_VOLUME + 1)));
+			assert_eq!(Referrals::referrer_volume_updated_at(ALICE), Some(101));
+			expect_events(vec![Event::LevelDown {
+				who: ALICE,
+				level: Level::Tier1,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn claim_rewards_should_start_tracking_volume_update_when_decay_enabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(Pallet::<Test>::pot_account_id(), HDX, 20_000_000_000_000)])
+		.with_referrer_shares(vec![(BOB, 5_000_000_000_000), (ALICE, 15_000_000_000_000)])
+		.with_tier_volumes(tier_volumes())
+		.with_volume_half_life(100)
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			// ACT
+			assert_ok!(Referrals::claim_rewards(RuntimeOrigin::signed(ALICE)));
+			// ASSERT
+			assert_eq!(
+				Referrals::referrer_level(ALICE),
+				Some((Level::Tier1, 15_000_000_000_000))
+			);
+			assert_eq!(Referrals::referrer_volume_updated_at(ALICE), Some(1));
+			System::set_block_number(101);
+			assert_eq!(
+				Referrals::current_referrer_level(&ALICE),
+				Some((Level::Tier0, 7_500_000_000_000))
+			);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_use_decayed_referrer_level() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tier_volumes(tier_volumes())
+		.with_volume_half_life(100)
+		.with_tiers(vec![
+			(
+				DAI,
+				Level::Tier1,
+				FeeDistribution {
+					referrer: Permill::from_percent(10),
+					trader: Permill::zero(),
+					external: Permill::zero(),
+				},
+			),
+			(
+				DAI,
+				Level::Tier2,
+				FeeDistribution {
+					referrer: Permill::from_percent(50),
+					trader: Permill::zero(),
+					external: Permill::zero(),
+				},
+			),
+		])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			Referrer::<Test>::insert(ALICE, (Level::Tier2, TIER2_VOLUME));
+			ReferrerVolumeUpdatedAt::<Test>::insert(ALICE, 1);
+			System::set_block_number(101);
+			// ACT
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// ASSERT
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 1_000_000_000);
+			assert_eq!(
+				Referrals::current_referrer_level(&ALICE),
+				Some((Level::Tier1, TIER1_VOLUME))
+			);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_use_stored_referrer_level_when_decay_is_disabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tier_volumes(tier_volumes())
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier2,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::zero(),
+				external: Permill::zero(),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// ARRANGE
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			Referrer::<Test>::insert(ALICE, (Level::Tier2, TIER2_VOLUME));
+			ReferrerVolumeUpdatedAt::<Test>::insert(ALICE, 1);
+			System::set_block_number(1_001);
+			// ACT
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// ASSERT
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 5_000_000_000);
+		});
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the staking pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_staking::types::{PayablePercentageProjection, PositionRewards, Vote};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query staking rewards.
+	pub trait StakingApi<AccountId, PositionId, BlockNumber> where
+		AccountId: Codec,
+		PositionId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns rewards the position would receive if it was claimed now.
+		fn position_rewards(position_id: PositionId) -> Option<PositionRewards>;
+
+		/// Returns account's position id and rewards the position would receive if it was claimed now.
+		fn account_rewards(who: AccountId) -> Option<(PositionId, PositionRewards)>;
+
+		/// Returns projection of position's payable percentage at given blocks without and with `additional_votes`.
+		fn payable_percentage_projection(
+			position_id: PositionId,
+			blocks: Vec<BlockNumber>,
+			additional_votes: Vec<Vote>,
+		) -> Option<Vec<PayablePercentageProjection<BlockNumber>>>;
+	}
+}
@@ -17,6 +17,7 @@
 
 use super::*;
 
+use crate::traits::BenchmarkHelper;
 use crate::types::{Conviction, Vote, Voting};
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
@@ -195,5 +196,104 @@ benchmarks! {
 		assert!(old_caller_1_balance < T::Currency::free_balance(hdx, &caller_1))
 	}
 
+	set_position_transfers {
+		init_staking::<T>(1_000 * UNIT)?;
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, true)
+	verify {
+		assert!(Pallet::<T>::position_transfers_enabled());
+	}
+
+	transfer_position {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::set_position_transfers(T::AuthorityOrigin::try_successful_origin().unwrap(), true)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller_0).unwrap().unwrap();
+	}: _(RawOrigin::Signed(caller_0.clone()), position_id, caller_1.clone())
+	verify {
+		assert_eq!(Pallet::<T>::get_user_position_id(&caller_1)?, Some(position_id));
+	}
+
+	split_position {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::set_position_transfers(T::AuthorityOrigin::try_successful_origin().unwrap(), true)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller_0).unwrap().unwrap();
+	}: _(RawOrigin::Signed(caller_0.clone()), position_id, 20_000 * UNIT, caller_1.clone())
+	verify {
+		assert!(Pallet::<T>::get_user_position_id(&caller_1)?.is_some());
+		assert_eq!(Pallet::<T>::positions(position_id).unwrap().stake, 30_000 * UNIT);
+	}
+
+	set_reward_asset {
+		let reward_asset_id = T::BenchmarkHelper::register_reward_asset()?;
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, reward_asset_id, true)
+	verify {
+		assert!(Pallet::<T>::is_reward_asset(reward_asset_id));
+	}
+
+	process_reward_asset {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+		let reward_asset_id = T::BenchmarkHelper::register_reward_asset()?;
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::set_reward_asset(T::AuthorityOrigin::try_successful_origin().unwrap(), reward_asset_id, false)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		let pot = Pallet::<T>::pot_account_id();
+		T::Currency::update_balance(reward_asset_id, &pot, (20_000 * UNIT) as i128)?;
+
+		let total_stake = Pallet::<T>::staking().total_stake;
+		let payable_percentage = FixedU128::from_rational(1, 2);
+	}: {
+		Pallet::<T>::process_reward_assets(total_stake, |reward_asset| {
+			Pallet::<T>::claim_reward_asset(&caller_1, position_id, 50_000 * UNIT, reward_asset, payable_percentage, false)
+		})?
+	}
+	verify {
+		assert_eq!(T::Currency::free_balance(reward_asset_id, &caller_1), 5_000 * UNIT);
+	}
+
+	claim_deferred_reward_asset {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let reward_asset_id = T::BenchmarkHelper::register_reward_asset()?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::set_reward_asset(T::AuthorityOrigin::try_successful_origin().unwrap(), reward_asset_id, false)?;
+
+		let pot = Pallet::<T>::pot_account_id();
+		T::Currency::update_balance(reward_asset_id, &pot, (20_000 * UNIT) as i128)?;
+		RewardAssets::<T>::mutate(|reward_assets| reward_assets[0].pot_reserved_balance = 10_000 * UNIT);
+		DeferredRewardAssets::<T>::insert(&caller, reward_asset_id, 10_000 * UNIT);
+	}: _(RawOrigin::Signed(caller.clone()), reward_asset_id)
+	verify {
+		assert_eq!(T::Currency::free_balance(reward_asset_id, &caller), 10_000 * UNIT);
+		assert!(!DeferredRewardAssets::<T>::contains_key(&caller, reward_asset_id));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -1,5 +1,5 @@
 use crate::pallet::{Positions, Votes, VotesRewarded};
-use crate::traits::{GetReferendumState, VestingDetails};
+use crate::traits::{ActionHook, GetReferendumState, VestingDetails};
 use crate::types::{Action, Balance, Conviction, ReferendumIndex, Vote};
 use crate::{Config, Error, Pallet};
 use frame_support::defensive;
@@ -9,7 +9,6 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use pallet_conviction_voting::traits::VotingHooks;
 use pallet_conviction_voting::AccountVote;
 use sp_core::Get;
-use sp_runtime::FixedPointNumber;
 
 pub struct StakingConvictionVoting<T>(sp_std::marker::PhantomData<T>);
 
@@ -100,32 +99,19 @@ where
 			return;
 		}
 
-		let _ = Positions::<T>::try_mutate(position_id, |maybe_position| -> DispatchResult {
-			if let Some(position) = maybe_position.as_mut() {
-				let max_position_vote = Conviction::max_multiplier().saturating_mul_int(position.stake);
-
-				if let Some(vote_idx) = Votes::<T>::get(position_id)
-					.votes
-					.iter()
-					.position(|(idx, _)| *idx == ref_index)
-				{
-					let (ref_idx, vote) = Votes::<T>::get(position_id).votes[vote_idx];
-					debug_assert_eq!(ref_idx, ref_index, "Referendum index mismatch");
-					let points =
-						Pallet::<T>::calculate_points_for_action(Action::DemocracyVote, vote, max_position_vote);
-					// Add points only if referendum is finished
-					if let Some(is_ongoing) = ongoing {
-						if !is_ongoing {
-							position.action_points = position.action_points.saturating_add(points);
-						}
-					}
-					Votes::<T>::mutate(position_id, |voting| {
-						voting.votes.remove(vote_idx);
-					});
-				}
-			}
-			Ok(())
-		});
+		let Ok(vote) = Votes::<T>::try_mutate(position_id, |voting| -> Result<Vote, ()> {
+			let vote_idx = voting.votes.iter().position(|(idx, _)| *idx == ref_index).ok_or(())?;
+			let (ref_idx, vote) = voting.votes.remove(vote_idx);
+			debug_assert_eq!(ref_idx, ref_index, "Referendum index mismatch");
+			Ok(vote)
+		}) else {
+			return;
+		};
+
+		// Add points only if referendum is finished
+		if ongoing == Some(false) {
+			Pallet::<T>::on_action(who, Action::DemocracyVote, vote.amount, vote.conviction.multiplier());
+		}
 	}
 
 	fn balance_locked_on_unsuccessful_vote(who: &T::AccountId, ref_index: ReferendumIndex) -> Option<Balance> {
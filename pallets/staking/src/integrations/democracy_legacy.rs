@@ -1,6 +1,7 @@
 #![allow(unused_imports)]
 
 use crate::pallet::{PositionVotes, Positions, ProcessedVotes};
+use crate::traits::ActionHook;
 use crate::types::Vote;
 use crate::types::{Action, Balance, Conviction};
 use crate::{Config, Pallet};
@@ -37,32 +38,19 @@ where
 			return;
 		}
 
-		let _ = Positions::<T>::try_mutate(position_id, |maybe_position| -> DispatchResult {
-			if let Some(position) = maybe_position.as_mut() {
-				let max_position_vote = Conviction::max_multiplier().saturating_mul_int(position.stake);
-
-				if let Some(vote_idx) = PositionVotes::<T>::get(position_id)
-					.votes
-					.iter()
-					.position(|(idx, _)| *idx == ref_index)
-				{
-					let (ref_idx, vote) = PositionVotes::<T>::get(position_id).votes[vote_idx];
-					debug_assert_eq!(ref_idx, ref_index, "Referendum index mismatch");
-					let points =
-						Pallet::<T>::calculate_points_for_action(Action::DemocracyVote, vote, max_position_vote);
-					// Add points only if referendum is finished
-					if let Some(is_finished) = is_finished {
-						if is_finished {
-							position.action_points = position.action_points.saturating_add(points);
-						}
-					}
-					PositionVotes::<T>::mutate(position_id, |voting| {
-						voting.votes.remove(vote_idx);
-					});
-				}
-			}
-			Ok(())
-		});
+		let Ok(vote) = PositionVotes::<T>::try_mutate(position_id, |voting| -> Result<Vote, ()> {
+			let vote_idx = voting.votes.iter().position(|(idx, _)| *idx == ref_index).ok_or(())?;
+			let (ref_idx, vote) = voting.votes.remove(vote_idx);
+			debug_assert_eq!(ref_idx, ref_index, "Referendum index mismatch");
+			Ok(vote)
+		}) else {
+			return;
+		};
+
+		// Add points only if referendum is finished
+		if is_finished == Some(true) {
+			Pallet::<T>::on_action(who, Action::DemocracyVote, vote.amount, vote.conviction.multiplier());
+		}
 	}
 
 	fn remove_vote_locks_if_needed(who: &T::AccountId, ref_index: ReferendumIndex) -> Option<Balance> {
@@ -18,17 +18,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::manual_inspect)]
 
-use crate::traits::{ActionData, GetReferendumState, PayablePercentage, VestingDetails};
-use crate::types::{Action, Balance, Period, Point, Position, StakingData};
+use crate::traits::{ActionData, ActionHook, GetReferendumState, PayablePercentage, VestingDetails};
+use crate::types::{
+	Action, Balance, PayablePercentageProjection, Period, Point, Position, PositionRewardAsset, PositionRewards,
+	RewardAsset, StakingData, Vote,
+};
 use frame_support::ensure;
 use frame_support::{
 	pallet_prelude::DispatchResult,
 	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
 	traits::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate},
 	traits::{DefensiveOption, LockIdentifier},
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use hydra_dx_math::staking as math;
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade};
 use orml_traits::{GetByKey, MultiCurrency, MultiLockableCurrency};
 use sp_core::Get;
 use sp_runtime::traits::{AccountIdConversion, CheckedAdd, One};
@@ -38,6 +43,7 @@ use sp_runtime::{
 };
 use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
 use sp_std::num::NonZeroU128;
+use sp_std::vec::Vec;
 
 pub mod migrations;
 
@@ -170,6 +176,7 @@ pub mod pallet {
 		/// Max amount of action points user can receive for action. Users receives
 		/// percentage of this based on how much of staking power they used. e.g. for democracy
 		/// vote it is percentage of stake used for voting.
+		/// Setting zero for an action disables rewarding of the action.
 		type MaxPointsPerAction: GetByKey<Action, u32>;
 
 		/// Democracy referendum state.
@@ -178,10 +185,33 @@ pub mod pallet {
 		/// Provides information about amount of vested tokens.
 		type Vesting: VestingDetails<Self::AccountId, Balance>;
 
+		/// Max number of reward assets distributed to stakers in addition to the native asset.
+		#[pallet::constant]
+		type MaxRewardAssets: Get<u32>;
+
+		/// Router used to convert reward assets to the native asset when they are claimed.
+		type RouteExecutor: RouterT<
+			Self::RuntimeOrigin,
+			Self::AssetId,
+			Balance,
+			Trade<Self::AssetId>,
+			AmountInAndOut<Balance>,
+		>;
+
+		/// Provides routes for conversion of reward assets to the native asset.
+		type RouteProvider: RouteProvider<Self::AssetId>;
+
+		/// Weight information for the router's trades.
+		type AmmTradeWeights: AmmTradeWeights<Trade<Self::AssetId>>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// Max mumber of locks per account.  It's used in on_vote_worst_case benchmarks.
 		type MaxLocks: Get<u32>;
 
+		#[cfg(feature = "runtime-benchmarks")]
+		/// Benchmark helper providing reward asset.
+		type BenchmarkHelper: crate::traits::BenchmarkHelper<Self::AssetId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -253,6 +283,36 @@ pub mod pallet {
 	pub(super) type SixSecBlocksSince<T: Config> =
 		StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultSixSecSince<T>>;
 
+	#[pallet::storage]
+	/// Whether staking positions can be transferred or split to other accounts.
+	#[pallet::getter(fn position_transfers_enabled)]
+	pub(super) type PositionTransfersEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	/// Reward assets distributed to stakers in addition to the native asset.
+	#[pallet::getter(fn reward_assets)]
+	pub(super) type RewardAssets<T: Config> =
+		StorageValue<_, BoundedVec<RewardAsset<T::AssetId>, T::MaxRewardAssets>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Position's state of the reward assets.
+	#[pallet::getter(fn position_reward_asset)]
+	pub(super) type PositionRewardAssets<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PositionItemId,
+		Blake2_128Concat,
+		T::AssetId,
+		PositionRewardAsset,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Rewards of the reward assets which couldn't be transferred when position was unstaked.
+	#[pallet::getter(fn deferred_reward_asset)]
+	pub(super) type DeferredRewardAssets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AssetId, Balance, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -300,6 +360,70 @@ pub mod pallet {
 			accumulated_rps: FixedU128,
 			total_stake: Balance,
 		},
+
+		/// Action points were added to the staking position for the action reported via `ActionHook`.
+		ActionPointsAdded {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			action: Action,
+			points: Point,
+		},
+
+		/// Transfers of staking positions were enabled or disabled.
+		PositionTransfersSet { enabled: bool },
+
+		/// Staking position was transferred to another account.
+		PositionTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			position_id: T::PositionItemId,
+			stake: Balance,
+		},
+
+		/// Part of the staking position was split into the new position owned by `to`.
+		PositionSplit {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			to: T::AccountId,
+			new_position_id: T::PositionItemId,
+			stake: Balance,
+		},
+
+		/// Reward asset was added or its configuration was updated.
+		RewardAssetSet {
+			asset_id: T::AssetId,
+			convert_to_native: bool,
+		},
+
+		/// Rewards of the reward asset were claimed.
+		RewardAssetClaimed {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			asset_id: T::AssetId,
+			paid_rewards: Balance,
+			slashed_rewards: Balance,
+			paid_asset_id: T::AssetId,
+			paid_amount: Balance,
+		},
+
+		/// Rewards of the reward asset couldn't be transferred and were kept to be paid later.
+		RewardAssetPaymentDeferred {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			asset_id: T::AssetId,
+			deferred_rewards: Balance,
+			slashed_rewards: Balance,
+			error: DispatchError,
+		},
+
+		/// Deferred rewards of the reward asset of unstaked position were claimed.
+		DeferredRewardAssetClaimed {
+			who: T::AccountId,
+			asset_id: T::AssetId,
+			paid_rewards: Balance,
+			paid_asset_id: T::AssetId,
+			paid_amount: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -340,6 +464,21 @@ pub mod pallet {
 		/// Position contains processed votes. Removed these votes first before increasing stake or claiming.
 		ExistingProcessedVotes,
 
+		/// Transfers of staking positions are disabled.
+		PositionTransfersDisabled,
+
+		/// Native asset can't be used as reward asset.
+		InvalidRewardAsset,
+
+		/// Maximum number of reward assets was reached.
+		MaxRewardAssetsReached,
+
+		/// Reward asset couldn't be converted to the native asset.
+		RewardConversionFailed,
+
+		/// Account has no deferred rewards of the reward asset.
+		NoDeferredRewards,
+
 		/// Action cannot be completed because unexpected error has occurred. This should be reported
 		/// to protocol maintainers.
 		InconsistentState(InconsistentStateError),
@@ -362,6 +501,9 @@ pub mod pallet {
 
 		/// Arithmetic error.
 		Arithmetic,
+
+		/// Reward asset with deferred rewards is not configured.
+		RewardAssetNotFound,
 	}
 
 	impl<T> From<InconsistentStateError> for Error<T> {
@@ -419,7 +561,7 @@ pub mod pallet {
 		/// Emits `PositionCreated` event when successful.
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::stake())]
+		#[pallet::weight(<T as Config>::WeightInfo::stake().saturating_add(Pallet::<T>::reward_assets_weight(false)))]
 		pub fn stake(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -439,6 +581,19 @@ pub mod pallet {
 				let position_id =
 					Self::create_position_and_mint_nft(&who, amount, staking.accumulated_reward_per_stake)?;
 
+				Self::process_reward_assets(staking.total_stake, |reward_asset| {
+					PositionRewardAssets::<T>::insert(
+						position_id,
+						reward_asset.asset_id,
+						PositionRewardAsset {
+							reward_per_stake: reward_asset.accumulated_reward_per_stake,
+							accumulated_unpaid_rewards: Zero::zero(),
+							deferred_rewards: Zero::zero(),
+						},
+					);
+					Ok(())
+				})?;
+
 				T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who, amount)?;
 
 				staking.add_stake(amount)?;
@@ -470,7 +625,7 @@ pub mod pallet {
 		/// Emits `StakeAdded` event when successful.
 		///
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::increase_stake())]
+		#[pallet::weight(<T as Config>::WeightInfo::increase_stake().saturating_add(Pallet::<T>::reward_assets_weight(false)))]
 		pub fn increase_stake(origin: OriginFor<T>, position_id: T::PositionItemId, amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -524,6 +679,10 @@ pub mod pallet {
 					position.accumulated_unpaid_rewards = unpaid_rewards;
 					position.reward_per_stake = staking.accumulated_reward_per_stake;
 
+					Self::process_reward_assets(staking.total_stake, |reward_asset| {
+						Self::accumulate_reward_asset_rewards(position_id, position.stake, reward_asset)
+					})?;
+
 					let points =
 						Self::get_points(position, current_period, created_at).ok_or(Error::<T>::Arithmetic)?;
 					let slash_points = math::calculate_slashed_points(
@@ -586,7 +745,7 @@ pub mod pallet {
 		/// Emits `RewardsClaimed` event when successful.
 		///
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		#[pallet::weight(<T as Config>::WeightInfo::claim().saturating_add(Pallet::<T>::reward_assets_weight(true)))]
 		pub fn claim(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -628,6 +787,18 @@ pub mod pallet {
 						T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards_to_pay)?;
 					}
 
+					let is_claimable = current_period.saturating_sub(created_at) > T::UnclaimablePeriods::get();
+					Self::process_reward_assets(staking.total_stake, |reward_asset| {
+						Self::claim_reward_asset(
+							&who,
+							position_id,
+							position.stake,
+							reward_asset,
+							payable_percentage,
+							!is_claimable,
+						)
+					})?;
+
 					let rewards_to_unlock = position.accumulated_locked_rewards;
 					position.accumulated_locked_rewards = Zero::zero();
 
@@ -640,13 +811,12 @@ pub mod pallet {
 						.checked_add(points_to_slash)
 						.ok_or(Error::<T>::Arithmetic)?;
 
-					let slashed_unpaid_rewards =
-						if current_period.saturating_sub(created_at) > T::UnclaimablePeriods::get() {
-							position.accumulated_unpaid_rewards = Zero::zero();
-							accumulated_unpaid_rewards
-						} else {
-							Zero::zero()
-						};
+					let slashed_unpaid_rewards = if is_claimable {
+						position.accumulated_unpaid_rewards = Zero::zero();
+						accumulated_unpaid_rewards
+					} else {
+						Zero::zero()
+					};
 					position.reward_per_stake = staking.accumulated_reward_per_stake;
 
 					T::Currency::set_lock(
@@ -684,6 +854,8 @@ pub mod pallet {
 		/// Function calculates and pays latest rewards, unlocks all the locked rewards and staked
 		/// tokens for staking position and burns NFT representing staking position.
 		/// Unpaid allocated rewards are returned to the Staking for redistribution.
+		/// Rewards of the reward assets which couldn't be transferred are kept for the owner and can
+		/// be claimed by `claim_deferred_reward_asset`.
 		///
 		/// Parameters:
 		/// - `position_id`: The identifier of the position to be destroyed.
@@ -691,7 +863,7 @@ pub mod pallet {
 		/// Emits `RewardsClaimed` and `Unstaked` events when successful.
 		///
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::unstake())]
+		#[pallet::weight(<T as Config>::WeightInfo::unstake().saturating_add(Pallet::<T>::reward_assets_weight(true)))]
 		pub fn unstake(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -733,6 +905,26 @@ pub mod pallet {
 						T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards_to_pay)?;
 					}
 
+					Self::process_reward_assets(staking.total_stake, |reward_asset| {
+						Self::claim_reward_asset(
+							&who,
+							position_id,
+							position.stake,
+							reward_asset,
+							payable_percentage,
+							false,
+						)
+					})?;
+					// Position has at most `MaxRewardAssets` reward asset states.
+					for (asset_id, position_reward_asset) in PositionRewardAssets::<T>::drain_prefix(position_id) {
+						if !position_reward_asset.deferred_rewards.is_zero() {
+							DeferredRewardAssets::<T>::mutate(&who, asset_id, |deferred_rewards| {
+								*deferred_rewards =
+									deferred_rewards.saturating_add(position_reward_asset.deferred_rewards)
+							});
+						}
+					}
+
 					staking.total_stake = staking
 						.total_stake
 						.checked_sub(position.stake)
@@ -772,6 +964,235 @@ pub mod pallet {
 				})
 			})
 		}
+
+		/// Enable or disable transfers and splits of staking positions.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `enabled`: Whether positions can be transferred or split to other accounts.
+		///
+		/// Emits `PositionTransfersSet` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_position_transfers())]
+		pub fn set_position_transfers(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			PositionTransfersEnabled::<T>::put(enabled);
+
+			Self::deposit_event(Event::PositionTransfersSet { enabled });
+
+			Ok(())
+		}
+
+		/// Transfer whole staking position to another account.
+		///
+		/// Staked tokens and rewards locked from `increase_stake` are moved and locked to `to`.
+		/// Position's state e.g. reward per stake, action and slash points is kept so `to`
+		/// continues where `origin` stopped.
+		///
+		/// Transfers must be enabled by `set_position_transfers`. `to` can't have a staking position
+		/// and position can't have any votes.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position to transfer.
+		/// - `to`: Account to transfer the position to.
+		///
+		/// Emits `PositionTransferred` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_position())]
+		pub fn transfer_position(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_can_move_position(&who, position_id, &to)?;
+
+			let position = Positions::<T>::get(position_id)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+			let total_locked = position.get_total_locked()?;
+
+			T::Currency::remove_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who)?;
+			T::Currency::transfer(T::NativeAssetId::get(), &who, &to, total_locked)?;
+			T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &to, total_locked)?;
+
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &position_id, &to)?;
+
+			Self::deposit_event(Event::PositionTransferred {
+				from: who,
+				to,
+				position_id,
+				stake: position.stake,
+			});
+
+			Ok(())
+		}
+
+		/// Split `amount` of the stake from the staking position into the new position owned by `to`.
+		///
+		/// Action points, slash points, unpaid and locked rewards are split proportionally to the
+		/// stake. Both positions keep original reward per stake and creation block. Tokens belonging
+		/// to the new position are moved and locked to `to`.
+		///
+		/// Transfers must be enabled by `set_position_transfers`. `to` can't have a staking position
+		/// and position can't have any votes. Both positions must have at least `MinStake` staked.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position to split.
+		/// - `amount`: Amount of the stake to move to the new position.
+		/// - `to`: Owner of the new position.
+		///
+		/// Emits `PositionSplit` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_position())]
+		pub fn split_position(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount: Balance,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_can_move_position(&who, position_id, &to)?;
+
+			Positions::<T>::try_mutate(position_id, |maybe_position| {
+				let position = maybe_position
+					.as_mut()
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+				ensure!(
+					amount >= T::MinStake::get() && position.stake.saturating_sub(amount) >= T::MinStake::get(),
+					Error::<T>::InsufficientStake
+				);
+
+				let new_position_id = Self::get_next_position_id()?;
+				for (asset_id, mut position_reward_asset) in
+					PositionRewardAssets::<T>::iter_prefix(position_id).collect::<Vec<_>>()
+				{
+					let new_position_reward_asset = position_reward_asset.split(amount, position.stake)?;
+					PositionRewardAssets::<T>::insert(position_id, asset_id, position_reward_asset);
+					PositionRewardAssets::<T>::insert(new_position_id, asset_id, new_position_reward_asset);
+				}
+
+				let new_position = position.split(amount)?;
+				let moved_amount = new_position.get_total_locked()?;
+
+				T::Currency::set_lock(
+					STAKING_LOCK_ID,
+					T::NativeAssetId::get(),
+					&who,
+					position.get_total_locked()?,
+				)?;
+				T::Currency::transfer(T::NativeAssetId::get(), &who, &to, moved_amount)?;
+				T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &to, moved_amount)?;
+
+				Positions::<T>::insert(new_position_id, new_position);
+				T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &new_position_id, &to)?;
+
+				Self::deposit_event(Event::PositionSplit {
+					who,
+					position_id,
+					to,
+					new_position_id,
+					stake: amount,
+				});
+
+				Ok(())
+			})
+		}
+
+		/// Add reward asset distributed to stakers in addition to the native asset or update its
+		/// configuration.
+		///
+		/// Reward asset has its own accumulated reward per stake. Rewards are collected from the
+		/// reward asset's balance of the `pot` and paid on `claim` and `unstake` with the same payable
+		/// percentage as native rewards.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `asset_id`: Reward asset. Native asset can't be used.
+		/// - `convert_to_native`: Whether claimed rewards are converted to the native asset via the
+		///   router. Rewards are paid in the reward asset if conversion fails.
+		///
+		/// Emits `RewardAssetSet` event when successful.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_asset())]
+		pub fn set_reward_asset(origin: OriginFor<T>, asset_id: T::AssetId, convert_to_native: bool) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::InvalidRewardAsset);
+
+			RewardAssets::<T>::try_mutate(|reward_assets| -> DispatchResult {
+				if let Some(reward_asset) = reward_assets.iter_mut().find(|r| r.asset_id == asset_id) {
+					reward_asset.convert_to_native = convert_to_native;
+				} else {
+					reward_assets
+						.try_push(RewardAsset::new(asset_id, convert_to_native))
+						.map_err(|_| Error::<T>::MaxRewardAssetsReached)?;
+				}
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RewardAssetSet {
+				asset_id,
+				convert_to_native,
+			});
+
+			Ok(())
+		}
+
+		/// Claim rewards of the reward asset which couldn't be transferred when caller's position
+		/// was unstaked, e.g. because they were below the existential deposit.
+		///
+		/// Rewards are converted to the native asset if it is configured for the reward asset.
+		///
+		/// Parameters:
+		/// - `asset_id`: Reward asset to claim deferred rewards of.
+		///
+		/// Emits `DeferredRewardAssetClaimed` event when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_deferred_reward_asset().saturating_add(Pallet::<T>::reward_asset_conversion_weight(*asset_id)))]
+		pub fn claim_deferred_reward_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let deferred_rewards = DeferredRewardAssets::<T>::take(&who, asset_id);
+			ensure!(!deferred_rewards.is_zero(), Error::<T>::NoDeferredRewards);
+
+			RewardAssets::<T>::try_mutate(|reward_assets| -> DispatchResult {
+				let reward_asset = reward_assets
+					.iter_mut()
+					.find(|r| r.asset_id == asset_id)
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::RewardAssetNotFound.into())?;
+
+				let (paid_asset_id, paid_amount) = Self::pay_reward_asset(&who, reward_asset, deferred_rewards)?;
+
+				reward_asset.pot_reserved_balance =
+					reward_asset
+						.pot_reserved_balance
+						.checked_sub(deferred_rewards)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+				Self::deposit_event(Event::DeferredRewardAssetClaimed {
+					who,
+					asset_id,
+					paid_rewards: deferred_rewards,
+					paid_asset_id,
+					paid_amount,
+				});
+
+				Ok(())
+			})
+		}
 	}
 
 	#[pallet::hooks]
@@ -821,6 +1242,35 @@ impl<T: Config> Pallet<T> {
 		Ok(position_id)
 	}
 
+	/// Ensures position can be transferred or split from `who` to `to`.
+	fn ensure_can_move_position(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		to: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+		ensure!(
+			Self::position_transfers_enabled(),
+			Error::<T>::PositionTransfersDisabled
+		);
+		ensure!(Self::is_owner(who, position_id), Error::<T>::Forbidden);
+		ensure!(
+			Self::get_user_position_id(to)?.is_none(),
+			Error::<T>::PositionAlreadyExists
+		);
+
+		// Votes and their locks belong to the account so position with votes can't be moved.
+		use frame_support::StorageDoubleMap;
+		ensure!(
+			Votes::<T>::get(position_id).votes.is_empty()
+				&& PositionVotes::<T>::get(position_id).votes.is_empty()
+				&& !VotesRewarded::<T>::contains_prefix(who),
+			Error::<T>::ExistingVotes
+		);
+
+		Ok(())
+	}
+
 	fn is_owner(who: &T::AccountId, id: T::PositionItemId) -> bool {
 		if let Some(owner) =
 			<T as pallet::Config>::NFTHandler::owner(&<T as pallet::Config>::NFTCollectionId::get(), &id)
@@ -982,7 +1432,7 @@ impl<T: Config> Pallet<T> {
 		asset: T::AssetId,
 		amount: Balance,
 	) -> Result<Option<(Balance, T::AccountId)>, DispatchError> {
-		if asset == T::NativeAssetId::get() && Self::is_initialized() {
+		if (asset == T::NativeAssetId::get() || Self::is_reward_asset(asset)) && Self::is_initialized() {
 			T::Currency::transfer(asset, &source, &Self::pot_account_id(), amount)?;
 			Ok(Some((amount, Self::pot_account_id())))
 		} else {
@@ -996,12 +1446,9 @@ impl<T: Config> Pallet<T> {
 		position: &mut Position<BlockNumberFor<T>>,
 	) -> DispatchResult {
 		Votes::<T>::mutate(position_id, |voting| {
-			let max_position_vote = Conviction::max_multiplier().saturating_mul_int(position.stake);
-
 			voting.votes.retain(|(ref_idx, vote)| {
 				if T::ReferendumInfo::is_referendum_finished(*ref_idx) {
-					let points = Self::calculate_points_for_action(Action::DemocracyVote, vote, max_position_vote);
-					position.action_points = position.action_points.saturating_add(points);
+					Self::add_action_points(who, position_id, position, Action::DemocracyVote, vote);
 					// We need to keep the vote info to determine if the vote should be locked when removed.
 					VotesRewarded::<T>::insert(who, *ref_idx, vote);
 					false
@@ -1013,6 +1460,46 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Adds action points for `action` to the `position` and emits `ActionPointsAdded`.
+	///
+	/// All action points which are persisted are added through this function, including the ones
+	/// reported via `ActionHook`.
+	fn add_action_points<V: ActionData>(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		position: &mut Position<BlockNumberFor<T>>,
+		action: Action,
+		data: V,
+	) -> Point {
+		let points = Self::accrue_action_points(position, action, data);
+
+		if !points.is_zero() {
+			Self::deposit_event(Event::ActionPointsAdded {
+				who: who.clone(),
+				position_id,
+				action,
+				points,
+			});
+		}
+
+		points
+	}
+
+	/// Adds action points for `action` to the `position` without emitting an event.
+	///
+	/// Used directly only to simulate the points of the position, e.g. in runtime API.
+	fn accrue_action_points<V: ActionData>(
+		position: &mut Position<BlockNumberFor<T>>,
+		action: Action,
+		data: V,
+	) -> Point {
+		let max_position_value = Conviction::max_multiplier().saturating_mul_int(position.stake);
+		let points = Self::calculate_points_for_action(action, data, max_position_value);
+		position.action_points = position.action_points.saturating_add(points);
+
+		points
+	}
+
 	/// Returns amount of action points user receives for action.
 	///
 	/// params:
@@ -1031,12 +1518,379 @@ impl<T: Config> Pallet<T> {
 	fn is_initialized() -> bool {
 		Staking::<T>::exists()
 	}
+
+	fn is_reward_asset(asset_id: T::AssetId) -> bool {
+		RewardAssets::<T>::get().iter().any(|r| r.asset_id == asset_id)
+	}
+
+	/// Distributes pending rewards of all reward assets and calls `f` for each of them.
+	fn process_reward_assets<F>(total_stake: Balance, mut f: F) -> DispatchResult
+	where
+		F: FnMut(&mut RewardAsset<T::AssetId>) -> DispatchResult,
+	{
+		RewardAssets::<T>::try_mutate(|reward_assets| {
+			for reward_asset in reward_assets.iter_mut() {
+				Self::update_reward_asset(reward_asset, total_stake)?;
+				f(reward_asset)?;
+			}
+
+			Ok(())
+		})
+	}
+
+	/// This function "distributes" pending rewards of the reward asset if possible.
+	fn update_reward_asset(reward_asset: &mut RewardAsset<T::AssetId>, total_stake: Balance) -> DispatchResult {
+		if total_stake.is_zero() {
+			return Ok(());
+		}
+
+		// NOTE: `pot`'s balance of reward asset is not protected from dusting.
+		let pending_rewards = T::Currency::free_balance(reward_asset.asset_id, &Self::pot_account_id())
+			.saturating_sub(reward_asset.pot_reserved_balance);
+
+		if pending_rewards.is_zero() {
+			return Ok(());
+		}
+
+		let accumulated_rps =
+			math::calculate_accumulated_rps(reward_asset.accumulated_reward_per_stake, pending_rewards, total_stake)
+				.ok_or(Error::<T>::Arithmetic)?;
+
+		if reward_asset.accumulated_reward_per_stake == accumulated_rps {
+			// Rewards are too small to distribute.
+			return Ok(());
+		}
+
+		reward_asset.accumulated_reward_per_stake = accumulated_rps;
+		reward_asset.pot_reserved_balance = reward_asset
+			.pot_reserved_balance
+			.checked_add(pending_rewards)
+			.ok_or(Error::<T>::Arithmetic)?;
+
+		Ok(())
+	}
+
+	/// Moves position's new rewards of the reward asset to its unpaid rewards.
+	fn accumulate_reward_asset_rewards(
+		position_id: T::PositionItemId,
+		stake: Balance,
+		reward_asset: &RewardAsset<T::AssetId>,
+	) -> DispatchResult {
+		PositionRewardAssets::<T>::try_mutate(position_id, reward_asset.asset_id, |position_reward_asset| {
+			let new_rewards = math::calculate_rewards(
+				reward_asset.accumulated_reward_per_stake,
+				position_reward_asset.reward_per_stake,
+				stake,
+			)
+			.ok_or(Error::<T>::Arithmetic)?;
+
+			position_reward_asset.accumulated_unpaid_rewards = position_reward_asset
+				.accumulated_unpaid_rewards
+				.checked_add(new_rewards)
+				.ok_or(Error::<T>::Arithmetic)?;
+			position_reward_asset.reward_per_stake = reward_asset.accumulated_reward_per_stake;
+
+			Ok(())
+		})
+	}
+
+	/// Pays `payable_percentage` of position's rewards of the reward asset and returns the rest
+	/// back to the `pot` for redistribution. Previously deferred rewards are paid in full.
+	///
+	/// If `keep_unpaid` is `true`, rewards are only accumulated to be paid later.
+	///
+	/// Failed payment doesn't fail the claim. Rewards to pay are kept as position's deferred rewards
+	/// and `RewardAssetPaymentDeferred` event is emitted instead.
+	fn claim_reward_asset(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		stake: Balance,
+		reward_asset: &mut RewardAsset<T::AssetId>,
+		payable_percentage: FixedU128,
+		keep_unpaid: bool,
+	) -> DispatchResult {
+		Self::accumulate_reward_asset_rewards(position_id, stake, reward_asset)?;
+
+		if keep_unpaid {
+			return Ok(());
+		}
+
+		let (total_rewards, deferred_rewards) =
+			PositionRewardAssets::<T>::mutate(position_id, reward_asset.asset_id, |p| {
+				(
+					sp_std::mem::take(&mut p.accumulated_unpaid_rewards),
+					sp_std::mem::take(&mut p.deferred_rewards),
+				)
+			});
+
+		if total_rewards.is_zero() && deferred_rewards.is_zero() {
+			return Ok(());
+		}
+
+		let payable_rewards = math::calculate_percentage_amount(total_rewards, payable_percentage);
+		let slashed_rewards = total_rewards.saturating_sub(payable_rewards);
+		let paid_rewards = payable_rewards.saturating_add(deferred_rewards);
+
+		reward_asset.pot_reserved_balance = reward_asset.pot_reserved_balance.saturating_sub(slashed_rewards);
+
+		let (paid_asset_id, paid_amount) = if paid_rewards.is_zero() {
+			(reward_asset.asset_id, Zero::zero())
+		} else {
+			match Self::pay_reward_asset(who, reward_asset, paid_rewards) {
+				Ok(paid) => paid,
+				Err(error) => {
+					PositionRewardAssets::<T>::mutate(position_id, reward_asset.asset_id, |p| {
+						p.deferred_rewards = paid_rewards
+					});
+
+					Self::deposit_event(Event::RewardAssetPaymentDeferred {
+						who: who.clone(),
+						position_id,
+						asset_id: reward_asset.asset_id,
+						deferred_rewards: paid_rewards,
+						slashed_rewards,
+						error,
+					});
+
+					return Ok(());
+				}
+			}
+		};
+
+		reward_asset.pot_reserved_balance = reward_asset.pot_reserved_balance.saturating_sub(paid_rewards);
+
+		Self::deposit_event(Event::RewardAssetClaimed {
+			who: who.clone(),
+			position_id,
+			asset_id: reward_asset.asset_id,
+			paid_rewards,
+			slashed_rewards,
+			paid_asset_id,
+			paid_amount,
+		});
+
+		Ok(())
+	}
+
+	/// Transfers `amount` of the reward asset from the `pot` to `who`, converted to the native asset
+	/// if configured.
+	///
+	/// Returns asset and amount paid to `who`. Nothing is changed if the payment fails.
+	fn pay_reward_asset(
+		who: &T::AccountId,
+		reward_asset: &RewardAsset<T::AssetId>,
+		amount: Balance,
+	) -> Result<(T::AssetId, Balance), DispatchError> {
+		let pot = Self::pot_account_id();
+
+		with_transaction(|| {
+			if reward_asset.convert_to_native {
+				if let Ok(amount_out) = Self::convert_to_native(reward_asset.asset_id, amount) {
+					return match T::Currency::transfer(T::NativeAssetId::get(), &pot, who, amount_out) {
+						Ok(()) => TransactionOutcome::Commit(Ok((T::NativeAssetId::get(), amount_out))),
+						Err(e) => TransactionOutcome::Rollback(Err(e)),
+					};
+				}
+			}
+
+			match T::Currency::transfer(reward_asset.asset_id, &pot, who, amount) {
+				Ok(()) => TransactionOutcome::Commit(Ok((reward_asset.asset_id, amount))),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			}
+		})
+	}
+
+	/// Sells `amount` of `asset_id` from the `pot` for the native asset.
+	///
+	/// Returns amount of the native asset received. Nothing is changed if the trade fails.
+	fn convert_to_native(asset_id: T::AssetId, amount: Balance) -> Result<Balance, DispatchError> {
+		let route = T::RouteProvider::get_route(AssetPair::new(asset_id, T::NativeAssetId::get()));
+		let amount_out = T::RouteExecutor::calculate_sell_trade_amounts(&route, amount)?
+			.last()
+			.map(|trade_amounts| trade_amounts.amount_out)
+			.ok_or(Error::<T>::RewardConversionFailed)?;
+
+		with_transaction(|| {
+			let result = T::RouteExecutor::sell(
+				RawOrigin::Signed(Self::pot_account_id()).into(),
+				asset_id,
+				T::NativeAssetId::get(),
+				amount,
+				amount_out,
+				route,
+			);
+
+			match result {
+				Ok(()) => TransactionOutcome::Commit(Ok(amount_out)),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			}
+		})
+	}
+
+	/// Weight of reward assets processing. Conversion weight is included if `with_conversion` is
+	/// `true` and reward asset is converted to the native asset.
+	fn reward_assets_weight(with_conversion: bool) -> Weight {
+		RewardAssets::<T>::get()
+			.iter()
+			.fold(Weight::zero(), |weight, reward_asset| {
+				let weight = weight.saturating_add(T::WeightInfo::process_reward_asset());
+				if with_conversion && reward_asset.convert_to_native {
+					weight.saturating_add(Self::conversion_weight(reward_asset.asset_id))
+				} else {
+					weight
+				}
+			})
+	}
+
+	/// Weight of the conversion of the reward asset to the native asset if it is configured.
+	fn reward_asset_conversion_weight(asset_id: T::AssetId) -> Weight {
+		if RewardAssets::<T>::get()
+			.iter()
+			.any(|r| r.asset_id == asset_id && r.convert_to_native)
+		{
+			Self::conversion_weight(asset_id)
+		} else {
+			Weight::zero()
+		}
+	}
+
+	fn conversion_weight(asset_id: T::AssetId) -> Weight {
+		let route = T::RouteProvider::get_route(AssetPair::new(asset_id, T::NativeAssetId::get()));
+		T::AmmTradeWeights::sell_and_calculate_sell_trade_amounts_weight(&route)
+			.saturating_add(T::AmmTradeWeights::get_route_weight())
+	}
 }
 
 impl<T: Config> Pallet<T> {
 	pub fn get_position(position_id: T::PositionItemId) -> Option<Position<BlockNumberFor<T>>> {
 		Positions::<T>::get(position_id)
 	}
+
+	/// Returns rewards the position would receive if it was claimed now.
+	///
+	/// Pending rewards in the `pot` and votes in finished referendums are taken into account
+	/// the same way as in `claim`.
+	pub fn position_rewards(position_id: T::PositionItemId) -> Option<PositionRewards> {
+		if !Self::is_initialized() {
+			return None;
+		}
+
+		let position = Self::position_with_unprocessed_votes(position_id)?;
+		let accumulated_reward_per_stake = Self::accumulated_rps_with_pending_rewards(&Self::staking())?;
+
+		let current_period = Self::get_current_period()?;
+		let created_at = Self::get_period_number(position.created_at)?;
+
+		let (claimable_rewards, accumulated_unpaid_rewards, payable_percentage) =
+			Self::calculate_rewards(&position, accumulated_reward_per_stake, current_period, created_at)?;
+
+		let slashed_unpaid_rewards = if current_period.saturating_sub(created_at) > T::UnclaimablePeriods::get() {
+			accumulated_unpaid_rewards
+		} else {
+			Zero::zero()
+		};
+
+		Some(PositionRewards {
+			claimable_rewards,
+			unlocked_rewards: position.accumulated_locked_rewards,
+			slashed_unpaid_rewards,
+			points: Self::get_points(&position, current_period, created_at)?,
+			payable_percentage,
+		})
+	}
+
+	/// Returns payable percentage of the position at given blocks.
+	///
+	/// Projection is calculated without and with `additional_votes` rewarded to the position.
+	/// Payable percentage is zero for blocks in the unclaimable periods.
+	pub fn payable_percentage_projection(
+		position_id: T::PositionItemId,
+		blocks: Vec<BlockNumberFor<T>>,
+		additional_votes: Vec<Vote>,
+	) -> Option<Vec<PayablePercentageProjection<BlockNumberFor<T>>>> {
+		let position = Self::position_with_unprocessed_votes(position_id)?;
+		let created_at = Self::get_period_number(position.created_at)?;
+
+		let mut position_with_votes = position.clone();
+		for vote in additional_votes.iter() {
+			Self::accrue_action_points(&mut position_with_votes, Action::DemocracyVote, vote);
+		}
+
+		blocks
+			.into_iter()
+			.map(|block_number| {
+				let period = Self::get_period_number(block_number)?;
+				if period.saturating_sub(created_at) <= T::UnclaimablePeriods::get() {
+					return Some(PayablePercentageProjection {
+						block_number,
+						payable_percentage: FixedU128::zero(),
+						payable_percentage_with_votes: FixedU128::zero(),
+					});
+				}
+
+				let points = Self::get_points(&position, period, created_at)?;
+				let points_with_votes = Self::get_points(&position_with_votes, period, created_at)?;
+
+				Some(PayablePercentageProjection {
+					block_number,
+					payable_percentage: T::PayablePercentage::get(points)?,
+					payable_percentage_with_votes: T::PayablePercentage::get(points_with_votes)?,
+				})
+			})
+			.collect()
+	}
+
+	/// Returns position with action points for votes in finished referendums which were not
+	/// processed yet.
+	fn position_with_unprocessed_votes(position_id: T::PositionItemId) -> Option<Position<BlockNumberFor<T>>> {
+		let mut position = Positions::<T>::get(position_id)?;
+
+		for (ref_idx, vote) in Votes::<T>::get(position_id).votes.iter() {
+			if T::ReferendumInfo::is_referendum_finished(*ref_idx) {
+				Self::accrue_action_points(&mut position, Action::DemocracyVote, vote);
+			}
+		}
+
+		Some(position)
+	}
+
+	/// Returns `accumulated_reward_per_stake` including rewards which were not distributed yet.
+	fn accumulated_rps_with_pending_rewards(staking: &StakingData) -> Option<FixedU128> {
+		if staking.total_stake.is_zero() {
+			return Some(staking.accumulated_reward_per_stake);
+		}
+
+		let pending_rewards = T::Currency::free_balance(T::NativeAssetId::get(), &Self::pot_account_id())
+			.checked_sub(staking.pot_reserved_balance)?;
+
+		if pending_rewards.is_zero() {
+			return Some(staking.accumulated_reward_per_stake);
+		}
+
+		math::calculate_accumulated_rps(
+			staking.accumulated_reward_per_stake,
+			pending_rewards,
+			staking.total_stake,
+		)
+	}
+}
+
+impl<T: Config> ActionHook<T::AccountId> for Pallet<T> {
+	fn on_action(who: &T::AccountId, action: Action, amount: Balance, multiplier: FixedU128) -> Point {
+		let Some(position_id) = Self::get_user_position_id(who).ok().flatten() else {
+			return Zero::zero();
+		};
+
+		Positions::<T>::mutate(position_id, |maybe_position| {
+			let Some(position) = maybe_position.as_mut() else {
+				return Zero::zero();
+			};
+
+			// Users can't use more staking power than they have staked.
+			let amount = amount.min(position.stake);
+			Self::add_action_points(who, position_id, position, action, (amount, multiplier))
+		})
+	}
 }
 
 pub struct SigmoidPercentage<T, B>(sp_std::marker::PhantomData<(T, B)>);
@@ -0,0 +1,188 @@
+use super::*;
+
+use crate::integrations::conviction_voting::StakingConvictionVoting;
+use crate::traits::ActionHook;
+use crate::types::{Action, Conviction, Vote};
+use mock::Staking;
+use pallet_conviction_voting::traits::VotingHooks;
+use pretty_assertions::assert_eq;
+//NOTE: Referendums with even indexes are finished.
+
+fn default_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(DAVE, 10 * ONE, 1_465_000, 1),
+		])
+}
+
+#[test]
+fn on_action_should_add_action_points_to_position() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+		let position_before = Staking::positions(bob_position_id).unwrap();
+
+		//Act
+		let points = Staking::on_action(
+			&BOB,
+			Action::OmnipoolLiquidity,
+			60_000 * ONE,
+			Conviction::max_multiplier(),
+		);
+
+		//Assert
+		assert_eq!(points, 25);
+		assert_eq!(
+			Staking::positions(bob_position_id).unwrap(),
+			Position {
+				action_points: 25,
+				..position_before
+			}
+		);
+		assert_last_event!(Event::<Test>::ActionPointsAdded {
+			who: BOB,
+			position_id: bob_position_id,
+			action: Action::OmnipoolLiquidity,
+			points: 25,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn on_action_should_cap_amount_by_staked_amount() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+		//Act
+		let points = Staking::on_action(
+			&BOB,
+			Action::OmnipoolLiquidity,
+			1_000_000 * ONE,
+			Conviction::max_multiplier(),
+		);
+
+		//Assert
+		assert_eq!(points, 50);
+		assert_eq!(Staking::positions(bob_position_id).unwrap().get_action_points(), 50);
+	});
+}
+
+#[test]
+fn on_action_should_not_add_points_when_action_has_zero_weight() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+		let position_before = Staking::positions(bob_position_id).unwrap();
+		let events_before = System::events().len();
+
+		//Act
+		let points = Staking::on_action(&BOB, Action::DcaSchedule, 60_000 * ONE, Conviction::max_multiplier());
+
+		//Assert
+		assert_eq!(points, 0);
+		assert_eq!(Staking::positions(bob_position_id).unwrap(), position_before);
+		assert_eq!(System::events().len(), events_before);
+	});
+}
+
+#[test]
+fn on_action_should_not_add_points_when_account_has_no_position() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		let events_before = System::events().len();
+
+		//Act
+		let points = Staking::on_action(
+			&VESTED_100K,
+			Action::OmnipoolLiquidity,
+			60_000 * ONE,
+			Conviction::max_multiplier(),
+		);
+
+		//Assert
+		assert_eq!(points, 0);
+		assert_eq!(System::events().len(), events_before);
+	});
+}
+
+#[test]
+fn on_remove_vote_should_add_points_via_action_hook_when_referendum_is_finished() {
+	default_ext()
+		.with_votings(vec![(
+			1,
+			vec![(
+				2_u32,
+				Vote {
+					amount: 100_000 * ONE,
+					conviction: Conviction::None,
+				},
+			)],
+		)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let position_id = 1;
+			let position_before = Staking::positions(position_id).unwrap();
+
+			//Act
+			StakingConvictionVoting::<Test>::on_remove_vote(&BOB, 2, Some(false));
+
+			//Assert
+			assert_eq!(
+				Staking::positions(position_id).unwrap(),
+				Position {
+					action_points: 1_u128,
+					..position_before
+				}
+			);
+			assert!(Votes::<Test>::get(position_id).votes.is_empty());
+			assert_last_event!(Event::<Test>::ActionPointsAdded {
+				who: BOB,
+				position_id,
+				action: Action::DemocracyVote,
+				points: 1,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn on_remove_vote_should_not_add_points_when_referendum_is_ongoing() {
+	default_ext()
+		.with_votings(vec![(
+			1,
+			vec![(
+				2_u32,
+				Vote {
+					amount: 100_000 * ONE,
+					conviction: Conviction::None,
+				},
+			)],
+		)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let position_id = 1;
+			let position_before = Staking::positions(position_id).unwrap();
+
+			//Act
+			StakingConvictionVoting::<Test>::on_remove_vote(&BOB, 2, Some(true));
+
+			//Assert
+			assert_eq!(Staking::positions(position_id).unwrap(), position_before);
+			assert!(Votes::<Test>::get(position_id).votes.is_empty());
+		});
+}
@@ -25,7 +25,8 @@ use frame_support::{
 	weights::RuntimeDbWeight,
 };
 use frame_system::{EnsureRoot, RawOrigin};
-use orml_traits::{parameter_type_with_key, LockIdentifier, MultiCurrencyExtended};
+use hydradx_traits::router::{AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade, MAX_NUMBER_OF_TRADES};
+use orml_traits::{parameter_type_with_key, LockIdentifier, MultiCurrency, MultiCurrencyExtended};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
@@ -41,6 +42,9 @@ type AssetId = u32;
 type BlockNumber = u64;
 
 pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+/// Reward asset without route to the native asset.
+pub const BSX: AssetId = 3;
 
 pub const ALICE: AccountId = 1_000;
 pub const BOB: AccountId = 1_001;
@@ -54,6 +58,8 @@ pub const STAKING_LOCK: LockIdentifier = crate::STAKING_LOCK_ID;
 
 pub const NON_DUSTABLE_BALANCE: Balance = 1_000 * ONE;
 
+pub const DAI_EXISTENTIAL_DEPOSIT: Balance = ONE;
+
 pub type PositionId = u128;
 
 construct_runtime!(
@@ -170,8 +176,12 @@ impl pallet_uniques::Config for Test {
 }
 
 parameter_type_with_key! {
-	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
-		0
+	pub ExistentialDeposits: |currency_id: AssetId| -> Balance {
+		if *currency_id == DAI {
+			DAI_EXISTENTIAL_DEPOSIT
+		} else {
+			0
+		}
 	};
 }
 
@@ -200,6 +210,7 @@ parameter_types! {
 	pub const UnclaimablePeriods: BlockNumber = 10;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(15,100);
 	pub const MaxVotes: u32 = 10;
+	pub const MaxRewardAssets: u32 = 2;
 }
 
 impl pallet_staking::Config for Test {
@@ -230,9 +241,111 @@ impl pallet_staking::Config for Test {
 	type Collections = FreezableUniques;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type MinSlash = DummyMinSlash;
+	type MaxRewardAssets = MaxRewardAssets;
+	type RouteExecutor = DummyRouter;
+	type RouteProvider = DummyRouter;
+	type AmmTradeWeights = ();
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type MaxLocks = MaxLocks;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyBenchmarkHelper;
+}
+
+/// Price of the native asset in other assets used by `DummyRouter`.
+pub const DUMMY_ROUTER_PRICE: Balance = 2;
+
+/// Router selling any asset except `BSX` for `DUMMY_ROUTER_PRICE` times more of the asset out.
+pub struct DummyRouter;
+
+impl RouteProvider<AssetId> for DummyRouter {}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for DummyRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	) -> DispatchResult {
+		let who = frame_system::ensure_signed(origin)?;
+		let amount_out = amount_in * DUMMY_ROUTER_PRICE;
+		ensure!(asset_in != BSX, DispatchError::Other("route not found"));
+		ensure!(amount_out >= min_amount_out, DispatchError::Other("slippage"));
+
+		Tokens::withdraw(asset_in, &who, amount_in)?;
+		Tokens::deposit(asset_out, &who, amount_out)
+	}
+
+	fn sell_all(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		route: &[Trade<AssetId>],
+		amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		ensure!(
+			route.iter().all(|trade| trade.asset_in != BSX),
+			DispatchError::Other("route not found")
+		);
+
+		Ok(vec![AmountInAndOut {
+			amount_in,
+			amount_out: amount_in * DUMMY_ROUTER_PRICE,
+		}])
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DummyBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::traits::BenchmarkHelper<AssetId> for DummyBenchmarkHelper {
+	fn register_reward_asset() -> Result<AssetId, DispatchError> {
+		Ok(DAI)
+	}
 }
 
 pub struct DummyMinSlash;
@@ -251,6 +364,8 @@ impl GetByKey<Action, u32> for DummyMaxPointsPerAction {
 	fn get(k: &Action) -> u32 {
 		match k {
 			Action::DemocracyVote => 100_u32,
+			Action::OmnipoolLiquidity => 50_u32,
+			Action::DcaSchedule => 0_u32,
 		}
 	}
 }
@@ -5,12 +5,16 @@ use crate::*;
 use frame_support::{assert_noop, assert_ok};
 use orml_tokens::BalanceLock;
 
+mod action_hook;
 mod claim;
 mod increase_stake;
 pub(crate) mod mock;
+mod position_rewards;
+mod reward_assets;
 mod stake;
 #[allow(clippy::module_inception)]
 mod tests;
+mod transfer_position;
 mod unstake;
 
 /// Assert amount of locked tokens. `amount == 0` asserts no lock.
@@ -0,0 +1,130 @@
+use super::*;
+
+use crate::types::{Conviction, PositionRewards, Vote};
+use mock::Staking;
+use pretty_assertions::assert_eq;
+//NOTE: Referendums with even indexes are finished.
+
+fn default_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(DAVE, 10 * ONE, 1_465_000, 1),
+		])
+}
+
+#[test]
+fn position_rewards_should_match_claimed_rewards() {
+	default_ext()
+		.with_votings(vec![(
+			1,
+			vec![(
+				2_u32,
+				Vote {
+					amount: 100_000 * ONE,
+					conviction: Conviction::Locked2x,
+				},
+			)],
+		)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			let rewards = Staking::position_rewards(bob_position_id).unwrap();
+
+			//Assert
+			assert!(!rewards.claimable_rewards.is_zero());
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+			assert_last_event!(Event::<Test>::RewardsClaimed {
+				who: BOB,
+				position_id: bob_position_id,
+				paid_rewards: rewards.claimable_rewards,
+				unlocked_rewards: rewards.unlocked_rewards,
+				slashed_points: rewards.points,
+				slashed_unpaid_rewards: rewards.slashed_unpaid_rewards,
+				payable_percentage: rewards.payable_percentage,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn position_rewards_should_return_zero_percentage_when_position_is_in_unclaimable_periods() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		set_pending_rewards(10_000 * ONE);
+		set_block_number(1_470_000);
+		let dave_position_id = Staking::get_user_position_id(&DAVE).unwrap().unwrap();
+
+		//Act
+		let rewards = Staking::position_rewards(dave_position_id).unwrap();
+
+		//Assert
+		assert_eq!(rewards.claimable_rewards, 0);
+		assert_eq!(rewards.slashed_unpaid_rewards, 0);
+		assert_eq!(rewards.payable_percentage, FixedU128::zero());
+	});
+}
+
+#[test]
+fn position_rewards_should_return_none_when_position_does_not_exist() {
+	default_ext().build().execute_with(|| {
+		assert_eq!(Staking::position_rewards(1_000), None::<PositionRewards>);
+	});
+}
+
+#[test]
+fn payable_percentage_projection_should_grow_in_time_and_with_votes() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		set_block_number(1_700_000);
+		let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+		let blocks = vec![1_452_990, 1_700_000, 1_800_000, 2_000_000];
+		let votes = vec![Vote::new(120_000 * ONE, Conviction::Locked6x); 3];
+
+		//Act
+		let projection = Staking::payable_percentage_projection(bob_position_id, blocks.clone(), votes).unwrap();
+
+		//Assert
+		assert_eq!(projection.iter().map(|p| p.block_number).collect::<Vec<_>>(), blocks);
+
+		// unclaimable periods
+		assert_eq!(projection[0].payable_percentage, FixedU128::zero());
+		assert_eq!(projection[0].payable_percentage_with_votes, FixedU128::zero());
+
+		assert_eq!(
+			projection[1].payable_percentage,
+			Staking::position_rewards(bob_position_id).unwrap().payable_percentage
+		);
+		for window in projection[1..].windows(2) {
+			assert!(window[0].payable_percentage < window[1].payable_percentage);
+		}
+		for p in projection[1..].iter() {
+			assert!(p.payable_percentage < p.payable_percentage_with_votes);
+		}
+	});
+}
+
+#[test]
+fn payable_percentage_projection_should_return_none_when_position_does_not_exist() {
+	default_ext().build().execute_with(|| {
+		assert_eq!(
+			Staking::payable_percentage_projection(1_000, vec![1_700_000], vec![]),
+			None
+		);
+	});
+}
@@ -0,0 +1,472 @@
+use super::*;
+
+use crate::types::{PositionRewardAsset, RewardAsset};
+use mock::Staking;
+use orml_traits::MultiCurrencyExtended;
+use pretty_assertions::assert_eq;
+use sp_runtime::{DispatchError::BadOrigin, FixedU128};
+
+fn default_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+}
+
+fn add_pot_balance(asset_id: u32, amount: Balance) {
+	assert_ok!(Tokens::update_balance(
+		asset_id,
+		&Staking::pot_account_id(),
+		amount as i128
+	));
+}
+
+#[test]
+fn set_reward_asset_should_work_when_origin_is_authority() {
+	default_ext().build().execute_with(|| {
+		//Act
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+
+		//Assert
+		assert_eq!(Staking::reward_assets().to_vec(), vec![RewardAsset::new(DAI, false)]);
+		assert_last_event!(Event::<Test>::RewardAssetSet {
+			asset_id: DAI,
+			convert_to_native: false,
+		}
+		.into());
+
+		//Act
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, true));
+
+		//Assert
+		assert_eq!(Staking::reward_assets().to_vec(), vec![RewardAsset::new(DAI, true)]);
+	});
+}
+
+#[test]
+fn set_reward_asset_should_not_work_when_origin_is_not_authority() {
+	default_ext().build().execute_with(|| {
+		assert_noop!(
+			Staking::set_reward_asset(RuntimeOrigin::signed(ALICE), DAI, false),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_reward_asset_should_not_work_when_asset_is_native() {
+	default_ext().build().execute_with(|| {
+		assert_noop!(
+			Staking::set_reward_asset(RuntimeOrigin::root(), HDX, false),
+			Error::<Test>::InvalidRewardAsset
+		);
+	});
+}
+
+#[test]
+fn set_reward_asset_should_not_work_when_max_reward_assets_is_reached() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), BSX, false));
+
+		//Act & assert
+		assert_noop!(
+			Staking::set_reward_asset(RuntimeOrigin::root(), 4, false),
+			Error::<Test>::MaxRewardAssetsReached
+		);
+	});
+}
+
+#[test]
+fn process_trade_fee_should_transfer_reward_asset_to_pot() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		assert_ok!(Tokens::update_balance(DAI, &DAVE, (1_000 * ONE) as i128));
+
+		//Act
+		let result = Staking::process_trade_fee(DAVE, DAI, 1_000 * ONE).unwrap();
+
+		//Assert
+		assert_eq!(result, Some((1_000 * ONE, Staking::pot_account_id())));
+		assert_eq!(Tokens::free_balance(DAI, &Staking::pot_account_id()), 1_000 * ONE);
+		assert_eq!(Staking::process_trade_fee(DAVE, BSX, 1_000 * ONE).unwrap(), None);
+	});
+}
+
+#[test]
+fn claim_should_pay_reward_asset_rewards_with_payable_percentage() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 22_000 * ONE);
+		set_block_number(1_700_000);
+		let bob_position_id = 1;
+		let payable_percentage = Staking::position_rewards(bob_position_id).unwrap().payable_percentage;
+		let expected_paid = payable_percentage.saturating_mul_int(12_000 * ONE);
+
+		//Act
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), expected_paid);
+		assert_eq!(
+			Staking::position_reward_asset(bob_position_id, DAI),
+			PositionRewardAsset {
+				reward_per_stake: FixedU128::from_rational(1, 10),
+				accumulated_unpaid_rewards: 0,
+				deferred_rewards: 0,
+			}
+		);
+		assert_eq!(
+			Staking::reward_assets().to_vec(),
+			vec![RewardAsset {
+				asset_id: DAI,
+				accumulated_reward_per_stake: FixedU128::from_rational(1, 10),
+				pot_reserved_balance: 10_000 * ONE,
+				convert_to_native: false,
+			}]
+		);
+		assert!(has_event(
+			Event::<Test>::RewardAssetClaimed {
+				who: BOB,
+				position_id: bob_position_id,
+				asset_id: DAI,
+				paid_rewards: expected_paid,
+				slashed_rewards: 12_000 * ONE - expected_paid,
+				paid_asset_id: DAI,
+				paid_amount: expected_paid,
+			}
+			.into()
+		));
+	});
+}
+
+#[test]
+fn claim_should_redistribute_slashed_reward_asset_rewards() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 22_000 * ONE);
+		set_block_number(1_700_000);
+		let bob_position_id = 1;
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+		let slashed_rewards = 12_000 * ONE - Tokens::free_balance(DAI, &BOB);
+		let total_stake = Staking::staking().total_stake;
+
+		//Act
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(ALICE), 0));
+
+		//Assert
+		assert_eq!(
+			Staking::reward_assets()[0].accumulated_reward_per_stake,
+			FixedU128::from_rational(1, 10) + FixedU128::from_rational(slashed_rewards, total_stake)
+		);
+	});
+}
+
+#[test]
+fn claim_should_convert_reward_asset_to_native_when_conversion_is_enabled() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, true));
+		add_pot_balance(DAI, 22_000 * ONE);
+		set_block_number(1_700_000);
+		let bob_position_id = 1;
+		let position_rewards = Staking::position_rewards(bob_position_id).unwrap();
+		let expected_paid = position_rewards.payable_percentage.saturating_mul_int(12_000 * ONE);
+		let bob_hdx_balance = Tokens::free_balance(HDX, &BOB);
+
+		//Act
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 0);
+		assert_eq!(
+			Tokens::free_balance(HDX, &BOB),
+			bob_hdx_balance + position_rewards.claimable_rewards + expected_paid * DUMMY_ROUTER_PRICE
+		);
+		assert!(has_event(
+			Event::<Test>::RewardAssetClaimed {
+				who: BOB,
+				position_id: bob_position_id,
+				asset_id: DAI,
+				paid_rewards: expected_paid,
+				slashed_rewards: 12_000 * ONE - expected_paid,
+				paid_asset_id: HDX,
+				paid_amount: expected_paid * DUMMY_ROUTER_PRICE,
+			}
+			.into()
+		));
+	});
+}
+
+#[test]
+fn claim_should_pay_reward_asset_when_conversion_fails() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), BSX, true));
+		add_pot_balance(BSX, 22_000 * ONE);
+		set_block_number(1_700_000);
+		let bob_position_id = 1;
+		let payable_percentage = Staking::position_rewards(bob_position_id).unwrap().payable_percentage;
+		let expected_paid = payable_percentage.saturating_mul_int(12_000 * ONE);
+
+		//Act
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(BSX, &BOB), expected_paid);
+	});
+}
+
+#[test]
+fn claim_should_keep_reward_asset_rewards_when_position_is_in_unclaimable_periods() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 22_000 * ONE);
+		set_block_number(1_470_000);
+		let bob_position_id = 1;
+
+		//Act
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 0);
+		assert_eq!(
+			Staking::position_reward_asset(bob_position_id, DAI),
+			PositionRewardAsset {
+				reward_per_stake: FixedU128::from_rational(1, 10),
+				accumulated_unpaid_rewards: 12_000 * ONE,
+				deferred_rewards: 0,
+			}
+		);
+		assert_eq!(Staking::reward_assets()[0].pot_reserved_balance, 22_000 * ONE);
+	});
+}
+
+#[test]
+fn stake_should_not_receive_reward_asset_rewards_distributed_before_position_was_created() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 22_000 * ONE);
+
+		//Act
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(DAVE), 50_000 * ONE));
+
+		//Assert
+		let dave_position_id = Staking::get_user_position_id(&DAVE).unwrap().unwrap();
+		assert_eq!(
+			Staking::position_reward_asset(dave_position_id, DAI),
+			PositionRewardAsset {
+				reward_per_stake: FixedU128::from_rational(1, 10),
+				accumulated_unpaid_rewards: 0,
+				deferred_rewards: 0,
+			}
+		);
+	});
+}
+
+#[test]
+fn increase_stake_should_accumulate_reward_asset_rewards() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 22_000 * ONE);
+		let bob_position_id = 1;
+
+		//Act
+		assert_ok!(Staking::increase_stake(
+			RuntimeOrigin::signed(BOB),
+			bob_position_id,
+			10_000 * ONE
+		));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 0);
+		assert_eq!(
+			Staking::position_reward_asset(bob_position_id, DAI),
+			PositionRewardAsset {
+				reward_per_stake: FixedU128::from_rational(1, 10),
+				accumulated_unpaid_rewards: 12_000 * ONE,
+				deferred_rewards: 0,
+			}
+		);
+	});
+}
+
+#[test]
+fn unstake_should_pay_reward_asset_rewards_and_remove_position_state() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 22_000 * ONE);
+		set_block_number(1_700_000);
+		let bob_position_id = 1;
+		let payable_percentage = Staking::position_rewards(bob_position_id).unwrap().payable_percentage;
+		let expected_paid = payable_percentage.saturating_mul_int(12_000 * ONE);
+
+		//Act
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), bob_position_id));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), expected_paid);
+		assert!(!PositionRewardAssets::<Test>::contains_key(bob_position_id, DAI));
+		assert_eq!(Staking::reward_assets()[0].pot_reserved_balance, 10_000 * ONE);
+	});
+}
+
+#[test]
+fn claim_should_defer_reward_asset_payment_when_rewards_are_below_existential_deposit() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 11 * ONE / 10);
+		set_block_number(1_700_000);
+		let bob_position_id = 1;
+		let payable_percentage = Staking::position_rewards(bob_position_id).unwrap().payable_percentage;
+		let expected_paid = payable_percentage.saturating_mul_int(6 * ONE / 10);
+
+		//Act
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 0);
+		assert_eq!(
+			Staking::position_reward_asset(bob_position_id, DAI).deferred_rewards,
+			expected_paid
+		);
+		assert_eq!(
+			Staking::reward_assets()[0].pot_reserved_balance,
+			5 * ONE / 10 + expected_paid
+		);
+		assert_last_event!(Event::<Test>::RewardAssetPaymentDeferred {
+			who: BOB,
+			position_id: bob_position_id,
+			asset_id: DAI,
+			deferred_rewards: expected_paid,
+			slashed_rewards: 6 * ONE / 10 - expected_paid,
+			error: orml_tokens::Error::<Test>::ExistentialDeposit.into(),
+		}
+		.into());
+	});
+}
+
+#[test]
+fn claim_should_pay_deferred_reward_asset_rewards_in_full() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 11 * ONE / 10);
+		set_block_number(1_700_000);
+		let bob_position_id = 1;
+		let payable_percentage = Staking::position_rewards(bob_position_id).unwrap().payable_percentage;
+		let expected_paid = payable_percentage.saturating_mul_int(6 * ONE / 10);
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+		assert_ok!(Tokens::update_balance(DAI, &BOB, DAI_EXISTENTIAL_DEPOSIT as i128));
+		// all points were slashed by the claim so no new rewards are paid
+		assert_eq!(
+			Staking::position_rewards(bob_position_id).unwrap().payable_percentage,
+			FixedU128::from_inner(0)
+		);
+
+		//Act
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), DAI_EXISTENTIAL_DEPOSIT + expected_paid);
+		assert_eq!(Staking::position_reward_asset(bob_position_id, DAI).deferred_rewards, 0);
+	});
+}
+
+#[test]
+fn unstake_should_not_fail_when_reward_asset_rewards_are_below_existential_deposit() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 11 * ONE / 10);
+		set_block_number(1_700_000);
+		let bob_position_id = 1;
+		let payable_percentage = Staking::position_rewards(bob_position_id).unwrap().payable_percentage;
+		let expected_paid = payable_percentage.saturating_mul_int(6 * ONE / 10);
+
+		//Act
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), bob_position_id));
+
+		//Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 0);
+		assert!(!PositionRewardAssets::<Test>::contains_key(bob_position_id, DAI));
+		assert_eq!(Staking::deferred_reward_asset(BOB, DAI), expected_paid);
+		assert_eq!(
+			Staking::reward_assets()[0].pot_reserved_balance,
+			5 * ONE / 10 + expected_paid
+		);
+		assert!(has_event(
+			Event::<Test>::RewardAssetPaymentDeferred {
+				who: BOB,
+				position_id: bob_position_id,
+				asset_id: DAI,
+				deferred_rewards: expected_paid,
+				slashed_rewards: 6 * ONE / 10 - expected_paid,
+				error: orml_tokens::Error::<Test>::ExistentialDeposit.into(),
+			}
+			.into()
+		));
+	});
+}
+
+#[test]
+fn claim_deferred_reward_asset_should_pay_rewards_deferred_on_unstake() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+		add_pot_balance(DAI, 11 * ONE / 10);
+		set_block_number(1_700_000);
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), 1));
+		let deferred_rewards = Staking::deferred_reward_asset(BOB, DAI);
+		assert_ok!(Tokens::update_balance(DAI, &BOB, DAI_EXISTENTIAL_DEPOSIT as i128));
+
+		//Act
+		assert_ok!(Staking::claim_deferred_reward_asset(RuntimeOrigin::signed(BOB), DAI));
+
+		//Assert
+		assert_eq!(
+			Tokens::free_balance(DAI, &BOB),
+			DAI_EXISTENTIAL_DEPOSIT + deferred_rewards
+		);
+		assert!(!DeferredRewardAssets::<Test>::contains_key(BOB, DAI));
+		assert_eq!(Staking::reward_assets()[0].pot_reserved_balance, 5 * ONE / 10);
+		assert_last_event!(Event::<Test>::DeferredRewardAssetClaimed {
+			who: BOB,
+			asset_id: DAI,
+			paid_rewards: deferred_rewards,
+			paid_asset_id: DAI,
+			paid_amount: deferred_rewards,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn claim_deferred_reward_asset_should_not_work_when_there_are_no_deferred_rewards() {
+	default_ext().build().execute_with(|| {
+		assert_ok!(Staking::set_reward_asset(RuntimeOrigin::root(), DAI, false));
+
+		assert_noop!(
+			Staking::claim_deferred_reward_asset(RuntimeOrigin::signed(BOB), DAI),
+			Error::<Test>::NoDeferredRewards
+		);
+	});
+}
@@ -1,6 +1,6 @@
 use crate::{
 	integrations::conviction_voting::StakingConvictionVoting,
-	types::{Conviction, Vote},
+	types::{Action, Conviction, Vote},
 };
 
 use super::*;
@@ -59,6 +59,13 @@ fn process_votes_should_work_when_referendum_is_finished() {
 			);
 
 			assert_eq!(Votes::<Test>::get(position_id).votes.len(), 0);
+			assert_last_event!(Event::<Test>::ActionPointsAdded {
+				who: BOB,
+				position_id,
+				action: Action::DemocracyVote,
+				points: 1_u128,
+			}
+			.into());
 		});
 }
 
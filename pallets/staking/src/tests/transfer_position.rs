@@ -0,0 +1,289 @@
+use super::*;
+
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn default_ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+			(DAVE, HDX, 100_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+			(BOB, 30_000 * ONE, 1_465_000, 50_000 * ONE),
+		])
+}
+
+#[test]
+fn set_position_transfers_should_work_when_origin_is_authority() {
+	default_ext().build().execute_with(|| {
+		//Act
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+
+		//Assert
+		assert!(Staking::position_transfers_enabled());
+		assert_last_event!(Event::<Test>::PositionTransfersSet { enabled: true }.into());
+
+		//Act
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), false));
+
+		//Assert
+		assert!(!Staking::position_transfers_enabled());
+	});
+}
+
+#[test]
+fn set_position_transfers_should_not_work_when_origin_is_not_authority() {
+	default_ext().build().execute_with(|| {
+		assert_noop!(
+			Staking::set_position_transfers(RuntimeOrigin::signed(ALICE), true),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_transfers_are_disabled() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		let bob_position_id = 1;
+
+		//Act & assert
+		assert_noop!(
+			Staking::transfer_position(RuntimeOrigin::signed(BOB), bob_position_id, DAVE),
+			Error::<Test>::PositionTransfersDisabled
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_origin_is_not_owner() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = 1;
+
+		//Act & assert
+		assert_noop!(
+			Staking::transfer_position(RuntimeOrigin::signed(ALICE), bob_position_id, DAVE),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_destination_has_position() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = 1;
+
+		//Act & assert
+		assert_noop!(
+			Staking::transfer_position(RuntimeOrigin::signed(BOB), bob_position_id, ALICE),
+			Error::<Test>::PositionAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn transfer_position_should_not_work_when_position_has_votes() {
+	default_ext()
+		.with_votings(vec![(
+			1,
+			vec![(
+				3_u32,
+				Vote {
+					amount: 10_000 * ONE,
+					conviction: Conviction::Locked1x,
+				},
+			)],
+		)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+			let bob_position_id = 1;
+
+			//Act & assert
+			assert_noop!(
+				Staking::transfer_position(RuntimeOrigin::signed(BOB), bob_position_id, DAVE),
+				Error::<Test>::ExistingVotes
+			);
+		});
+}
+
+#[test]
+fn transfer_position_should_move_position_with_its_state_when_transfers_are_enabled() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = 1;
+		Positions::<Test>::mutate(bob_position_id, |p| {
+			p.as_mut().unwrap().action_points = 1_000;
+		});
+		let position = Staking::positions(bob_position_id).unwrap();
+		let total_locked = position.get_total_locked().unwrap();
+		let staking_data = Staking::staking();
+		let bob_balance = Tokens::free_balance(HDX, &BOB);
+		let dave_balance = Tokens::free_balance(HDX, &DAVE);
+
+		//Act
+		assert_ok!(Staking::transfer_position(
+			RuntimeOrigin::signed(BOB),
+			bob_position_id,
+			DAVE
+		));
+
+		//Assert
+		assert_eq!(Staking::positions(bob_position_id).unwrap(), position);
+		assert_eq!(Staking::staking(), staking_data);
+		assert_eq!(Staking::get_user_position_id(&BOB).unwrap(), None);
+		assert_eq!(Staking::get_user_position_id(&DAVE).unwrap(), Some(bob_position_id));
+
+		assert_hdx_lock!(BOB, 0, STAKING_LOCK);
+		assert_hdx_lock!(DAVE, total_locked, STAKING_LOCK);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), bob_balance - total_locked);
+		assert_eq!(Tokens::free_balance(HDX, &DAVE), dave_balance + total_locked);
+
+		assert_last_event!(Event::<Test>::PositionTransferred {
+			from: BOB,
+			to: DAVE,
+			position_id: bob_position_id,
+			stake: 150_000 * ONE,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn split_position_should_not_work_when_transfers_are_disabled() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		let bob_position_id = 1;
+
+		//Act & assert
+		assert_noop!(
+			Staking::split_position(RuntimeOrigin::signed(BOB), bob_position_id, 50_000 * ONE, DAVE),
+			Error::<Test>::PositionTransfersDisabled
+		);
+	});
+}
+
+#[test]
+fn split_position_should_not_work_when_remaining_stake_is_too_low() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = 1;
+
+		//Act & assert
+		assert_noop!(
+			Staking::split_position(RuntimeOrigin::signed(BOB), bob_position_id, 150_000 * ONE - ONE, DAVE),
+			Error::<Test>::InsufficientStake
+		);
+		assert_noop!(
+			Staking::split_position(RuntimeOrigin::signed(BOB), bob_position_id, ONE, DAVE),
+			Error::<Test>::InsufficientStake
+		);
+	});
+}
+
+#[test]
+fn split_position_should_split_position_state_proportionally() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Staking::set_position_transfers(RuntimeOrigin::root(), true));
+		let bob_position_id = 1;
+		Positions::<Test>::mutate(bob_position_id, |p| {
+			let p = p.as_mut().unwrap();
+			p.action_points = 1_000;
+			p.accumulated_slash_points = 300;
+			p.accumulated_unpaid_rewards = 60_000 * ONE;
+		});
+		let position = Staking::positions(bob_position_id).unwrap();
+		let staking_data = Staking::staking();
+		let bob_balance = Tokens::free_balance(HDX, &BOB);
+		let dave_balance = Tokens::free_balance(HDX, &DAVE);
+		let new_position_id = Staking::next_position_id();
+
+		//Act
+		assert_ok!(Staking::split_position(
+			RuntimeOrigin::signed(BOB),
+			bob_position_id,
+			50_000 * ONE,
+			DAVE
+		));
+
+		//Assert
+		let expected_new_position = Position {
+			stake: 50_000 * ONE,
+			action_points: 333,
+			reward_per_stake: position.reward_per_stake,
+			created_at: position.created_at,
+			accumulated_slash_points: 100,
+			accumulated_unpaid_rewards: 20_000 * ONE,
+			accumulated_locked_rewards: position.accumulated_locked_rewards / 3,
+		};
+		let expected_position = Position {
+			stake: 100_000 * ONE,
+			action_points: 667,
+			accumulated_slash_points: 200,
+			accumulated_unpaid_rewards: 40_000 * ONE,
+			accumulated_locked_rewards: position.accumulated_locked_rewards
+				- expected_new_position.accumulated_locked_rewards,
+			..position.clone()
+		};
+		assert_eq!(Staking::positions(bob_position_id).unwrap(), expected_position);
+		assert_eq!(Staking::positions(new_position_id).unwrap(), expected_new_position);
+		assert_eq!(Staking::staking(), staking_data);
+		assert_eq!(Staking::get_user_position_id(&BOB).unwrap(), Some(bob_position_id));
+		assert_eq!(Staking::get_user_position_id(&DAVE).unwrap(), Some(new_position_id));
+
+		let moved_amount = expected_new_position.get_total_locked().unwrap();
+		assert_hdx_lock!(BOB, expected_position.get_total_locked().unwrap(), STAKING_LOCK);
+		assert_hdx_lock!(DAVE, moved_amount, STAKING_LOCK);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), bob_balance - moved_amount);
+		assert_eq!(Tokens::free_balance(HDX, &DAVE), dave_balance + moved_amount);
+
+		assert_last_event!(Event::<Test>::PositionSplit {
+			who: BOB,
+			position_id: bob_position_id,
+			to: DAVE,
+			new_position_id,
+			stake: 50_000 * ONE,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn position_split_should_keep_reward_per_stake_and_total_locked_amount() {
+	default_ext().build().execute_with(|| {
+		//Arrange
+		let bob_position_id = 1;
+		let mut position = Staking::positions(bob_position_id).unwrap();
+		let total_locked = position.get_total_locked().unwrap();
+		let reward_per_stake = position.reward_per_stake;
+
+		//Act
+		let new_position = position.split(50_000 * ONE).unwrap();
+
+		//Assert
+		assert_eq!(position.stake + new_position.stake, 150_000 * ONE);
+		assert_eq!(position.reward_per_stake, reward_per_stake);
+		assert_eq!(new_position.reward_per_stake, reward_per_stake);
+		assert_eq!(
+			position.get_total_locked().unwrap() + new_position.get_total_locked().unwrap(),
+			total_locked
+		);
+	});
+}
@@ -1,4 +1,4 @@
-use crate::types::Balance;
+use crate::types::{Action, Balance, Point};
 use frame_support::dispatch::DispatchResult;
 use sp_runtime::FixedU128;
 
@@ -25,3 +25,27 @@ pub trait VestingDetails<AccountId, Balance> {
 	/// Returns vested amount for who.
 	fn locked(who: AccountId) -> Balance;
 }
+
+/// Hook used by other pallets to reward users' staking positions with action points.
+pub trait ActionHook<AccountId> {
+	/// Reports `action` performed by `who`.
+	///
+	/// `amount` is the amount of staking power used for the action and `multiplier` its weight
+	/// (e.g. vote's conviction). Points are calculated as a share of `MaxPointsPerAction` based on
+	/// the ratio between used and max. staking power of the position.
+	///
+	/// Returns amount of action points added. It's zero if `who` doesn't have staking position.
+	fn on_action(who: &AccountId, action: Action, amount: Balance, multiplier: FixedU128) -> Point;
+}
+
+impl<AccountId> ActionHook<AccountId> for () {
+	fn on_action(_who: &AccountId, _action: Action, _amount: Balance, _multiplier: FixedU128) -> Point {
+		Point::default()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Registers asset which can be used as reward asset.
+	fn register_reward_asset() -> Result<AssetId, sp_runtime::DispatchError>;
+}
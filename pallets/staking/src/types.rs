@@ -3,8 +3,9 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::bounded::BoundedVec;
 use sp_core::Get;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_runtime::RuntimeDebug;
-use sp_runtime::{traits::Zero, ArithmeticError, FixedU128};
+use sp_runtime::{traits::Zero, ArithmeticError, FixedU128, Rounding};
 
 pub type Balance = u128;
 pub type Point = u128;
@@ -12,8 +13,15 @@ pub type Period = u128;
 
 pub type ReferendumIndex = u32;
 
+/// Actions users are rewarded with action points for.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub enum Action {
+	/// Vote in a referendum.
 	DemocracyVote,
+	/// Providing liquidity to the Omnipool.
+	OmnipoolLiquidity,
+	/// Running DCA schedule.
+	DcaSchedule,
 }
 
 /// Staking position, represents user's state in staking, e.g. staked amount, slashed points,...
@@ -59,6 +67,101 @@ impl<BlockNumber> Position<BlockNumber> {
 	pub fn get_action_points(&self) -> Point {
 		self.action_points
 	}
+
+	/// Splits `amount` of the stake from the position into the new position.
+	///
+	/// Action points, slash points, unpaid and locked rewards are split proportionally to the stake.
+	/// Reward per stake and creation block are the same for both positions.
+	///
+	/// Returns the new position. `self` is updated to the remaining part.
+	pub fn split(&mut self, amount: Balance) -> Result<Self, ArithmeticError>
+	where
+		BlockNumber: Clone,
+	{
+		let stake = self.stake;
+		let share = |value: Balance| -> Result<Balance, ArithmeticError> {
+			multiply_by_rational_with_rounding(value, amount, stake, Rounding::Down)
+				.ok_or(ArithmeticError::DivisionByZero)
+		};
+
+		let new_position = Self {
+			stake: amount,
+			action_points: share(self.action_points)?,
+			reward_per_stake: self.reward_per_stake,
+			created_at: self.created_at.clone(),
+			accumulated_slash_points: share(self.accumulated_slash_points)?,
+			accumulated_unpaid_rewards: share(self.accumulated_unpaid_rewards)?,
+			accumulated_locked_rewards: share(self.accumulated_locked_rewards)?,
+		};
+
+		self.stake = self.stake.checked_sub(amount).ok_or(ArithmeticError::Underflow)?;
+		self.action_points = self.action_points.saturating_sub(new_position.action_points);
+		self.accumulated_slash_points = self
+			.accumulated_slash_points
+			.saturating_sub(new_position.accumulated_slash_points);
+		self.accumulated_unpaid_rewards = self
+			.accumulated_unpaid_rewards
+			.saturating_sub(new_position.accumulated_unpaid_rewards);
+		self.accumulated_locked_rewards = self
+			.accumulated_locked_rewards
+			.saturating_sub(new_position.accumulated_locked_rewards);
+
+		Ok(new_position)
+	}
+}
+
+/// Reward asset distributed to stakers in addition to the native asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RewardAsset<AssetId> {
+	/// Asset id of the reward asset.
+	pub asset_id: AssetId,
+	/// Accumulated reward per stake of the reward asset.
+	pub accumulated_reward_per_stake: FixedU128,
+	/// Balance of the reward asset allocated/reserved for stakers in the `pot`.
+	pub pot_reserved_balance: Balance,
+	/// Whether rewards are converted to the native asset when they are claimed.
+	pub convert_to_native: bool,
+}
+
+impl<AssetId> RewardAsset<AssetId> {
+	pub fn new(asset_id: AssetId, convert_to_native: bool) -> Self {
+		Self {
+			asset_id,
+			accumulated_reward_per_stake: FixedU128::zero(),
+			pot_reserved_balance: Zero::zero(),
+			convert_to_native,
+		}
+	}
+}
+
+/// Position's state of the reward asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+pub struct PositionRewardAsset {
+	/// Position's reward per stake of the reward asset.
+	pub reward_per_stake: FixedU128,
+	/// Rewards accumulated when stake was increased which were not paid yet.
+	pub accumulated_unpaid_rewards: Balance,
+	/// Claimed rewards which couldn't be transferred to the owner, e.g. because they were below
+	/// the existential deposit. These are paid in full with the next claim.
+	pub deferred_rewards: Balance,
+}
+
+impl PositionRewardAsset {
+	/// Splits unpaid rewards proportionally to `amount` of position's `stake` into the new state.
+	/// Deferred rewards stay with the original position.
+	pub fn split(&mut self, amount: Balance, stake: Balance) -> Result<Self, ArithmeticError> {
+		let unpaid_rewards =
+			multiply_by_rational_with_rounding(self.accumulated_unpaid_rewards, amount, stake, Rounding::Down)
+				.ok_or(ArithmeticError::DivisionByZero)?;
+
+		self.accumulated_unpaid_rewards = self.accumulated_unpaid_rewards.saturating_sub(unpaid_rewards);
+
+		Ok(Self {
+			reward_per_stake: self.reward_per_stake,
+			accumulated_unpaid_rewards: unpaid_rewards,
+			deferred_rewards: Zero::zero(),
+		})
+	}
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
@@ -78,6 +181,32 @@ impl StakingData {
 	}
 }
 
+/// Rewards of the staking position if it was claimed now.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PositionRewards {
+	/// Amount of rewards paid to user.
+	pub claimable_rewards: Balance,
+	/// Amount of rewards locked from `increase_stake` which are unlocked.
+	pub unlocked_rewards: Balance,
+	/// Amount of unpaid rewards returned to the `pot` for redistribution.
+	pub slashed_unpaid_rewards: Balance,
+	/// Amount of points position accumulated until now. Slashed points are subtracted.
+	pub points: Point,
+	/// Percentage of the rewards available to user.
+	pub payable_percentage: FixedU128,
+}
+
+/// Projected payable percentage of the staking position at future block.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PayablePercentageProjection<BlockNumber> {
+	/// Block number the projection is calculated for.
+	pub block_number: BlockNumber,
+	/// Payable percentage if user doesn't do any other action.
+	pub payable_percentage: FixedU128,
+	/// Payable percentage if user's additional votes are rewarded.
+	pub payable_percentage_with_votes: FixedU128,
+}
+
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
 pub enum Conviction {
 	#[default]
@@ -141,6 +270,17 @@ impl<'a> ActionData for &'a Vote {
 	}
 }
 
+/// Action's data reported via `ActionHook` - `(amount, multiplier)`.
+impl ActionData for (Balance, FixedU128) {
+	fn amount(&self) -> Balance {
+		self.0
+	}
+
+	fn conviction(&self) -> FixedU128 {
+		self.1
+	}
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 #[codec(mel_bound(skip_type_params(MaxVotes)))]
 #[scale_info(skip_type_params(MaxVotes))]
@@ -33,18 +33,20 @@ mod tests;
 mod traits;
 
 pub use crate::traits::*;
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::storage::with_transaction;
-use frame_support::traits::{Contains, IsSubType};
+use frame_support::traits::{Contains, GetCallMetadata, IsSubType};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	sp_runtime::{
 		traits::{DispatchInfoOf, One, PostDispatchInfoOf, Saturating, Zero},
 		transaction_validity::{InvalidTransaction, TransactionValidityError},
-		FixedPointNumber, FixedPointOperand, FixedU128,
+		DispatchError, FixedPointNumber, FixedPointOperand, FixedU128, TransactionOutcome,
 	},
 	traits::Get,
 	weights::Weight,
+	BoundedVec, RuntimeDebug,
 };
 use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor};
 use hydra_dx_math::ema::EmaPrice;
@@ -67,6 +69,46 @@ type BalanceOf<T> = <<T as Config>::Currencies as MultiCurrency<<T as frame_syst
 /// Spot price type
 pub type Price = FixedU128;
 
+/// Matches runtime calls by pallet name and, optionally, by call name.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen)]
+pub struct CallFilter<Name> {
+	/// Name of the pallet as declared in the runtime.
+	pub pallet: Name,
+	/// Name of the call. If not set, all calls of the pallet match.
+	pub call: Option<Name>,
+}
+
+impl<Name: AsRef<[u8]>> CallFilter<Name> {
+	pub fn matches(&self, pallet_name: &str, call_name: &str) -> bool {
+		self.pallet.as_ref() == pallet_name.as_bytes()
+			&& self
+				.call
+				.as_ref()
+				.map_or(true, |call| call.as_ref() == call_name.as_bytes())
+	}
+}
+
+/// Fee sponsorship registered by a sponsor account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen)]
+pub struct Sponsorship<Calls, BlockNumber> {
+	/// Calls for which the sponsor pays the fees.
+	pub calls: Calls,
+	/// Last block in which the sponsorship can be used.
+	pub expires_at: BlockNumber,
+}
+
+/// Account whose fees are paid by a sponsor.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen)]
+pub struct SponsoredAccount<AccountId, Balance> {
+	pub sponsor: AccountId,
+	/// Remaining amount of fees, in native currency, the sponsor pays for the account.
+	pub remaining: Balance,
+}
+
+pub type CallFilterOf<T> = CallFilter<BoundedVec<u8, <T as Config>::MaxCallNameLength>>;
+pub type SponsorshipOf<T> =
+	Sponsorship<BoundedVec<CallFilterOf<T>, <T as Config>::MaxSponsoredCalls>, BlockNumberFor<T>>;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -161,6 +203,18 @@ pub mod pallet {
 		/// Try to retrieve fee currency from runtime call.
 		/// It is generic implementation to avoid tight coupling with other pallets such as utility.
 		type TryCallCurrency<'a>: TryConvert<&'a <Self as frame_system::Config>::RuntimeCall, AssetIdOf<Self>>;
+
+		/// Maximum number of call filters in a fee sponsorship.
+		#[pallet::constant]
+		type MaxSponsoredCalls: Get<u32>;
+
+		/// Maximum length of a pallet or call name in a call filter.
+		#[pallet::constant]
+		type MaxCallNameLength: Get<u32>;
+
+		/// Maximum number of fallback fee currencies of an account.
+		#[pallet::constant]
+		type MaxFallbackCurrencies: Get<u32>;
 	}
 
 	#[pallet::event]
@@ -181,7 +235,8 @@ pub mod pallet {
 		/// [currency]
 		CurrencyRemoved { asset_id: AssetIdOf<T> },
 
-		/// Transaction fee paid in non-native currency
+		/// Transaction fee paid. Currency is the native currency and both amounts are equal if the fee
+		/// was paid in the native currency.
 		/// [Account, Currency, Native fee amount, Non-native fee amount, Destination account]
 		FeeWithdrawn {
 			account_id: T::AccountId,
@@ -190,6 +245,43 @@ pub mod pallet {
 			non_native_fee_amount: BalanceOf<T>,
 			destination_account_id: T::AccountId,
 		},
+
+		/// Fee sponsorship registered or updated.
+		SponsorshipRegistered {
+			sponsor: T::AccountId,
+			calls: BoundedVec<CallFilterOf<T>, T::MaxSponsoredCalls>,
+			expires_at: BlockNumberFor<T>,
+		},
+
+		/// Fee sponsorship removed.
+		SponsorshipRemoved { sponsor: T::AccountId },
+
+		/// Account added to a fee sponsorship or its quota updated.
+		AccountSponsored {
+			sponsor: T::AccountId,
+			account_id: T::AccountId,
+			quota: BalanceOf<T>,
+		},
+
+		/// Account removed from a fee sponsorship.
+		AccountSponsorshipRemoved {
+			sponsor: T::AccountId,
+			account_id: T::AccountId,
+		},
+
+		/// Fallback fee currencies of an account set.
+		FallbackCurrenciesSet {
+			account_id: T::AccountId,
+			currencies: BoundedVec<AssetIdOf<T>, T::MaxFallbackCurrencies>,
+		},
+
+		/// Transaction fee of an account, without the tip, paid by its sponsor.
+		/// [Sponsor, Account, Native fee amount]
+		FeeSponsored {
+			sponsor: T::AccountId,
+			account_id: T::AccountId,
+			native_fee_amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -229,6 +321,27 @@ pub mod pallet {
 
 		/// EVM permit call failed.
 		EvmPermitRunnerError,
+
+		/// Fee sponsorship must contain at least one call filter.
+		EmptyCallFilter,
+
+		/// Fee sponsorship expiry must be in the future.
+		SponsorshipExpired,
+
+		/// Fee sponsorship does not exist.
+		SponsorshipNotFound,
+
+		/// Account is already sponsored by another active sponsorship.
+		AlreadySponsored,
+
+		/// Account is not sponsored.
+		NotSponsored,
+
+		/// Only the sponsor or the sponsored account can remove an active sponsorship.
+		NotAllowed,
+
+		/// Currency is listed more than once.
+		DuplicateCurrency,
 	}
 
 	/// Account currency map
@@ -251,6 +364,28 @@ pub mod pallet {
 	pub type TransactionCurrencyOverride<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, AssetIdOf<T>, OptionQuery>;
 
+	/// Fee sponsorships mapped by sponsor account.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorship)]
+	pub type Sponsorships<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, SponsorshipOf<T>, OptionQuery>;
+
+	/// Accounts whose fees are paid by a sponsor.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_account)]
+	pub type SponsoredAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SponsoredAccount<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	/// Ordered list of currencies used to pay fees when the account currency cannot be used.
+	#[pallet::storage]
+	#[pallet::getter(fn fallback_currencies)]
+	pub type FallbackCurrencies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<AssetIdOf<T>, T::MaxFallbackCurrencies>, ValueQuery>;
+
+	/// Sponsor charged for the fee of the transaction currently being executed.
+	/// Set when the fee is withdrawn and removed when the fee is corrected.
+	#[pallet::storage]
+	pub type TransactionFeeSponsor<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -293,17 +428,7 @@ pub mod pallet {
 		pub fn set_currency(origin: OriginFor<T>, currency: AssetIdOf<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(currency) {
-				ensure!(
-					currency == T::NativeAssetId::get() || AcceptedCurrencies::<T>::contains_key(currency),
-					Error::<T>::UnsupportedCurrency
-				);
-			} else {
-				ensure!(
-					T::SwappablePaymentAssetSupport::is_trade_supported(currency, T::PolkadotNativeAssetId::get()),
-					Error::<T>::UnsupportedCurrency
-				);
-			}
+			Self::ensure_fee_currency(currency)?;
 
 			<AccountCurrencyMap<T>>::insert(who.clone(), currency);
 
@@ -455,6 +580,170 @@ pub mod pallet {
 
 			Ok(result)
 		}
+
+		/// Register or update a fee sponsorship of the origin.
+		///
+		/// Sponsor pays the transaction fees of its sponsored accounts for calls matching any of
+		/// the `calls` filters until `expires_at` block (inclusive).
+		/// Fees are paid in the currency set for the sponsor account.
+		///
+		/// Emits `SponsorshipRegistered` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_sponsorship())]
+		pub fn register_sponsorship(
+			origin: OriginFor<T>,
+			calls: BoundedVec<CallFilterOf<T>, T::MaxSponsoredCalls>,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			ensure!(!calls.is_empty(), Error::<T>::EmptyCallFilter);
+			ensure!(
+				expires_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::SponsorshipExpired
+			);
+
+			Sponsorships::<T>::insert(
+				&sponsor,
+				Sponsorship {
+					calls: calls.clone(),
+					expires_at,
+				},
+			);
+
+			Self::deposit_event(Event::SponsorshipRegistered {
+				sponsor,
+				calls,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Remove fee sponsorship of the origin.
+		///
+		/// Sponsored accounts are no longer charged to the sponsor. Their entries can be removed
+		/// by anyone via `remove_sponsored_account`.
+		///
+		/// Emits `SponsorshipRemoved` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsorship())]
+		pub fn remove_sponsorship(origin: OriginFor<T>) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			ensure!(
+				Sponsorships::<T>::take(&sponsor).is_some(),
+				Error::<T>::SponsorshipNotFound
+			);
+
+			Self::deposit_event(Event::SponsorshipRemoved { sponsor });
+
+			Ok(())
+		}
+
+		/// Sponsor fees of `account` up to `quota` of native currency.
+		///
+		/// Origin must have an active fee sponsorship. Calling it again for the same account
+		/// resets the remaining quota.
+		///
+		/// Emits `AccountSponsored` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::sponsor_account())]
+		pub fn sponsor_account(origin: OriginFor<T>, account: T::AccountId, quota: BalanceOf<T>) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+
+			let sponsorship = Sponsorships::<T>::get(&sponsor).ok_or(Error::<T>::SponsorshipNotFound)?;
+			ensure!(
+				sponsorship.expires_at >= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::SponsorshipExpired
+			);
+
+			if let Some(current) = SponsoredAccounts::<T>::get(&account) {
+				ensure!(
+					current.sponsor == sponsor || !Self::is_sponsorship_active(&current.sponsor),
+					Error::<T>::AlreadySponsored
+				);
+			}
+
+			SponsoredAccounts::<T>::insert(
+				&account,
+				SponsoredAccount {
+					sponsor: sponsor.clone(),
+					remaining: quota,
+				},
+			);
+
+			Self::deposit_event(Event::AccountSponsored {
+				sponsor,
+				account_id: account,
+				quota,
+			});
+
+			Ok(())
+		}
+
+		/// Remove `account` from its fee sponsorship.
+		///
+		/// Can be called by the sponsor or by the sponsored account. Once the sponsorship expired or
+		/// was removed, anyone can remove the account.
+		///
+		/// Emits `AccountSponsorshipRemoved` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsored_account())]
+		pub fn remove_sponsored_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let sponsored = SponsoredAccounts::<T>::get(&account).ok_or(Error::<T>::NotSponsored)?;
+			ensure!(
+				who == account || who == sponsored.sponsor || !Self::is_sponsorship_active(&sponsored.sponsor),
+				Error::<T>::NotAllowed
+			);
+
+			SponsoredAccounts::<T>::remove(&account);
+
+			Self::deposit_event(Event::AccountSponsorshipRemoved {
+				sponsor: sponsored.sponsor,
+				account_id: account,
+			});
+
+			Ok(())
+		}
+
+		/// Set ordered list of fallback fee currencies for the origin.
+		///
+		/// When the fee cannot be paid in the account currency, e.g. due to insufficient balance,
+		/// the fallback currencies are tried in the given order.
+		/// Each currency must be accepted as fee currency the same way as in `set_currency`.
+		///
+		/// Empty list removes the fallback currencies.
+		///
+		/// Emits `FallbackCurrenciesSet` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fallback_currencies(currencies.len() as u32))]
+		pub fn set_fallback_currencies(
+			origin: OriginFor<T>,
+			currencies: BoundedVec<AssetIdOf<T>, T::MaxFallbackCurrencies>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			for (idx, currency) in currencies.iter().enumerate() {
+				ensure!(!currencies[..idx].contains(currency), Error::<T>::DuplicateCurrency);
+				Self::ensure_fee_currency(*currency)?;
+			}
+
+			if currencies.is_empty() {
+				FallbackCurrencies::<T>::remove(&who);
+			} else {
+				FallbackCurrencies::<T>::insert(&who, currencies.clone());
+			}
+
+			Self::deposit_event(Event::FallbackCurrenciesSet {
+				account_id: who,
+				currencies,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -563,6 +852,81 @@ impl<T: Config> Pallet<T> {
 		Pallet::<T>::get_currency(who).unwrap_or_else(T::NativeAssetId::get)
 	}
 
+	fn ensure_fee_currency(currency: AssetIdOf<T>) -> DispatchResult {
+		if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(currency) {
+			ensure!(
+				currency == T::NativeAssetId::get() || AcceptedCurrencies::<T>::contains_key(currency),
+				Error::<T>::UnsupportedCurrency
+			);
+		} else {
+			ensure!(
+				T::SwappablePaymentAssetSupport::is_trade_supported(currency, T::PolkadotNativeAssetId::get()),
+				Error::<T>::UnsupportedCurrency
+			);
+		}
+
+		Ok(())
+	}
+
+	fn is_sponsorship_active(sponsor: &T::AccountId) -> bool {
+		Sponsorships::<T>::get(sponsor).map_or(false, |sponsorship| {
+			sponsorship.expires_at >= frame_system::Pallet::<T>::block_number()
+		})
+	}
+
+	/// Returns the sponsor which pays `fee` of `call` dispatched by `who`, if any.
+	pub fn fee_sponsor(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		fee: BalanceOf<T>,
+	) -> Option<T::AccountId>
+	where
+		<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+	{
+		let sponsored = SponsoredAccounts::<T>::get(who)?;
+		if sponsored.remaining < fee {
+			return None;
+		}
+
+		let sponsorship = Sponsorships::<T>::get(&sponsored.sponsor)?;
+		if sponsorship.expires_at < frame_system::Pallet::<T>::block_number() {
+			return None;
+		}
+
+		let metadata = call.get_call_metadata();
+		sponsorship
+			.calls
+			.iter()
+			.any(|filter| filter.matches(metadata.pallet_name, metadata.function_name))
+			.then_some(sponsored.sponsor)
+	}
+
+	/// Returns the sponsor and the remaining quota of an account with an active fee sponsorship.
+	pub fn remaining_sponsorship(who: &T::AccountId) -> Option<(T::AccountId, BalanceOf<T>, BlockNumberFor<T>)> {
+		let sponsored = SponsoredAccounts::<T>::get(who)?;
+		let sponsorship = Sponsorships::<T>::get(&sponsored.sponsor)?;
+
+		if sponsorship.expires_at < frame_system::Pallet::<T>::block_number() {
+			return None;
+		}
+
+		Some((sponsored.sponsor, sponsored.remaining, sponsorship.expires_at))
+	}
+
+	fn note_sponsored_fee(who: &T::AccountId, sponsor: &T::AccountId, fee: BalanceOf<T>) {
+		SponsoredAccounts::<T>::mutate(who, |maybe_sponsored| {
+			if let Some(sponsored) = maybe_sponsored.as_mut().filter(|s| s.sponsor == *sponsor) {
+				sponsored.remaining = sponsored.remaining.saturating_sub(fee);
+			}
+		});
+
+		Self::deposit_event(Event::FeeSponsored {
+			sponsor: sponsor.clone(),
+			account_id: who.clone(),
+			native_fee_amount: fee,
+		});
+	}
+
 	fn get_currency_price(currency: AssetIdOf<T>) -> Option<Price>
 	where
 		BalanceOf<T>: FixedPointOperand,
@@ -622,7 +986,8 @@ where
 	MC::Balance: FixedPointOperand,
 	FR: Get<T::AccountId>,
 	DF: DepositFee<T::AccountId, MC::CurrencyId, MC::Balance>,
-	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>> + IsSubType<pallet_utility::pallet::Call<T>>,
+	<T as frame_system::Config>::RuntimeCall:
+		IsSubType<Call<T>> + IsSubType<pallet_utility::pallet::Call<T>> + GetCallMetadata,
 	<T as pallet_utility::Config>::RuntimeCall: IsSubType<Call<T>>,
 	BalanceOf<T>: FixedPointOperand,
 	BalanceOf<T>: From<MC::Balance>,
@@ -630,7 +995,10 @@ where
 	type LiquidityInfo = Option<PaymentInfo<Self::Balance, AssetIdOf<T>, Price>>;
 	type Balance = <MC as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 
-	/// Withdraw the predicted fee from the transaction origin.
+	/// Withdraw the predicted fee from the transaction origin or from its sponsor
+	/// if the call is sponsored.
+	///
+	/// If the fee cannot be paid in the fee currency, fallback currencies of the payer are tried in order.
 	///
 	/// Note: The `fee` already includes the `tip`.
 	fn withdraw_fee(
@@ -638,13 +1006,13 @@ where
 		call: &<T as frame_system::Config>::RuntimeCall,
 		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
 		fee: Self::Balance,
-		_tip: Self::Balance,
+		tip: Self::Balance,
 	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
 		if fee.is_zero() {
 			return Ok(None);
 		}
 
-		let currency = if let Some(Call::set_currency { currency }) = call.is_sub_type() {
+		let fee_currency = if let Some(Call::set_currency { currency }) = call.is_sub_type() {
 			*currency
 		} else if let Some(pallet_utility::pallet::Call::batch { calls })
 		| Some(pallet_utility::pallet::Call::batch_all { calls })
@@ -662,54 +1030,109 @@ where
 			Pallet::<T>::account_currency(who)
 		};
 
-		let (converted_fee, currency, price) = if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(currency)
-		{
-			let price = Pallet::<T>::get_currency_price(currency)
-				.ok_or(TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+		// Pays the `amount` of native fee by `payer` in `currency` and returns withdrawn amount,
+		// withdrawn currency and its price.
+		let pay_with = |payer: &T::AccountId,
+		                currency: AssetIdOf<T>,
+		                amount: Self::Balance|
+		 -> Result<(Self::Balance, AssetIdOf<T>, Price), DispatchError> {
+			if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(currency) {
+				let price = Pallet::<T>::get_currency_price(currency).ok_or(Error::<T>::UnsupportedCurrency)?;
 
-			let converted_fee = convert_fee_with_price(fee, price)
-				.ok_or(TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
-			(converted_fee, currency, price)
-		} else {
-			//In case of insufficient asset we buy DOT with insufficient asset, and using that DOT and amount as fee currency
-			let dot_hdx_price = Pallet::<T>::get_currency_price(T::PolkadotNativeAssetId::get())
-				.ok_or(TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
-
-			let fee_in_dot = convert_fee_with_price(fee, dot_hdx_price)
-				.ok_or(TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
-
-			let amount_in = T::SwappablePaymentAssetSupport::calculate_in_given_out(
-				currency,
-				T::PolkadotNativeAssetId::get(),
-				fee_in_dot.into(),
-			)
-			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
-			let pool_fee = T::SwappablePaymentAssetSupport::calculate_fee_amount(amount_in)
-				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
-			let max_limit = amount_in.saturating_add(pool_fee);
-
-			T::SwappablePaymentAssetSupport::buy(
-				who,
-				currency,
-				T::PolkadotNativeAssetId::get(),
-				fee_in_dot.into(),
-				max_limit,
-				who,
-			)
-			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
-
-			(fee_in_dot, T::PolkadotNativeAssetId::get(), dot_hdx_price)
+				let converted_fee = convert_fee_with_price(amount, price).ok_or(Error::<T>::Overflow)?;
+
+				MC::withdraw(currency.into(), payer, converted_fee)?;
+
+				Ok((converted_fee, currency, price))
+			} else {
+				//In case of insufficient asset we buy DOT with insufficient asset, and using that DOT and amount as fee currency
+				let dot_hdx_price = Pallet::<T>::get_currency_price(T::PolkadotNativeAssetId::get())
+					.ok_or(Error::<T>::UnsupportedCurrency)?;
+
+				let fee_in_dot = convert_fee_with_price(amount, dot_hdx_price).ok_or(Error::<T>::Overflow)?;
+
+				let amount_in = T::SwappablePaymentAssetSupport::calculate_in_given_out(
+					currency,
+					T::PolkadotNativeAssetId::get(),
+					fee_in_dot.into(),
+				)?;
+				let pool_fee = T::SwappablePaymentAssetSupport::calculate_fee_amount(amount_in)?;
+				let max_limit = amount_in.saturating_add(pool_fee);
+
+				T::SwappablePaymentAssetSupport::buy(
+					payer,
+					currency,
+					T::PolkadotNativeAssetId::get(),
+					fee_in_dot.into(),
+					max_limit,
+					payer,
+				)?;
+
+				MC::withdraw(T::PolkadotNativeAssetId::get().into(), payer, fee_in_dot)?;
+
+				Ok((fee_in_dot, T::PolkadotNativeAssetId::get(), dot_hdx_price))
+			}
 		};
 
-		match MC::withdraw(currency.into(), who, converted_fee) {
-			Ok(()) => {
-				if currency == T::NativeAssetId::get() {
-					Ok(Some(PaymentInfo::Native(fee)))
-				} else {
-					Ok(Some(PaymentInfo::NonNative(converted_fee, currency, price)))
+		// Tries `currency` first and then the fallback currencies of the payer in the given order.
+		let pay = |payer: &T::AccountId,
+		           currency: AssetIdOf<T>,
+		           amount: Self::Balance|
+		 -> Result<(Self::Balance, AssetIdOf<T>, Price), TransactionValidityError> {
+			let fallback_currencies = Pallet::<T>::fallback_currencies(payer);
+			for c in sp_std::iter::once(currency).chain(fallback_currencies.into_iter().filter(|c| *c != currency)) {
+				let result = with_transaction(|| match pay_with(payer, c, amount) {
+					Ok(paid) => TransactionOutcome::Commit(Ok(paid)),
+					Err(e) => TransactionOutcome::Rollback(Err(e)),
+				});
+
+				if let Ok(paid) = result {
+					return Ok(paid);
 				}
 			}
-			Err(_) => Err(InvalidTransaction::Payment.into()),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+		};
+
+		// The sponsor pays the fee without the tip, the tip is always paid by the signer.
+		// If the sponsor can't pay, the signer pays the whole fee.
+		let sponsored_fee = fee.saturating_sub(tip);
+		let sponsored = Pallet::<T>::fee_sponsor(who, call, sponsored_fee.into()).and_then(|sponsor| {
+			with_transaction(|| {
+				let result = pay(&sponsor, Pallet::<T>::account_currency(&sponsor), sponsored_fee).and_then(|paid| {
+					if !tip.is_zero() {
+						let (tip_amount, tip_currency, _) = pay(who, fee_currency, tip)?;
+						DF::deposit_fee(&FR::get(), tip_currency.into(), tip_amount)
+							.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+						Pallet::<T>::deposit_event(Event::FeeWithdrawn {
+							account_id: who.clone(),
+							asset_id: tip_currency,
+							native_fee_amount: tip.into(),
+							non_native_fee_amount: tip_amount.into(),
+							destination_account_id: FR::get(),
+						});
+					}
+					Ok(paid)
+				});
+				match result {
+					Ok(paid) => TransactionOutcome::Commit(Ok((sponsor, paid))),
+					Err(e) => TransactionOutcome::Rollback(Err(e)),
+				}
+			})
+			.ok()
+		});
+
+		let (paid_fee, (converted_fee, currency, price)) = match sponsored {
+			Some((sponsor, payment)) => {
+				TransactionFeeSponsor::<T>::insert(who, sponsor);
+				(sponsored_fee, payment)
+			}
+			None => (fee, pay(who, fee_currency, fee)?),
+		};
+
+		if currency == T::NativeAssetId::get() {
+			Ok(Some(PaymentInfo::Native(paid_fee)))
+		} else {
+			Ok(Some(PaymentInfo::NonNative(converted_fee, currency, price)))
 		}
 	}
 
@@ -728,6 +1151,21 @@ where
 		let fee_receiver = FR::get();
 
 		if let Some(paid) = already_withdrawn {
+			// The tip of a sponsored transaction has already been paid by the signer.
+			let (payer, corrected_fee, tip) = match TransactionFeeSponsor::<T>::take(who) {
+				Some(sponsor) => {
+					let sponsored_fee = corrected_fee.saturating_sub(tip);
+					Pallet::<T>::note_sponsored_fee(who, &sponsor, sponsored_fee.into());
+					(sponsor, sponsored_fee, Zero::zero())
+				}
+				None => (who.clone(), corrected_fee, tip),
+			};
+
+			let asset_id = match paid {
+				PaymentInfo::NonNative(_, currency, _) => currency,
+				PaymentInfo::Native(_) => T::NativeAssetId::get(),
+			};
+
 			// Calculate how much refund we should return
 			let (currency, refund, fee, tip) = match paid {
 				PaymentInfo::Native(paid_fee) => (
@@ -754,12 +1192,22 @@ where
 			};
 
 			// refund to the account that paid the fees
-			MC::deposit(currency, who, refund)
+			MC::deposit(currency, &payer, refund)
 				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
 
 			// deposit the fee
 			DF::deposit_fee(&fee_receiver, currency, fee + tip)
 				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
+
+			// Reported for the native currency as well, so the currency which paid the fee is known
+			// also when the fallback currencies end with the native one.
+			Pallet::<T>::deposit_event(Event::FeeWithdrawn {
+				account_id: payer,
+				asset_id,
+				native_fee_amount: corrected_fee.into(),
+				non_native_fee_amount: (fee + tip).into(),
+				destination_account_id: fee_receiver,
+			});
 		}
 
 		Ok(())
@@ -796,11 +1244,13 @@ impl<T: Config> Happened<(T::AccountId, AssetIdOf<T>)> for AddTxAssetOnAccount<T
 ///
 /// Note: The fee currency is only removed if the system account is gone or the account
 /// corresponding to the fee currency is empty.
+/// Fallback fee currencies are removed only if the system account is gone.
 pub struct RemoveTxAssetOnKilled<T>(PhantomData<T>);
 impl<T: Config> Happened<(T::AccountId, AssetIdOf<T>)> for RemoveTxAssetOnKilled<T> {
 	fn happened((who, _currency): &(T::AccountId, AssetIdOf<T>)) {
 		if !frame_system::Pallet::<T>::account_exists(who) {
 			AccountCurrencyMap::<T>::remove(who);
+			FallbackCurrencies::<T>::remove(who);
 		} else if let Some(currency) = AccountCurrencyMap::<T>::get(who) {
 			if T::Currencies::total_balance(currency, who).is_zero() {
 				AccountCurrencyMap::<T>::remove(who);
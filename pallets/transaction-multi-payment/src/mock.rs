@@ -28,7 +28,7 @@ use frame_support::{
 		traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 		BuildStorage, MultiSignature, Perbill,
 	},
-	traits::{Everything, Get, Nothing},
+	traits::{ConstU32, Everything, Get, Nothing},
 	weights::{IdentityFee, Weight},
 };
 use frame_system as system;
@@ -178,6 +178,9 @@ impl Config for Test {
 	type EvmPermit = PermitDispatchHandler;
 	type TryCallCurrency<'a> = NoCallCurrency<Test>;
 	type SwappablePaymentAssetSupport = MockedInsufficientAssetSupport;
+	type MaxSponsoredCalls = ConstU32<4>;
+	type MaxCallNameLength = ConstU32<32>;
+	type MaxFallbackCurrencies = ConstU32<3>;
 }
 
 pub struct MockedInsufficientAssetSupport;
@@ -355,6 +358,7 @@ impl pallet_currencies::Config for Test {
 	type NativeCurrency = BasicCurrencyAdapter<Test, Balances, Amount, u32>;
 	type Erc20Currency = MockErc20Currency<Test>;
 	type BoundErc20 = MockBoundErc20<Test>;
+	type AccountFreeze = ();
 	type ReserveAccount = ReserveAccount;
 	type GetNativeCurrencyId = HdxAssetId;
 	type WeightInfo = ();
@@ -372,6 +376,10 @@ impl pallet_evm_accounts::Config for Test {
 	type EvmNonceProvider = EvmNonceProvider;
 	type FeeMultiplier = frame_support::traits::ConstU32<10>;
 	type ControllerOrigin = frame_system::EnsureRoot<AccountId>;
+	type ChainId = frame_support::traits::ConstU64<222_222>;
+	type MaxAuditReferenceLength = frame_support::traits::ConstU32<128>;
+	type DepositCurrency = Balances;
+	type BindingDeposit = frame_support::traits::ConstU128<0>;
 	type WeightInfo = ();
 }
 
@@ -16,7 +16,10 @@
 // limitations under the License.
 
 pub use crate::{mock::*, Error};
-use crate::{AcceptedCurrencies, AcceptedCurrencyPrice, Event, PaymentInfo, Price};
+use crate::{
+	AcceptedCurrencies, AcceptedCurrencyPrice, CallFilter, CallFilterOf, Event, FallbackCurrencies, PaymentInfo, Price,
+	SponsoredAccount, SponsoredAccounts, Sponsorship, Sponsorships,
+};
 
 use frame_support::{
 	assert_noop, assert_ok, assert_storage_noop,
@@ -31,7 +34,7 @@ use pallet_balances::Call as BalancesCall;
 use pallet_transaction_payment::ChargeTransactionPayment;
 use sp_core::{H256, U256};
 use sp_runtime::traits::ValidateUnsigned;
-use sp_runtime::transaction_validity::TransactionSource;
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
 
 const CALL: &<Test as frame_system::Config>::RuntimeCall =
 	&RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: BOB, value: 69 });
@@ -852,6 +855,16 @@ fn fee_should_be_transferred_when_paid_in_non_native_currency_with_tip() {
 			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &FeeReceiver::get()), 45);
 			assert_eq!(Balances::free_balance(CHARLIE), 0);
 			assert_eq!(Balances::free_balance(FEE_RECEIVER), 0);
+			System::assert_last_event(
+				Event::FeeWithdrawn {
+					account_id: CHARLIE,
+					asset_id: SUPPORTED_CURRENCY,
+					native_fee_amount: 30,
+					non_native_fee_amount: 45,
+					destination_account_id: FEE_RECEIVER,
+				}
+				.into(),
+			);
 		});
 }
 
@@ -1481,3 +1494,703 @@ fn dispatch_should_correctly_call_validate_and_dispatch() {
 			assert_eq!(PermitDispatchHandler::last_dispatch_call_data(), expected);
 		});
 }
+
+fn call_filter(pallet: &[u8], call: Option<&[u8]>) -> CallFilterOf<Test> {
+	CallFilter {
+		pallet: pallet.to_vec().try_into().unwrap(),
+		call: call.map(|c| c.to_vec().try_into().unwrap()),
+	}
+}
+
+fn sponsor_transfers(sponsor: AccountId, account: AccountId, quota: Balance, expires_at: u64) {
+	assert_ok!(PaymentPallet::register_sponsorship(
+		RuntimeOrigin::signed(sponsor.clone()),
+		vec![call_filter(b"Balances", Some(b"transfer_allow_death"))]
+			.try_into()
+			.unwrap(),
+		expires_at,
+	));
+	assert_ok!(PaymentPallet::sponsor_account(
+		RuntimeOrigin::signed(sponsor),
+		account,
+		quota
+	));
+}
+
+#[test]
+fn register_sponsorship_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let calls: frame_support::BoundedVec<_, _> = vec![call_filter(b"Balances", None)].try_into().unwrap();
+
+		assert_ok!(PaymentPallet::register_sponsorship(
+			RuntimeOrigin::signed(BOB),
+			calls.clone(),
+			10
+		));
+
+		assert_eq!(
+			Sponsorships::<Test>::get(BOB),
+			Some(Sponsorship {
+				calls: calls.clone(),
+				expires_at: 10
+			})
+		);
+		expect_events(vec![Event::SponsorshipRegistered {
+			sponsor: BOB,
+			calls,
+			expires_at: 10,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn register_sponsorship_should_fail_when_filter_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::register_sponsorship(RuntimeOrigin::signed(BOB), Default::default(), 10),
+			Error::<Test>::EmptyCallFilter
+		);
+	});
+}
+
+#[test]
+fn register_sponsorship_should_fail_when_expiry_is_not_in_future() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::register_sponsorship(
+				RuntimeOrigin::signed(BOB),
+				vec![call_filter(b"Balances", None)].try_into().unwrap(),
+				1
+			),
+			Error::<Test>::SponsorshipExpired
+		);
+	});
+}
+
+#[test]
+fn remove_sponsorship_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+		assert_ok!(PaymentPallet::remove_sponsorship(RuntimeOrigin::signed(BOB)));
+
+		assert_eq!(Sponsorships::<Test>::get(BOB), None);
+		assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), None);
+		expect_events(vec![Event::SponsorshipRemoved { sponsor: BOB }.into()]);
+
+		assert_noop!(
+			PaymentPallet::remove_sponsorship(RuntimeOrigin::signed(BOB)),
+			Error::<Test>::SponsorshipNotFound
+		);
+	});
+}
+
+#[test]
+fn sponsor_account_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+		assert_eq!(
+			SponsoredAccounts::<Test>::get(CHARLIE),
+			Some(SponsoredAccount {
+				sponsor: BOB,
+				remaining: 100
+			})
+		);
+		assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), Some((BOB, 100, 10)));
+		expect_events(vec![Event::AccountSponsored {
+			sponsor: BOB,
+			account_id: CHARLIE,
+			quota: 100,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sponsor_account_should_fail_without_sponsorship() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::sponsor_account(RuntimeOrigin::signed(BOB), CHARLIE, 100),
+			Error::<Test>::SponsorshipNotFound
+		);
+	});
+}
+
+#[test]
+fn sponsor_account_should_fail_when_sponsorship_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+		System::set_block_number(11);
+
+		assert_noop!(
+			PaymentPallet::sponsor_account(RuntimeOrigin::signed(BOB), DAVE, 100),
+			Error::<Test>::SponsorshipExpired
+		);
+	});
+}
+
+#[test]
+fn sponsor_account_should_fail_when_account_has_another_active_sponsor() {
+	ExtBuilder::default().build().execute_with(|| {
+		sponsor_transfers(BOB, CHARLIE, 100, 10);
+		assert_ok!(PaymentPallet::register_sponsorship(
+			RuntimeOrigin::signed(DAVE),
+			vec![call_filter(b"Balances", None)].try_into().unwrap(),
+			20
+		));
+
+		assert_noop!(
+			PaymentPallet::sponsor_account(RuntimeOrigin::signed(DAVE), CHARLIE, 100),
+			Error::<Test>::AlreadySponsored
+		);
+
+		assert_ok!(PaymentPallet::remove_sponsorship(RuntimeOrigin::signed(BOB)));
+
+		assert_ok!(PaymentPallet::sponsor_account(
+			RuntimeOrigin::signed(DAVE),
+			CHARLIE,
+			100
+		));
+		assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), Some((DAVE, 100, 20)));
+	});
+}
+
+#[test]
+fn remove_sponsored_account_should_work_for_sponsor_and_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		sponsor_transfers(BOB, CHARLIE, 100, 10);
+		assert_ok!(PaymentPallet::sponsor_account(RuntimeOrigin::signed(BOB), DAVE, 100));
+
+		assert_noop!(
+			PaymentPallet::remove_sponsored_account(RuntimeOrigin::signed(ALICE), CHARLIE),
+			Error::<Test>::NotAllowed
+		);
+
+		assert_ok!(PaymentPallet::remove_sponsored_account(
+			RuntimeOrigin::signed(CHARLIE),
+			CHARLIE
+		));
+		expect_events(vec![Event::AccountSponsorshipRemoved {
+			sponsor: BOB,
+			account_id: CHARLIE,
+		}
+		.into()]);
+
+		assert_ok!(PaymentPallet::remove_sponsored_account(
+			RuntimeOrigin::signed(BOB),
+			DAVE
+		));
+
+		assert_eq!(SponsoredAccounts::<Test>::get(CHARLIE), None);
+		assert_eq!(SponsoredAccounts::<Test>::get(DAVE), None);
+		assert_noop!(
+			PaymentPallet::remove_sponsored_account(RuntimeOrigin::signed(BOB), DAVE),
+			Error::<Test>::NotSponsored
+		);
+	});
+}
+
+#[test]
+fn remove_sponsored_account_should_be_permissionless_when_sponsorship_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+		System::set_block_number(11);
+
+		assert_ok!(PaymentPallet::remove_sponsored_account(
+			RuntimeOrigin::signed(ALICE),
+			CHARLIE
+		));
+		assert_eq!(SponsoredAccounts::<Test>::get(CHARLIE), None);
+	});
+}
+
+#[test]
+fn fee_should_be_paid_by_sponsor_when_call_is_sponsored() {
+	ExtBuilder::default()
+		.account_native_balance(BOB, 1_000)
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+			let len = 10;
+			let tip = 0;
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			let pre = ChargeTransactionPayment::<Test>::from(tip)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
+				.unwrap();
+
+			// Assert
+			assert_eq!(pre, (tip, CHARLIE, Some(PaymentInfo::Native(5 + 15 + 10))));
+			assert_eq!(Balances::free_balance(CHARLIE), 100);
+			assert_eq!(Balances::free_balance(BOB), 1_000 - 30);
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&post_info_from_weight(Weight::from_parts(5, 0)),
+				len,
+				&Ok(())
+			));
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100);
+			assert_eq!(Balances::free_balance(BOB), 1_000 - 20);
+			assert_eq!(Balances::free_balance(FEE_RECEIVER), 20);
+			assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), Some((BOB, 80, 10)));
+			System::assert_has_event(
+				Event::FeeSponsored {
+					sponsor: BOB,
+					account_id: CHARLIE,
+					native_fee_amount: 20,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn fee_should_be_paid_in_sponsor_currency_when_call_is_sponsored() {
+	ExtBuilder::default()
+		.account_native_balance(CHARLIE, 100)
+		.account_tokens(BOB, SUPPORTED_CURRENCY, 10_000)
+		.with_currencies(vec![(BOB, SUPPORTED_CURRENCY)])
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+			let len = 10;
+			let tip = 0;
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			let pre = ChargeTransactionPayment::<Test>::from(tip)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
+				.unwrap();
+
+			// Assert
+			assert_eq!(
+				pre,
+				(
+					tip,
+					CHARLIE,
+					Some(PaymentInfo::NonNative(45, SUPPORTED_CURRENCY, Price::from_float(1.5)))
+				)
+			);
+			assert_eq!(Balances::free_balance(CHARLIE), 100);
+			assert_eq!(Tokens::free_balance(SUPPORTED_CURRENCY, &BOB), 10_000 - 45);
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&default_post_info(),
+				len,
+				&Ok(())
+			));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(SUPPORTED_CURRENCY, &BOB), 10_000 - 45);
+			assert_eq!(Tokens::free_balance(SUPPORTED_CURRENCY, &FEE_RECEIVER), 45);
+			assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), Some((BOB, 70, 10)));
+			System::assert_has_event(
+				Event::FeeWithdrawn {
+					account_id: BOB,
+					asset_id: SUPPORTED_CURRENCY,
+					native_fee_amount: 30,
+					non_native_fee_amount: 45,
+					destination_account_id: FEE_RECEIVER,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn tip_should_be_paid_by_account_when_call_is_sponsored() {
+	ExtBuilder::default()
+		.account_native_balance(BOB, 1_000)
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+			let len = 10;
+			let tip = 7;
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			let pre = ChargeTransactionPayment::<Test>::from(tip)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
+				.unwrap();
+
+			// Assert
+			assert_eq!(pre, (tip, CHARLIE, Some(PaymentInfo::Native(5 + 15 + 10))));
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 7);
+			assert_eq!(Balances::free_balance(BOB), 1_000 - 30);
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&post_info_from_weight(Weight::from_parts(5, 0)),
+				len,
+				&Ok(())
+			));
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 7);
+			assert_eq!(Balances::free_balance(BOB), 1_000 - 20);
+			assert_eq!(Balances::free_balance(FEE_RECEIVER), 20 + 7);
+			assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), Some((BOB, 80, 10)));
+		});
+}
+
+#[test]
+fn fee_should_be_paid_by_account_when_sponsor_cannot_pay() {
+	ExtBuilder::default()
+		.account_native_balance(BOB, 10)
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+			let len = 10;
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			let pre = ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
+				.unwrap();
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 30);
+			assert_eq!(Balances::free_balance(BOB), 10);
+
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&default_post_info(),
+				len,
+				&Ok(())
+			));
+			assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), Some((BOB, 100, 10)));
+		});
+}
+
+#[test]
+fn fee_should_be_paid_by_account_when_call_is_not_sponsored() {
+	ExtBuilder::default()
+		.account_native_balance(BOB, 1_000)
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			assert_ok!(PaymentPallet::register_sponsorship(
+				RuntimeOrigin::signed(BOB),
+				vec![call_filter(b"Balances", Some(b"transfer_keep_alive"))]
+					.try_into()
+					.unwrap(),
+				10,
+			));
+			assert_ok!(PaymentPallet::sponsor_account(RuntimeOrigin::signed(BOB), CHARLIE, 100));
+
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			let pre = ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, 10)
+				.unwrap();
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 30);
+			assert_eq!(Balances::free_balance(BOB), 1_000);
+
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&default_post_info(),
+				10,
+				&Ok(())
+			));
+			assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), Some((BOB, 100, 10)));
+		});
+}
+
+#[test]
+fn fee_should_be_paid_by_account_when_sponsorship_quota_is_exhausted() {
+	ExtBuilder::default()
+		.account_native_balance(BOB, 1_000)
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			sponsor_transfers(BOB, CHARLIE, 29, 10);
+
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::from(0).pre_dispatch(&CHARLIE, CALL, &dispatch_info, 10));
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 30);
+			assert_eq!(Balances::free_balance(BOB), 1_000);
+		});
+}
+
+#[test]
+fn fee_should_be_paid_by_account_when_sponsorship_expired() {
+	ExtBuilder::default()
+		.account_native_balance(BOB, 1_000)
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			sponsor_transfers(BOB, CHARLIE, 100, 10);
+
+			System::set_block_number(11);
+
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::from(0).pre_dispatch(&CHARLIE, CALL, &dispatch_info, 10));
+
+			// Assert
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 30);
+			assert_eq!(Balances::free_balance(BOB), 1_000);
+			assert_eq!(PaymentPallet::remaining_sponsorship(&CHARLIE), None);
+		});
+}
+
+#[test]
+fn set_fallback_currencies_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let currencies: frame_support::BoundedVec<_, _> = vec![SUPPORTED_CURRENCY, HDX].try_into().unwrap();
+
+		assert_ok!(PaymentPallet::set_fallback_currencies(
+			RuntimeOrigin::signed(ALICE),
+			currencies.clone()
+		));
+
+		assert_eq!(FallbackCurrencies::<Test>::get(ALICE), currencies);
+		expect_events(vec![Event::FallbackCurrenciesSet {
+			account_id: ALICE,
+			currencies,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_remove_currencies_when_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(PaymentPallet::set_fallback_currencies(
+			RuntimeOrigin::signed(ALICE),
+			vec![SUPPORTED_CURRENCY].try_into().unwrap()
+		));
+
+		assert_ok!(PaymentPallet::set_fallback_currencies(
+			RuntimeOrigin::signed(ALICE),
+			Default::default()
+		));
+
+		assert!(!FallbackCurrencies::<Test>::contains_key(ALICE));
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_fail_when_currency_is_not_accepted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::set_fallback_currencies(
+				RuntimeOrigin::signed(ALICE),
+				vec![SUPPORTED_CURRENCY, UNSUPPORTED_CURRENCY].try_into().unwrap()
+			),
+			Error::<Test>::UnsupportedCurrency
+		);
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_fail_when_currency_is_duplicated() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PaymentPallet::set_fallback_currencies(
+				RuntimeOrigin::signed(ALICE),
+				vec![SUPPORTED_CURRENCY, HDX, SUPPORTED_CURRENCY].try_into().unwrap()
+			),
+			Error::<Test>::DuplicateCurrency
+		);
+	});
+}
+
+#[test]
+fn fee_should_be_paid_in_fallback_currency_when_account_currency_balance_is_insufficient() {
+	ExtBuilder::default()
+		.with_currencies(vec![(CHARLIE, SUPPORTED_CURRENCY_WITH_PRICE)])
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY_WITH_PRICE, 10)
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY, 10_000)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			assert_ok!(PaymentPallet::set_fallback_currencies(
+				RuntimeOrigin::signed(CHARLIE),
+				vec![HDX, SUPPORTED_CURRENCY].try_into().unwrap()
+			));
+
+			let len = 10;
+			let tip = 0;
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			let pre = ChargeTransactionPayment::<Test>::from(tip)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, len)
+				.unwrap();
+
+			// Assert
+			assert_eq!(
+				pre,
+				(
+					tip,
+					CHARLIE,
+					Some(PaymentInfo::NonNative(45, SUPPORTED_CURRENCY, Price::from_float(1.5)))
+				)
+			);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY_WITH_PRICE, &CHARLIE), 10);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &CHARLIE), 10_000 - 45);
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&default_post_info(),
+				len,
+				&Ok(())
+			));
+
+			// Assert
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &CHARLIE), 10_000 - 45);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &FEE_RECEIVER), 45);
+			System::assert_last_event(
+				Event::FeeWithdrawn {
+					account_id: CHARLIE,
+					asset_id: SUPPORTED_CURRENCY,
+					native_fee_amount: 30,
+					non_native_fee_amount: 45,
+					destination_account_id: FEE_RECEIVER,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn fee_should_be_paid_in_native_currency_when_it_is_fallback_currency() {
+	ExtBuilder::default()
+		.with_currencies(vec![(CHARLIE, SUPPORTED_CURRENCY)])
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			assert_ok!(PaymentPallet::set_fallback_currencies(
+				RuntimeOrigin::signed(CHARLIE),
+				vec![HDX].try_into().unwrap()
+			));
+
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			let pre = ChargeTransactionPayment::<Test>::from(0)
+				.pre_dispatch(&CHARLIE, CALL, &dispatch_info, 10)
+				.unwrap();
+
+			// Assert
+			assert_eq!(pre, (0, CHARLIE, Some(PaymentInfo::Native(30))));
+			assert_eq!(Balances::free_balance(CHARLIE), 100 - 30);
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::post_dispatch(
+				Some(pre),
+				&dispatch_info,
+				&default_post_info(),
+				10,
+				&Ok(())
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::FeeWithdrawn {
+					account_id: CHARLIE,
+					asset_id: HDX,
+					native_fee_amount: 30,
+					non_native_fee_amount: 30,
+					destination_account_id: FEE_RECEIVER,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn fee_should_not_use_fallback_currency_when_account_currency_balance_is_sufficient() {
+	ExtBuilder::default()
+		.with_currencies(vec![(CHARLIE, SUPPORTED_CURRENCY)])
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY, 10_000)
+		.account_native_balance(CHARLIE, 100)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			assert_ok!(PaymentPallet::set_fallback_currencies(
+				RuntimeOrigin::signed(CHARLIE),
+				vec![HDX].try_into().unwrap()
+			));
+
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act
+			assert_ok!(ChargeTransactionPayment::<Test>::from(0).pre_dispatch(&CHARLIE, CALL, &dispatch_info, 10));
+
+			// Assert
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &CHARLIE), 10_000 - 45);
+			assert_eq!(Balances::free_balance(CHARLIE), 100);
+		});
+}
+
+#[test]
+fn fee_payment_should_fail_when_no_fallback_currency_has_sufficient_balance() {
+	ExtBuilder::default()
+		.with_currencies(vec![(CHARLIE, SUPPORTED_CURRENCY)])
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY, 10)
+		.account_tokens(CHARLIE, SUPPORTED_CURRENCY_WITH_PRICE, 10)
+		.base_weight(5)
+		.build()
+		.execute_with(|| {
+			assert_ok!(PaymentPallet::set_fallback_currencies(
+				RuntimeOrigin::signed(CHARLIE),
+				vec![SUPPORTED_CURRENCY_WITH_PRICE, HDX].try_into().unwrap()
+			));
+
+			let dispatch_info = info_from_weight(Weight::from_parts(15, 0));
+
+			// Act & Assert
+			assert_eq!(
+				ChargeTransactionPayment::<Test>::from(0)
+					.pre_dispatch(&CHARLIE, CALL, &dispatch_info, 10)
+					.map(|_| ()),
+				Err(InvalidTransaction::Payment.into())
+			);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY, &CHARLIE), 10);
+			assert_eq!(Currencies::free_balance(SUPPORTED_CURRENCY_WITH_PRICE, &CHARLIE), 10);
+		});
+}
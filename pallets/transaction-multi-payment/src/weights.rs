@@ -17,6 +17,11 @@ pub trait WeightInfo {
 	fn get_oracle_price() -> Weight;
 	fn reset_payment_currency() -> Weight;
 	fn withdraw_fee() -> Weight;
+	fn register_sponsorship() -> Weight;
+	fn remove_sponsorship() -> Weight;
+	fn sponsor_account() -> Weight;
+	fn remove_sponsored_account() -> Weight;
+	fn set_fallback_currencies(c: u32, ) -> Weight;
 }
 
 /// Weights for pallet_transaction_multi_payment using the hydraDX node and recommended hardware.
@@ -115,13 +120,70 @@ impl WeightInfo for () {
 	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
 	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::SponsoredAccounts` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::SponsoredAccounts` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::Sponsorships` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::Sponsorships` (`max_values`: None, `max_size`: Some(1370), added: 3845, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::FallbackCurrencies` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::FallbackCurrencies` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::TransactionFeeSponsor` (r:0 w:1)
+	/// Proof: `MultiTransactionPayment::TransactionFeeSponsor` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	fn withdraw_fee() -> Weight {
+		// Sponsorship and fallback currencies storage reads and writes were added manually, needs to be regenerated.
 		// Proof Size summary in bytes:
 		//  Measured:  `4158`
 		//  Estimated: `11322`
 		// Minimum execution time: 283_584_000 picoseconds.
 		Weight::from_parts(285_058_000, 11322)
-			.saturating_add(RocksDbWeight::get().reads(24_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(27_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `MultiTransactionPayment::Sponsorships` (r:0 w:1)
+	/// Proof: `MultiTransactionPayment::Sponsorships` (`max_values`: None, `max_size`: Some(1370), added: 3845, mode: `MaxEncodedLen`)
+	fn register_sponsorship() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(24_862_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MultiTransactionPayment::Sponsorships` (r:1 w:1)
+	/// Proof: `MultiTransactionPayment::Sponsorships` (`max_values`: None, `max_size`: Some(1370), added: 3845, mode: `MaxEncodedLen`)
+	fn remove_sponsorship() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(27_981_000, 4835)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MultiTransactionPayment::Sponsorships` (r:2 w:0)
+	/// Proof: `MultiTransactionPayment::Sponsorships` (`max_values`: None, `max_size`: Some(1370), added: 3845, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::SponsoredAccounts` (r:1 w:1)
+	/// Proof: `MultiTransactionPayment::SponsoredAccounts` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn sponsor_account() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(34_102_000, 8680)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MultiTransactionPayment::SponsoredAccounts` (r:1 w:1)
+	/// Proof: `MultiTransactionPayment::SponsoredAccounts` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::Sponsorships` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::Sponsorships` (`max_values`: None, `max_size`: Some(1370), added: 3845, mode: `MaxEncodedLen`)
+	fn remove_sponsored_account() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(30_415_000, 4835)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:5 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::FallbackCurrencies` (r:0 w:1)
+	/// Proof: `MultiTransactionPayment::FallbackCurrencies` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 5]`.
+	fn set_fallback_currencies(c: u32, ) -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(24_397_516, 1990)
+			.saturating_add(Weight::from_parts(3_892_344, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2503).saturating_mul(c.into()))
 	}
 }
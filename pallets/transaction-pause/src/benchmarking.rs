@@ -23,7 +23,19 @@ use super::*;
 use frame_benchmarking::benchmarks;
 use frame_support::assert_ok;
 
+fn asset_target<T: Config>(asset_id: u32) -> PauseTargetOf<T>
+where
+	T::AssetId: From<u32>,
+{
+	PauseTarget::AssetTransaction(
+		BoundedName::truncate_from(b"Omnipool".to_vec()),
+		BoundedName::truncate_from(b"sell".to_vec()),
+		asset_id.into(),
+	)
+}
+
 benchmarks! {
+	where_clause { where T::AssetId: From<u32> }
 
 	pause_transaction {
 		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
@@ -38,6 +50,44 @@ benchmarks! {
 	}:{
 		assert_ok!(crate::Pallet::<T>::unpause_transaction(origin, b"Balances".to_vec(), b"transfer".to_vec()));
 	}
+
+	pause {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let target = asset_target::<T>(1);
+	}: {
+		assert_ok!(crate::Pallet::<T>::pause(origin, target.clone(), Some(10u32.into())));
+	}
+	verify {
+		assert!(PauseExpiry::<T>::get(target).is_some());
+	}
+
+	unpause {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let target = asset_target::<T>(1);
+		crate::Pallet::<T>::pause(origin, target.clone(), Some(10u32.into()))?;
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+	}: {
+		assert_ok!(crate::Pallet::<T>::unpause(origin, target.clone()));
+	}
+	verify {
+		assert!(PauseExpiry::<T>::get(target).is_none());
+	}
+
+	on_initialize {
+		let n in 0 .. T::MaxExpiringPausesPerBlock::get();
+
+		let duration: BlockNumberFor<T> = 10u32.into();
+		for i in 0..n {
+			let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+			crate::Pallet::<T>::pause(origin, asset_target::<T>(i), Some(duration))?;
+		}
+		let expires_at = frame_system::Pallet::<T>::block_number() + duration;
+	}: {
+		crate::Pallet::<T>::on_initialize(expires_at);
+	}
+	verify {
+		assert!(ExpiringPauses::<T>::get(expires_at).is_empty());
+	}
 }
 
 #[cfg(test)]
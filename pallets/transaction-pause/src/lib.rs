@@ -26,7 +26,10 @@ use frame_support::{
 	BoundedVec,
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::DispatchResult;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchResult, RuntimeDebug,
+};
 use sp_std::{prelude::*, vec::Vec};
 
 mod benchmarking;
@@ -38,6 +41,47 @@ pub mod weights;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Scope of a pause.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PauseTarget<Name, AssetId> {
+	/// Single call of a pallet - `(pallet_name, function_name)`.
+	Transaction(Name, Name),
+	/// All calls of a pallet.
+	Pallet(Name),
+	/// Single call of a pallet, only when the call involves given asset - `(pallet_name, function_name, asset_id)`.
+	AssetTransaction(Name, Name, AssetId),
+}
+
+impl<Name, AssetId> PauseTarget<Name, AssetId> {
+	fn pallet_name(&self) -> &Name {
+		match self {
+			PauseTarget::Transaction(pallet_name, _)
+			| PauseTarget::Pallet(pallet_name)
+			| PauseTarget::AssetTransaction(pallet_name, _, _) => pallet_name,
+		}
+	}
+
+	fn function_name(&self) -> Option<&Name> {
+		match self {
+			PauseTarget::Transaction(_, function_name) | PauseTarget::AssetTransaction(_, function_name, _) => {
+				Some(function_name)
+			}
+			PauseTarget::Pallet(_) => None,
+		}
+	}
+}
+
+/// Provides asset ids a call operates on. Used to match asset scoped pauses.
+pub trait CallAssetIds<Call, AssetId> {
+	fn asset_ids(call: &Call) -> Vec<AssetId>;
+}
+
+impl<Call, AssetId> CallAssetIds<Call, AssetId> for () {
+	fn asset_ids(_call: &Call) -> Vec<AssetId> {
+		Vec::new()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -52,6 +96,7 @@ pub mod pallet {
 	// max length of a pallet name or function name
 	pub const MAX_STR_LENGTH: u32 = 40;
 	pub type BoundedName = BoundedVec<u8, ConstU32<MAX_STR_LENGTH>>;
+	pub type PauseTargetOf<T> = PauseTarget<BoundedName, <T as Config>::AssetId>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -60,6 +105,20 @@ pub mod pallet {
 		/// The origin which may set the transaction pause filter.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Asset id type used by asset scoped pauses.
+		type AssetId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Provides asset ids involved in a call.
+		type CallAssetIds: CallAssetIds<Self::RuntimeCall, Self::AssetId>;
+
+		/// Pallets which can't be paused as a whole, e.g. governance and system pallets.
+		/// Single calls of these pallets can still be paused.
+		type UnpausablePallets: Contains<BoundedName>;
+
+		/// Max number of time-limited pauses which can expire in the same block.
+		#[pallet::constant]
+		type MaxExpiringPausesPerBlock: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -72,6 +131,10 @@ pub mod pallet {
 		InvalidCharacter,
 		/// pallet name or function name is too long
 		NameTooLong,
+		/// duration of a time-limited pause must be greater than zero
+		InvalidDuration,
+		/// too many pauses expire in the same block
+		TooManyExpiringPauses,
 	}
 
 	#[pallet::event]
@@ -87,6 +150,15 @@ pub mod pallet {
 			pallet_name_bytes: Vec<u8>,
 			function_name_bytes: Vec<u8>,
 		},
+		/// Pause was set. Pause is permanent if `expires_at` is `None`.
+		Paused {
+			target: PauseTargetOf<T>,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// Pause was removed
+		Unpaused { target: PauseTargetOf<T> },
+		/// Time-limited pause expired
+		PauseExpired { target: PauseTargetOf<T> },
 	}
 
 	/// The paused transaction map
@@ -96,8 +168,56 @@ pub mod pallet {
 	#[pallet::getter(fn paused_transactions)]
 	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (BoundedName, BoundedName), (), OptionQuery>;
 
+	/// The paused pallets map
+	///
+	/// map PalletNameBytes => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_pallets)]
+	pub type PausedPallets<T: Config> = StorageMap<_, Twox64Concat, BoundedName, (), OptionQuery>;
+
+	/// The asset scoped paused transaction map
+	///
+	/// map (PalletNameBytes, FunctionNameBytes, AssetId) => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_asset_transactions)]
+	pub type PausedAssetTransactions<T: Config> =
+		StorageMap<_, Twox64Concat, (BoundedName, BoundedName, T::AssetId), (), OptionQuery>;
+
+	/// Expiry block of time-limited pauses. Pauses without an entry are permanent.
+	#[pallet::storage]
+	#[pallet::getter(fn pause_expiry)]
+	pub type PauseExpiry<T: Config> = StorageMap<_, Twox64Concat, PauseTargetOf<T>, BlockNumberFor<T>, OptionQuery>;
+
+	/// Pauses scheduled to expire at given block.
+	///
+	/// Entries can be stale if a pause was removed or re-set in the meantime. Those are skipped when swept.
+	#[pallet::storage]
+	pub type ExpiringPauses<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<PauseTargetOf<T>, T::MaxExpiringPausesPerBlock>,
+		ValueQuery,
+	>;
+
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let expiring = ExpiringPauses::<T>::take(n);
+			let count = expiring.len() as u32;
+
+			for target in expiring {
+				if PauseExpiry::<T>::get(&target) != Some(n) {
+					continue;
+				}
+				PauseExpiry::<T>::remove(&target);
+				Self::remove_pause(&target);
+				Self::deposit_event(Event::PauseExpired { target });
+			}
+
+			T::WeightInfo::on_initialize(count)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -109,12 +229,9 @@ pub mod pallet {
 			let pallet_name_b = BoundedName::try_from(pallet_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
 			let function_name_b = BoundedName::try_from(function_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
 
-			// not allowed to pause calls of this pallet to ensure safe
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotPause
-			);
+			Self::ensure_can_pause(&pallet_name_b, Some(&function_name_b))?;
+
+			PauseExpiry::<T>::remove(PauseTarget::Transaction(pallet_name_b.clone(), function_name_b.clone()));
 
 			PausedTransactions::<T>::mutate_exists((pallet_name_b, function_name_b), |maybe_paused| {
 				if maybe_paused.is_none() {
@@ -140,6 +257,8 @@ pub mod pallet {
 			let pallet_name_b = BoundedName::try_from(pallet_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
 			let function_name_b = BoundedName::try_from(function_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
 
+			PauseExpiry::<T>::remove(PauseTarget::Transaction(pallet_name_b.clone(), function_name_b.clone()));
+
 			if PausedTransactions::<T>::take((&pallet_name_b, &function_name_b)).is_some() {
 				Self::deposit_event(Event::TransactionUnpaused {
 					pallet_name_bytes: pallet_name,
@@ -148,6 +267,115 @@ pub mod pallet {
 			};
 			Ok(())
 		}
+
+		/// Pause a single call, all calls of a pallet or a single call involving given asset.
+		///
+		/// Pallets in `T::UnpausablePallets` can't be paused as a whole.
+		///
+		/// If `duration` is set, the pause is removed automatically `duration` blocks after current block.
+		/// Pausing an already paused target replaces its expiry.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::UpdateOrigin`.
+		/// - `target`: Scope of the pause.
+		/// - `duration`: Number of blocks the pause lasts. Permanent if `None`.
+		///
+		/// Emits `Paused` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(
+			origin: OriginFor<T>,
+			target: PauseTargetOf<T>,
+			duration: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			Self::ensure_can_pause(target.pallet_name(), target.function_name())?;
+			if let PauseTarget::Pallet(pallet_name) = &target {
+				ensure!(!T::UnpausablePallets::contains(pallet_name), Error::<T>::CannotPause);
+			}
+
+			let expires_at = match duration {
+				Some(duration) => {
+					ensure!(!duration.is_zero(), Error::<T>::InvalidDuration);
+					let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+					ExpiringPauses::<T>::try_mutate(expires_at, |expiring| {
+						expiring
+							.try_push(target.clone())
+							.map_err(|_| Error::<T>::TooManyExpiringPauses)
+					})?;
+					PauseExpiry::<T>::insert(&target, expires_at);
+					Some(expires_at)
+				}
+				None => {
+					PauseExpiry::<T>::remove(&target);
+					None
+				}
+			};
+
+			match &target {
+				PauseTarget::Transaction(pallet_name, function_name) => {
+					PausedTransactions::<T>::insert((pallet_name, function_name), ())
+				}
+				PauseTarget::Pallet(pallet_name) => PausedPallets::<T>::insert(pallet_name, ()),
+				PauseTarget::AssetTransaction(pallet_name, function_name, asset_id) => {
+					PausedAssetTransactions::<T>::insert((pallet_name, function_name, asset_id), ())
+				}
+			}
+
+			Self::deposit_event(Event::Paused { target, expires_at });
+			Ok(())
+		}
+
+		/// Remove a pause set by `pause` or `pause_transaction`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::UpdateOrigin`.
+		/// - `target`: Scope of the pause.
+		///
+		/// Emits `Unpaused` event when the target was paused.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>, target: PauseTargetOf<T>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			PauseExpiry::<T>::remove(&target);
+
+			if Self::remove_pause(&target) {
+				Self::deposit_event(Event::Unpaused { target });
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Ensures the names are valid and the pause doesn't target calls of this pallet.
+	fn ensure_can_pause(pallet_name: &BoundedName, function_name: Option<&BoundedName>) -> DispatchResult {
+		let pallet_name_string = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		if let Some(function_name) = function_name {
+			sp_std::str::from_utf8(function_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		}
+
+		// not allowed to pause calls of this pallet to ensure safe
+		ensure!(
+			pallet_name_string != <Self as PalletInfoAccess>::name(),
+			Error::<T>::CannotPause
+		);
+		Ok(())
+	}
+
+	/// Removes the pause of given target. Returns `true` if the target was paused.
+	fn remove_pause(target: &PauseTargetOf<T>) -> bool {
+		match target {
+			PauseTarget::Transaction(pallet_name, function_name) => {
+				PausedTransactions::<T>::take((pallet_name, function_name)).is_some()
+			}
+			PauseTarget::Pallet(pallet_name) => PausedPallets::<T>::take(pallet_name).is_some(),
+			PauseTarget::AssetTransaction(pallet_name, function_name, asset_id) => {
+				PausedAssetTransactions::<T>::take((pallet_name, function_name, asset_id)).is_some()
+			}
+		}
 	}
 }
 
@@ -169,6 +397,17 @@ where
 		}
 
 		// it's safe to call unwrap here thanks to the test above
-		PausedTransactions::<T>::contains_key((pallet_name_b.unwrap_or_default(), function_name_b.unwrap_or_default()))
+		let pallet_name_b = pallet_name_b.unwrap_or_default();
+		let function_name_b = function_name_b.unwrap_or_default();
+
+		if PausedPallets::<T>::contains_key(&pallet_name_b)
+			|| PausedTransactions::<T>::contains_key((&pallet_name_b, &function_name_b))
+		{
+			return true;
+		}
+
+		T::CallAssetIds::asset_ids(call)
+			.into_iter()
+			.any(|asset_id| PausedAssetTransactions::<T>::contains_key((&pallet_name_b, &function_name_b, asset_id)))
 	}
 }
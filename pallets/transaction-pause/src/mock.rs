@@ -33,6 +33,7 @@ use sp_runtime::{traits::IdentityLookup, BuildStorage};
 pub type AccountId = u128;
 pub const ALICE: AccountId = 1;
 pub const AUSD: CurrencyId = 2;
+pub const DOT: CurrencyId = 3;
 
 pub type Amount = i128;
 pub type Balance = u128;
@@ -118,9 +119,31 @@ ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
 
+pub struct TokensCallAssetIds;
+impl CallAssetIds<RuntimeCall, CurrencyId> for TokensCallAssetIds {
+	fn asset_ids(call: &RuntimeCall) -> Vec<CurrencyId> {
+		match call {
+			RuntimeCall::Tokens(orml_tokens::Call::transfer { currency_id, .. })
+			| RuntimeCall::Tokens(orml_tokens::Call::transfer_keep_alive { currency_id, .. }) => vec![*currency_id],
+			_ => vec![],
+		}
+	}
+}
+
+pub struct UnpausablePallets;
+impl Contains<BoundedName> for UnpausablePallets {
+	fn contains(pallet_name: &BoundedName) -> bool {
+		pallet_name.as_slice() == b"System"
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type AssetId = CurrencyId;
+	type CallAssetIds = TokensCallAssetIds;
+	type UnpausablePallets = UnpausablePallets;
+	type MaxExpiringPausesPerBlock = ConstU32<5>;
 	type WeightInfo = ();
 }
 
@@ -195,3 +195,263 @@ fn paused_transaction_filter_work() {
 		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
 	});
 }
+
+fn name(bytes: &[u8]) -> BoundedName {
+	BoundedName::try_from(bytes.to_vec()).unwrap()
+}
+
+const DOT_TRANSFER: &<Runtime as frame_system::Config>::RuntimeCall =
+	&mock::RuntimeCall::Tokens(orml_tokens::Call::transfer {
+		dest: ALICE,
+		currency_id: DOT,
+		amount: 10,
+	});
+
+#[test]
+fn pause_with_duration_should_expire_in_on_initialize() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+		let target = PauseTarget::Transaction(name(b"Balances"), name(b"transfer_allow_death"));
+
+		assert_ok!(TransactionPause::pause(
+			RuntimeOrigin::signed(1),
+			target.clone(),
+			Some(10)
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::Paused {
+			target: target.clone(),
+			expires_at: Some(11),
+		}));
+		assert_eq!(TransactionPause::pause_expiry(&target), Some(11));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		TransactionPause::on_initialize(10);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		TransactionPause::on_initialize(11);
+		System::assert_last_event(Event::TransactionPause(crate::Event::PauseExpired {
+			target: target.clone(),
+		}));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert_eq!(TransactionPause::pause_expiry(&target), None);
+		assert!(ExpiringPauses::<Runtime>::get(11).is_empty());
+	});
+}
+
+#[test]
+fn pause_pallet_should_filter_all_calls_of_pallet() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause(
+			RuntimeOrigin::signed(1),
+			PauseTarget::Pallet(name(b"Tokens")),
+			None
+		));
+		assert_eq!(TransactionPause::paused_pallets(name(b"Tokens")), Some(()));
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+		assert!(PausedTransactionFilter::<Runtime>::contains(DOT_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		assert_ok!(TransactionPause::unpause(
+			RuntimeOrigin::signed(1),
+			PauseTarget::Pallet(name(b"Tokens"))
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::Unpaused {
+			target: PauseTarget::Pallet(name(b"Tokens")),
+		}));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+	});
+}
+
+#[test]
+fn asset_scoped_pause_should_filter_only_calls_involving_asset() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause(
+			RuntimeOrigin::signed(1),
+			PauseTarget::AssetTransaction(name(b"Tokens"), name(b"transfer"), DOT),
+			None
+		));
+		assert_eq!(
+			TransactionPause::paused_asset_transactions((name(b"Tokens"), name(b"transfer"), DOT)),
+			Some(())
+		);
+		assert!(PausedTransactionFilter::<Runtime>::contains(DOT_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		assert_ok!(TransactionPause::unpause(
+			RuntimeOrigin::signed(1),
+			PauseTarget::AssetTransaction(name(b"Tokens"), name(b"transfer"), DOT)
+		));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(DOT_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_should_fail_when_invalid() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+		let target = PauseTarget::Pallet(name(b"Balances"));
+
+		assert_noop!(
+			TransactionPause::pause(RuntimeOrigin::signed(5), target.clone(), None),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::pause(
+				RuntimeOrigin::signed(1),
+				PauseTarget::Pallet(name(b"TransactionPause")),
+				None
+			),
+			Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause(
+				RuntimeOrigin::signed(1),
+				PauseTarget::AssetTransaction(name(b"TransactionPause"), name(b"unpause"), DOT),
+				None
+			),
+			Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause(RuntimeOrigin::signed(1), target, Some(0)),
+			Error::<Runtime>::InvalidDuration
+		);
+	});
+}
+
+#[test]
+fn pause_should_fail_when_pallet_is_unpausable() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause(RuntimeOrigin::signed(1), PauseTarget::Pallet(name(b"System")), None),
+			Error::<Runtime>::CannotPause
+		);
+
+		// single calls of the pallet can still be paused
+		assert_ok!(TransactionPause::pause(
+			RuntimeOrigin::signed(1),
+			PauseTarget::Transaction(name(b"System"), name(b"remark")),
+			None
+		));
+	});
+}
+
+#[test]
+fn pause_should_fail_when_function_name_is_invalid() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause(
+				RuntimeOrigin::signed(1),
+				PauseTarget::Transaction(name(b"Balances"), name(&[0xff, 0xfe])),
+				None
+			),
+			Error::<Runtime>::InvalidCharacter
+		);
+		assert_noop!(
+			TransactionPause::pause(
+				RuntimeOrigin::signed(1),
+				PauseTarget::AssetTransaction(name(b"Tokens"), name(&[0xff, 0xfe]), DOT),
+				None
+			),
+			Error::<Runtime>::InvalidCharacter
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(RuntimeOrigin::signed(1), b"Balances".to_vec(), vec![0xff, 0xfe]),
+			Error::<Runtime>::InvalidCharacter
+		);
+	});
+}
+
+#[test]
+fn pause_should_fail_when_too_many_pauses_expire_in_same_block() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		for asset_id in 0..5 {
+			assert_ok!(TransactionPause::pause(
+				RuntimeOrigin::signed(1),
+				PauseTarget::AssetTransaction(name(b"Tokens"), name(b"transfer"), asset_id),
+				Some(5)
+			));
+		}
+
+		assert_noop!(
+			TransactionPause::pause(
+				RuntimeOrigin::signed(1),
+				PauseTarget::AssetTransaction(name(b"Tokens"), name(b"transfer"), 5),
+				Some(5)
+			),
+			Error::<Runtime>::TooManyExpiringPauses
+		);
+		assert_ok!(TransactionPause::pause(
+			RuntimeOrigin::signed(1),
+			PauseTarget::AssetTransaction(name(b"Tokens"), name(b"transfer"), 5),
+			Some(6)
+		));
+	});
+}
+
+#[test]
+fn repausing_should_replace_expiry() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+		let target = PauseTarget::Pallet(name(b"Balances"));
+
+		assert_ok!(TransactionPause::pause(
+			RuntimeOrigin::signed(1),
+			target.clone(),
+			Some(5)
+		));
+		assert_ok!(TransactionPause::pause(
+			RuntimeOrigin::signed(1),
+			target.clone(),
+			Some(10)
+		));
+
+		// stale entry of the first pause is skipped
+		TransactionPause::on_initialize(6);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		// permanent pause is never swept
+		assert_ok!(TransactionPause::pause(RuntimeOrigin::signed(1), target.clone(), None));
+		assert_eq!(TransactionPause::pause_expiry(&target), None);
+		TransactionPause::on_initialize(11);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
+#[test]
+fn unpause_before_expiry_should_not_affect_later_pause() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+		let target = PauseTarget::Transaction(name(b"Balances"), name(b"transfer_allow_death"));
+
+		assert_ok!(TransactionPause::pause(
+			RuntimeOrigin::signed(1),
+			target.clone(),
+			Some(5)
+		));
+		assert_ok!(TransactionPause::unpause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer_allow_death".to_vec()
+		));
+		assert_eq!(TransactionPause::pause_expiry(&target), None);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer_allow_death".to_vec()
+		));
+
+		TransactionPause::on_initialize(6);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
@@ -11,6 +11,9 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn pause_transaction() -> Weight;
 	fn unpause_transaction() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn on_initialize(n: u32) -> Weight;
 }
 
 /// Weights for module_transaction_pause using the Acala node and recommended hardware.
@@ -37,4 +40,43 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TransactionPause::ExpiringPauses` (r:1 w:1)
+	/// Proof: `TransactionPause::ExpiringPauses` (`max_values`: None, `max_size`: Some(4726), added: 7201, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPause::PauseExpiry` (r:0 w:1)
+	/// Proof: `TransactionPause::PauseExpiry` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:0 w:1)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(17_958_000, 8191)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TransactionPause::PauseExpiry` (r:0 w:1)
+	/// Proof: `TransactionPause::PauseExpiry` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:1 w:1)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(16_114_000, 3559)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TransactionPause::ExpiringPauses` (r:1 w:1)
+	/// Proof: `TransactionPause::ExpiringPauses` (`max_values`: None, `max_size`: Some(4726), added: 7201, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPause::PauseExpiry` (r:50 w:50)
+	/// Proof: `TransactionPause::PauseExpiry` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:50 w:50)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 50]`.
+	fn on_initialize(n: u32, ) -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(4_263_000, 8191)
+			.saturating_add(Weight::from_parts(11_836_512, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2574).saturating_mul(n.into()))
+	}
 }
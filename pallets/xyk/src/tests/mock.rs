@@ -106,6 +106,15 @@ parameter_types! {
 	pub const FeesBeneficiarry: u64 = TREASURY;
 }
 
+parameter_types! {
+	#[derive(PartialEq, Debug)]
+	pub const MetadataFieldLimit: u32 = 64;
+	pub const MetadataDepositBase: Balance = 0;
+	pub const MetadataDepositPerByte: Balance = 0;
+	pub const RegistrationDeposit: Balance = 0;
+	pub const MinOwnedAssetExistentialDeposit: Balance = 1;
+}
+
 impl pallet_asset_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RegistryOrigin = EnsureSigned<AccountId>;
@@ -116,8 +125,15 @@ impl pallet_asset_registry::Config for Test {
 	type StringLimit = RegistryStringLimit;
 	type MinStringLimit = MinRegistryStringLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
+	type NativeAssetId = NativeAssetId;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
 	type RegisterAssetHook = ();
+	type DepositCurrency = orml_tokens::CurrencyAdapter<Test, NativeAssetId>;
+	type MetadataFieldLimit = MetadataFieldLimit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type RegistrationDeposit = RegistrationDeposit;
+	type MinOwnedAssetExistentialDeposit = MinOwnedAssetExistentialDeposit;
 	type WeightInfo = ();
 }
 
@@ -18,7 +18,7 @@
 use super::*;
 use crate::evm::precompiles::erc20_mapping::SetCodeForErc20Precompile;
 use crate::evm::Erc20Currency;
-use crate::origins::{EconomicParameters, GeneralAdmin, OmnipoolAdmin};
+use crate::origins::{EconomicParameters, GeneralAdmin, OmnipoolAdmin, Treasurer};
 use crate::system::NativeAssetId;
 use crate::Stableswap;
 use core::ops::RangeInclusive;
@@ -122,9 +122,9 @@ pub struct CurrencyHooks;
 impl MutationHooks<AccountId, AssetId, Balance> for CurrencyHooks {
 	type OnDust = Duster;
 	type OnSlash = ();
-	type PreDeposit = SufficiencyCheck;
+	type PreDeposit = (SufficiencyCheck, pallet_bonds::AccrueCoupons<Runtime>);
 	type PostDeposit = pallet_circuit_breaker::fuses::issuance::IssuanceIncreaseFuse<Runtime>;
-	type PreTransfer = SufficiencyCheck;
+	type PreTransfer = (SufficiencyCheck, pallet_bonds::AccrueCoupons<Runtime>);
 	type PostTransfer = ();
 	type OnNewTokenAccount = AddTxAssetOnAccount<Runtime>;
 	type OnKilledTokenAccount = (RemoveTxAssetOnKilled<Runtime>, OnKilledTokenAccount);
@@ -312,6 +312,11 @@ impl SufficiencyCheck {
 
 impl OnTransfer<AccountId, AssetId, Balance> for SufficiencyCheck {
 	fn on_transfer(asset: AssetId, from: &AccountId, to: &AccountId, _amount: Balance) -> DispatchResult {
+		//NOTE: transfers via `Currencies` are checked there already, this covers direct `Tokens` transfers.
+		if AssetRegistry::is_account_frozen(asset, from) {
+			return Err(pallet_asset_registry::Error::<Runtime>::AccountFrozen.into());
+		}
+
 		//This is mainly needed to disable charging any ED when we send the initial assetIn insufficient asset to the router account in the beginning of router trades
 		let router_account = pallet_route_executor::Pallet::<Runtime>::router_account();
 		if *to == <sp_runtime::AccountId32 as Into<AccountId>>::into(router_account) {
@@ -422,6 +427,7 @@ impl pallet_currencies::Config for Runtime {
 	type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 	type Erc20Currency = Erc20Currency<Runtime>;
 	type BoundErc20 = AssetRegistry;
+	type AccountFreeze = AssetRegistry;
 	type ReserveAccount = ReserveAccount;
 	type GetNativeCurrencyId = NativeAssetId;
 	type WeightInfo = weights::pallet_currencies::HydraWeight<Runtime>;
@@ -440,9 +446,7 @@ impl EnsureOrigin<RuntimeOrigin> for RootAsVestingPallet {
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
-		let zero_account_id = AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes())
-			.expect("infinite length input; no invalid inputs for type; qed");
-		Ok(RuntimeOrigin::from(RawOrigin::Signed(zero_account_id)))
+		Ok(RuntimeOrigin::root())
 	}
 }
 
@@ -462,6 +466,22 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxAssetVestingSchedules: u32 = 50;
+}
+
+impl pallet_asset_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type GrantorOrigin = RootAsVestingPallet;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type MaxVestingSchedules = MaxAssetVestingSchedules;
+	type WeightInfo = weights::pallet_asset_vesting::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = helpers::benchmark_helpers::AssetVestingBenchmarkHelper;
+}
+
 parameter_types! {
 	pub ClaimMessagePrefix: &'static [u8] = b"I hereby claim all my HDX tokens to wallet:";
 }
@@ -481,6 +501,12 @@ parameter_types! {
 	pub const MinRegistryStrLimit: u32 = 3;
 	pub const SequentialIdOffset: u32 = 1_000_000;
 	pub const RegExternalWeightMultiplier: u64 = 10;
+	#[derive(PartialEq, Debug)]
+	pub const RegistryMetadataFieldLimit: u32 = 128;
+	pub const RegistryMetadataDepositBase: Balance = 100 * UNITS;
+	pub const RegistryMetadataDepositPerByte: Balance = UNITS;
+	pub const RegistryRegistrationDeposit: Balance = 10_000 * UNITS;
+	pub const RegistryMinOwnedAssetExistentialDeposit: Balance = 1_000_000;
 }
 
 impl pallet_asset_registry::Config for Runtime {
@@ -493,8 +519,15 @@ impl pallet_asset_registry::Config for Runtime {
 	type StringLimit = RegistryStrLimit;
 	type MinStringLimit = MinRegistryStrLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
+	type NativeAssetId = NativeAssetId;
 	type RegExternalWeightMultiplier = RegExternalWeightMultiplier;
 	type RegisterAssetHook = SetCodeForErc20Precompile;
+	type DepositCurrency = Balances;
+	type MetadataFieldLimit = RegistryMetadataFieldLimit;
+	type MetadataDepositBase = RegistryMetadataDepositBase;
+	type MetadataDepositPerByte = RegistryMetadataDepositPerByte;
+	type RegistrationDeposit = RegistryRegistrationDeposit;
+	type MinOwnedAssetExistentialDeposit = RegistryMinOwnedAssetExistentialDeposit;
 	type WeightInfo = weights::pallet_asset_registry::HydraWeight<Runtime>;
 }
 
@@ -680,6 +713,25 @@ impl Contains<AccountId> for DustRemovalWhitelist {
 
 parameter_types! {
 	pub const DustingReward: u128 = 0;
+	pub const DusterMaxDustBatch: u32 = 20;
+	pub const DusterMaxOffchainScan: u32 = 500;
+}
+
+/// Balances of non-native assets scanned by the duster offchain worker.
+/// Native balances are not scanned, accounts below the native existential deposit are reaped by `pallet_balances`.
+pub struct TokenBalances;
+impl pallet_duster::AccountBalances<AccountId, AssetId, Balance> for TokenBalances {
+	fn balances(start: Option<(AccountId, AssetId)>, limit: u32) -> Vec<(AccountId, AssetId, Balance)> {
+		let iter = match start {
+			Some((who, asset)) => orml_tokens::Accounts::<Runtime>::iter_from(
+				orml_tokens::Accounts::<Runtime>::hashed_key_for(who, asset),
+			),
+			None => orml_tokens::Accounts::<Runtime>::iter(),
+		};
+		iter.take(limit as usize)
+			.map(|(who, asset, data)| (who, asset, data.total()))
+			.collect()
+	}
 }
 
 impl pallet_duster::Config for Runtime {
@@ -693,6 +745,9 @@ impl pallet_duster::Config for Runtime {
 	type NativeCurrencyId = NativeAssetId;
 	type BlacklistUpdateOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type TreasuryAccountId = TreasuryAccount;
+	type AccountBalances = TokenBalances;
+	type MaxDustBatch = DusterMaxDustBatch;
+	type MaxOffchainScan = DusterMaxOffchainScan;
 	type WeightInfo = weights::pallet_duster::HydraWeight<Runtime>;
 }
 
@@ -1549,6 +1604,7 @@ impl pallet_stableswap::Config for Runtime {
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
 	pub const BondsPalletId: PalletId = PalletId(*b"pltbonds");
+	pub const BondsMaxDiscountPoints: u32 = 10;
 }
 
 pub struct AssetTypeWhitelist;
@@ -1570,6 +1626,8 @@ impl pallet_bonds::Config for Runtime {
 	type AssetTypeWhitelist = AssetTypeWhitelist;
 	type ProtocolFee = ProtocolFee;
 	type FeeReceiver = TreasuryAccount;
+	type EarlyRedemptionOrigin = EitherOf<EnsureRoot<Self::AccountId>, Treasurer>;
+	type MaxDiscountPoints = BondsMaxDiscountPoints;
 	type WeightInfo = weights::pallet_bonds::HydraWeight<Runtime>;
 }
 
@@ -1592,6 +1650,9 @@ impl GetByKey<Action, u32> for PointsPerAction {
 	fn get(k: &Action) -> u32 {
 		match k {
 			Action::DemocracyVote => 100_u32,
+			// Not reported by any pallet yet.
+			Action::OmnipoolLiquidity => 0_u32,
+			Action::DcaSchedule => 0_u32,
 		}
 	}
 }
@@ -1606,6 +1667,7 @@ impl GetByKey<FixedU128, Point> for StakingMinSlash {
 
 parameter_types! {
 	pub const MaxVotes: u32 = 25;
+	pub const StakingMaxRewardAssets: u32 = 5;
 }
 
 impl pallet_staking::Config for Runtime {
@@ -1638,9 +1700,18 @@ impl pallet_staking::Config for Runtime {
 	type Vesting = VestingInfo<Runtime>;
 	type WeightInfo = weights::pallet_staking::HydraWeight<Runtime>;
 	type MinSlash = StakingMinSlash;
+	type MaxRewardAssets = StakingMaxRewardAssets;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type RouteExecutor = Router;
+	#[cfg(feature = "runtime-benchmarks")]
+	type RouteExecutor = pallet_route_executor::DummyRouter<Runtime>;
+	type RouteProvider = Router;
+	type AmmTradeWeights = RouterWeightInfo;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type MaxLocks = MaxLocks;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = helpers::benchmark_helpers::StakingBenchmarkHelper;
 }
 
 // LBP
@@ -1711,6 +1782,8 @@ parameter_types! {
 	pub const ReferralsOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 	pub const ReferralsSeedAmount: Balance = 10_000_000_000_000;
 	pub ReferralsExternalRewardAccount: Option<AccountId> = Some(StakingPalletId::get().into_account_truncating());
+	pub const ReferralsMaxTiers: u32 = 10;
+	pub const ReferralsVolumeHalfLife: BlockNumber = 90 * DAYS;
 }
 
 impl pallet_referrals::Config for Runtime {
@@ -1732,6 +1805,8 @@ impl pallet_referrals::Config for Runtime {
 	type LevelVolumeAndRewardPercentages = ReferralsLevelVolumeAndRewards;
 	type ExternalAccount = ReferralsExternalRewardAccount;
 	type SeedNativeAmount = ReferralsSeedAmount;
+	type MaxTiers = ReferralsMaxTiers;
+	type VolumeHalfLife = ReferralsVolumeHalfLife;
 	type WeightInfo = weights::pallet_referrals::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ReferralsBenchmarkHelper;
@@ -1739,6 +1814,7 @@ impl pallet_referrals::Config for Runtime {
 
 parameter_types! {
 	pub const LiquidationGasLimit: u64 = 4_000_000;
+	pub const MaxLiquidations: u32 = 5;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1781,6 +1857,7 @@ impl pallet_liquidation::Config for Runtime {
 	type HollarId = HOLLAR;
 	type FlashMinter = pallet_hsm::GetFlashMinterSupport<Runtime>;
 	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, GeneralAdmin>>;
+	type MaxLiquidations = MaxLiquidations;
 }
 
 impl pallet_broadcast::Config for Runtime {
@@ -1791,6 +1868,9 @@ parameter_types! {
 	pub const HsmGasLimit: u64 = 400_000;
 	pub const HsmPalletId: PalletId = PalletId(*b"py/hsmod");
 	pub const HOLLAR: AssetId = 222;
+	pub const HsmKeeperRewardShare: Permill = Permill::from_percent(10);
+	pub const HsmMaxArbitragesPerBlock: u32 = 1;
+	pub const HsmMaxSignedArbitragesPerBlock: u32 = 1;
 }
 
 impl pallet_hsm::Config for Runtime {
@@ -1807,6 +1887,9 @@ impl pallet_hsm::Config for Runtime {
 	type Evm = evm::Executor<Runtime>;
 	type EvmAccounts = EVMAccounts;
 	type GasLimit = HsmGasLimit;
+	type KeeperRewardShare = HsmKeeperRewardShare;
+	type MaxArbitragesPerBlock = HsmMaxArbitragesPerBlock;
+	type MaxSignedArbitragesPerBlock = HsmMaxSignedArbitragesPerBlock;
 	type GasWeightMapping = evm::FixedHydraGasWeightMapping<Runtime>;
 	type WeightInfo = weights::pallet_hsm::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
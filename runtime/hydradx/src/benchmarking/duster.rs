@@ -72,6 +72,13 @@ runtime_benchmarks! {
 		assert!(pallet_duster::Pallet::<Runtime>::blacklisted(&nondustable_account).is_none());
 	}
 
+	set_dust_reward{
+		let reward: Balance = 1_000_000_000_000;
+	}: { pallet_duster::Pallet::<Runtime>::set_dust_reward(RawOrigin::Root.into(), reward)? }
+	verify {
+		assert_eq!(pallet_duster::Pallet::<Runtime>::dust_reward(), reward);
+	}
+
 }
 
 #[cfg(test)]
@@ -26,6 +26,7 @@ use frame_benchmarking::BenchmarkError;
 use frame_support::assert_ok;
 use frame_support::dispatch::GetDispatchInfo;
 use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
 use hydradx_traits::evm::InspectEvmAccounts;
 use hydradx_traits::router::PoolType;
@@ -47,7 +48,7 @@ type Router<T> = pallet_route_executor::Pallet<T>;
 use hydradx_traits::router::AssetPair;
 use hydradx_traits::router::Trade;
 use hydradx_traits::OraclePeriod;
-use pallet_transaction_multi_payment::{DepositAll, PaymentInfo, TransferFees};
+use pallet_transaction_multi_payment::{CallFilter, CallFilterOf, DepositAll, PaymentInfo, TransferFees};
 
 const SEED: u32 = 1;
 
@@ -232,6 +233,83 @@ runtime_benchmarks! {
 	verify {
 		assert!(tx_result.is_ok());
 	}
+
+	set_fallback_currencies {
+		let c in 1 .. <Runtime as pallet_transaction_multi_payment::Config>::MaxFallbackCurrencies::get();
+
+		let caller: AccountId = account("caller", 0, SEED);
+
+		let mut currencies = vec![];
+		for i in 0..c {
+			let asset_id = register_asset(vec![b'F', b'C', b'0' + i as u8], 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+			add_as_accepted_currency(asset_id, Price::from(1)).map_err(|_| BenchmarkError::Stop("Failed to add supported currency"))?;
+			currencies.push(asset_id);
+		}
+		let currencies: BoundedVec<AssetId, _> = BoundedVec::truncate_from(currencies);
+	}: { MultiPaymentPallet::<Runtime>::set_fallback_currencies(RawOrigin::Signed(caller.clone()).into(), currencies.clone())? }
+	verify {
+		assert_eq!(MultiPaymentPallet::<Runtime>::fallback_currencies(caller), currencies);
+	}
+
+	register_sponsorship {
+		let sponsor: AccountId = account("sponsor", 0, SEED);
+		let calls = sponsored_calls();
+	}: { MultiPaymentPallet::<Runtime>::register_sponsorship(RawOrigin::Signed(sponsor.clone()).into(), calls, 1_000)? }
+	verify {
+		assert!(MultiPaymentPallet::<Runtime>::sponsorship(sponsor).is_some());
+	}
+
+	remove_sponsorship {
+		let sponsor: AccountId = account("sponsor", 0, SEED);
+		MultiPaymentPallet::<Runtime>::register_sponsorship(RawOrigin::Signed(sponsor.clone()).into(), sponsored_calls(), 1_000)?;
+	}: { MultiPaymentPallet::<Runtime>::remove_sponsorship(RawOrigin::Signed(sponsor.clone()).into())? }
+	verify {
+		assert!(MultiPaymentPallet::<Runtime>::sponsorship(sponsor).is_none());
+	}
+
+	sponsor_account {
+		let sponsor: AccountId = account("sponsor", 0, SEED);
+		let previous_sponsor: AccountId = account("sponsor", 1, SEED);
+		let user: AccountId = account("user", 0, SEED);
+
+		// Account sponsored by a removed sponsorship requires reading both sponsorships
+		MultiPaymentPallet::<Runtime>::register_sponsorship(RawOrigin::Signed(previous_sponsor.clone()).into(), sponsored_calls(), 1_000)?;
+		MultiPaymentPallet::<Runtime>::sponsor_account(RawOrigin::Signed(previous_sponsor.clone()).into(), user.clone(), UNITS)?;
+		MultiPaymentPallet::<Runtime>::remove_sponsorship(RawOrigin::Signed(previous_sponsor).into())?;
+
+		MultiPaymentPallet::<Runtime>::register_sponsorship(RawOrigin::Signed(sponsor.clone()).into(), sponsored_calls(), 1_000)?;
+	}: { MultiPaymentPallet::<Runtime>::sponsor_account(RawOrigin::Signed(sponsor.clone()).into(), user.clone(), UNITS)? }
+	verify {
+		assert_eq!(MultiPaymentPallet::<Runtime>::sponsored_account(user).map(|s| s.sponsor), Some(sponsor));
+	}
+
+	remove_sponsored_account {
+		let sponsor: AccountId = account("sponsor", 0, SEED);
+		let user: AccountId = account("user", 0, SEED);
+		let caller: AccountId = account("caller", 0, SEED);
+
+		MultiPaymentPallet::<Runtime>::register_sponsorship(RawOrigin::Signed(sponsor.clone()).into(), sponsored_calls(), 1_000)?;
+		MultiPaymentPallet::<Runtime>::sponsor_account(RawOrigin::Signed(sponsor.clone()).into(), user.clone(), UNITS)?;
+		MultiPaymentPallet::<Runtime>::remove_sponsorship(RawOrigin::Signed(sponsor).into())?;
+	}: { MultiPaymentPallet::<Runtime>::remove_sponsored_account(RawOrigin::Signed(caller).into(), user.clone())? }
+	verify {
+		assert!(MultiPaymentPallet::<Runtime>::sponsored_account(user).is_none());
+	}
+}
+
+fn sponsored_calls(
+) -> BoundedVec<CallFilterOf<Runtime>, <Runtime as pallet_transaction_multi_payment::Config>::MaxSponsoredCalls> {
+	let name_length = <Runtime as pallet_transaction_multi_payment::Config>::MaxCallNameLength::get() as usize;
+	let max_calls = <Runtime as pallet_transaction_multi_payment::Config>::MaxSponsoredCalls::get();
+
+	let calls = (0..max_calls)
+		.map(|i| CallFilter {
+			pallet: BoundedVec::truncate_from(vec![i as u8; name_length]),
+			call: Some(BoundedVec::truncate_from(vec![i as u8; name_length])),
+		})
+		.collect::<Vec<_>>();
+
+	BoundedVec::truncate_from(calls)
 }
 
 fn create_xyk_pool<T: pallet_xyk::Config>(asset_a: AssetId, amount_a: Balance, asset_b: AssetId, amount_b: Balance)
@@ -46,8 +46,11 @@ use orml_tokens::CurrencyAdapter;
 use pallet_currencies::fungibles::FungibleCurrencies;
 use pallet_evm::{EnsureAddressOrigin, FrameSystemAccountProvider};
 use pallet_transaction_payment::Multiplier;
-use primitives::{constants::chain::MAXIMUM_BLOCK_WEIGHT, AssetId};
-use sp_arithmetic::FixedU128;
+use primitives::{
+	constants::{chain::MAXIMUM_BLOCK_WEIGHT, currency::deposit},
+	AssetId, Balance,
+};
+use sp_arithmetic::{FixedU128, Permill};
 use sp_core::{crypto::AccountId32, Get, U256};
 
 pub mod aave_trade_executor;
@@ -64,6 +67,7 @@ pub use erc20_currency::Erc20Currency;
 pub use erc20_currency::Function;
 pub use executor::Executor;
 pub use primitives::AccountId as AccountIdType;
+pub use runner::WrapRunnerError;
 
 // Current approximation of the gas per second consumption considering
 // EVM execution over compiled WASM (on 4.4Ghz CPU).
@@ -226,11 +230,20 @@ impl pallet_evm_accounts::EvmNonceProvider for EvmNonceProvider {
 
 type EvmAccounts<T> = pallet_evm_accounts::Pallet<T>;
 
+parameter_types! {
+	// Deposit for the bound account, EVM address and deposit entries
+	pub EvmAccountsBindingDeposit: Balance = deposit(3, 168);
+}
+
 impl pallet_evm_accounts::Config for Runtime {
 	type RuntimeEvent = crate::RuntimeEvent;
 	type EvmNonceProvider = EvmNonceProvider;
 	type ControllerOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type FeeMultiplier = sp_core::ConstU32<50>;
+	type ChainId = crate::EVMChainId;
+	type MaxAuditReferenceLength = sp_core::ConstU32<256>;
+	type DepositCurrency = crate::Balances;
+	type BindingDeposit = EvmAccountsBindingDeposit;
 	type WeightInfo = crate::weights::pallet_evm_accounts::HydraWeight<Runtime>;
 }
 
@@ -250,6 +263,9 @@ parameter_types! {
 	pub const DefaultBaseFeePerGas: u128 = DEFAULT_BASE_FEE_PER_GAS;
 	pub const MinBaseFeePerGas: u128 = DEFAULT_BASE_FEE_PER_GAS.saturating_div(10);
 	pub const MaxBaseFeePerGas: u128 = 14415000000; //To reach 10 dollar per omnipool trade
+	// Ethereum RPC fee history estimates the next base fee assuming 50% target and this elasticity
+	pub const TargetGasUtilization: Permill = Permill::from_percent(50);
+	pub const BaseFeeElasticity: Permill = Permill::from_parts(125_000);
 }
 
 impl pallet_dynamic_evm_fee::Config for Runtime {
@@ -261,5 +277,8 @@ impl pallet_dynamic_evm_fee::Config for Runtime {
 	type NativePriceOracle = FeePriceOracle;
 	type WethAssetId = WethAssetId;
 	type BaseFeePerGasMultiplier = BaseFeePerGasMultiplier;
+	type BlockGasLimit = BlockGasLimit;
+	type TargetGasUtilization = TargetGasUtilization;
+	type Elasticity = BaseFeeElasticity;
 	type WeightInfo = crate::weights::pallet_dynamic_evm_fee::HydraWeight<Runtime>;
 }
@@ -21,32 +21,93 @@
 //! EVM stack-based runner.
 //! This runner is a wrapper around the default stack-based runner that adds possibility to charge fees in
 //! different currencies and to validate transactions based on the account's fee payment asset.
+//! Contract creation is allowed only for whitelisted deployers or for contracts with approved init code hash,
+//! otherwise it fails with `AddressNotWhitelisted` error of `pallet_evm_accounts`.
+//! Gas used by every successful execution is noted for the EIP-1559 base fee adjustment.
 //!
 //! Shamelessly copied from pallet-evm and modified to support multi-currency fees.
 use crate::evm::WethAssetId;
 use fp_evm::{Account, TransactionValidationError};
 use frame_support::traits::Get;
+use hydradx_traits::evm::InspectEvmAccounts;
 use hydradx_traits::AccountFeeCurrencyBalanceInCurrency;
 use pallet_evm::runner::Runner;
 use pallet_evm::{AccountProvider, AddressMapping, CallInfo, Config, CreateInfo, FeeCalculator, RunnerError};
 use pallet_genesis_history::migration::Weight;
 use primitive_types::{H160, H256, U256};
 use primitives::{AssetId, Balance};
+use sp_io::hashing::keccak_256;
 use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{DispatchError, RuntimeDebug};
 use sp_std::vec::Vec;
 
+/// Error of the `WrapRunner`.
+#[derive(RuntimeDebug)]
+pub enum WrapRunnerError<E> {
+	/// Error of the wrapped runner.
+	Runner(E),
+	/// Error of `pallet_evm_accounts`, e.g. `AddressNotWhitelisted` when the contract can't be deployed.
+	EvmAccounts(DispatchError),
+}
+
+impl<E: From<TransactionValidationError>> From<TransactionValidationError> for WrapRunnerError<E> {
+	fn from(error: TransactionValidationError) -> Self {
+		Self::Runner(error.into())
+	}
+}
+
+impl<E: Into<DispatchError>> From<WrapRunnerError<E>> for DispatchError {
+	fn from(error: WrapRunnerError<E>) -> Self {
+		match error {
+			WrapRunnerError::Runner(error) => error.into(),
+			WrapRunnerError::EvmAccounts(error) => error,
+		}
+	}
+}
+
+/// Converts error of the wrapped runner to the error of the `WrapRunner`.
+fn runner_error<E: Into<DispatchError>>(error: RunnerError<E>) -> RunnerError<WrapRunnerError<E>> {
+	RunnerError {
+		error: WrapRunnerError::Runner(error.error),
+		weight: error.weight,
+	}
+}
+
 pub struct WrapRunner<T, R, B>(sp_std::marker::PhantomData<(T, R, B)>);
 
+impl<T, R, B> WrapRunner<T, R, B>
+where
+	T: Config + pallet_evm_accounts::Config,
+	R: Runner<T>,
+	<R as pallet_evm::Runner<T>>::Error: core::convert::From<TransactionValidationError>,
+	pallet_evm_accounts::Pallet<T>: InspectEvmAccounts<T::AccountId>,
+{
+	/// Contract can be deployed by a whitelisted deployer or by any address if its init code hash is approved.
+	fn ensure_can_deploy(source: H160, init: &[u8]) -> Result<(), RunnerError<WrapRunnerError<R::Error>>> {
+		if pallet_evm_accounts::Pallet::<T>::can_deploy_contracts(source)
+			|| pallet_evm_accounts::Pallet::<T>::is_approved_contract_code(H256::from(keccak_256(init)))
+		{
+			return Ok(());
+		}
+
+		Err(RunnerError {
+			error: WrapRunnerError::EvmAccounts(pallet_evm_accounts::Error::<T>::AddressNotWhitelisted.into()),
+			weight: T::DbWeight::get().reads(2),
+		})
+	}
+}
+
 impl<T, R, B> Runner<T> for WrapRunner<T, R, B>
 where
-	T: Config + pallet_dispatcher::Config,
+	T: Config + pallet_dispatcher::Config + pallet_evm_accounts::Config + pallet_dynamic_evm_fee::Config,
 	R: Runner<T>,
 	<R as pallet_evm::Runner<T>>::Error: core::convert::From<TransactionValidationError>,
 	B: AccountFeeCurrencyBalanceInCurrency<AssetId, T::AccountId, Output = (Balance, Weight)>,
 	T::AddressMapping: pallet_evm::AddressMapping<T::AccountId>,
 	pallet_evm::AccountIdOf<T>: From<T::AccountId>,
+	pallet_evm_accounts::Pallet<T>: InspectEvmAccounts<T::AccountId>,
 {
-	type Error = R::Error;
+	type Error = WrapRunnerError<R::Error>;
 
 	fn validate(
 		source: H160,
@@ -84,11 +145,11 @@ where
 				evm_config,
 				block_gas_limit: T::BlockGasLimit::get(),
 				base_fee,
-				chain_id: T::ChainId::get(),
+				chain_id: <T as Config>::ChainId::get(),
 				is_transactional,
 			},
 			fp_evm::CheckEvmTransactionInput {
-				chain_id: Some(T::ChainId::get()),
+				chain_id: Some(<T as Config>::ChainId::get()),
 				to: target,
 				input,
 				nonce: nonce.unwrap_or(source_account.nonce),
@@ -158,10 +219,12 @@ where
 			weight_limit,
 			proof_size_base_cost,
 			config,
-		)?;
+		)
+		.map_err(runner_error)?;
 
 		// Store the exit reason for the last EVM call
 		pallet_dispatcher::Pallet::<T>::set_last_evm_call_exit_reason(&result.exit_reason);
+		pallet_dynamic_evm_fee::Pallet::<T>::note_gas_used(result.used_gas.effective);
 
 		Ok(result)
 	}
@@ -181,6 +244,8 @@ where
 		proof_size_base_cost: Option<u64>,
 		config: &evm::Config,
 	) -> Result<CreateInfo, RunnerError<Self::Error>> {
+		Self::ensure_can_deploy(source, &init)?;
+
 		if validate {
 			Self::validate(
 				source,
@@ -199,7 +264,7 @@ where
 			)?;
 		}
 		// Validated, flag set to false
-		R::create(
+		let result = R::create(
 			source,
 			init,
 			value,
@@ -214,6 +279,11 @@ where
 			proof_size_base_cost,
 			config,
 		)
+		.map_err(runner_error)?;
+
+		pallet_dynamic_evm_fee::Pallet::<T>::note_gas_used(result.used_gas.effective);
+
+		Ok(result)
 	}
 
 	fn create2(
@@ -232,6 +302,8 @@ where
 		proof_size_base_cost: Option<u64>,
 		config: &evm::Config,
 	) -> Result<CreateInfo, RunnerError<Self::Error>> {
+		Self::ensure_can_deploy(source, &init)?;
+
 		if validate {
 			Self::validate(
 				source,
@@ -250,7 +322,7 @@ where
 			)?;
 		}
 		//Validated, flag set to false
-		R::create2(
+		let result = R::create2(
 			source,
 			init,
 			salt,
@@ -266,6 +338,11 @@ where
 			proof_size_base_cost,
 			config,
 		)
+		.map_err(runner_error)?;
+
+		pallet_dynamic_evm_fee::Pallet::<T>::note_gas_used(result.used_gas.effective);
+
+		Ok(result)
 	}
 
 	fn create_force_address(
@@ -302,7 +379,7 @@ where
 			)?;
 		}
 		//Validated, flag set to false
-		R::create_force_address(
+		let result = R::create_force_address(
 			source,
 			init,
 			value,
@@ -318,5 +395,10 @@ where
 			config,
 			contract_address,
 		)
+		.map_err(runner_error)?;
+
+		pallet_dynamic_evm_fee::Pallet::<T>::note_gas_used(result.used_gas.effective);
+
+		Ok(result)
 	}
 }
@@ -138,6 +138,50 @@ pub mod benchmark_helpers {
 		}
 	}
 
+	pub struct StakingBenchmarkHelper;
+
+	impl pallet_staking::traits::BenchmarkHelper<AssetId> for StakingBenchmarkHelper {
+		fn register_reward_asset() -> Result<AssetId, sp_runtime::DispatchError> {
+			let asset_name: BoundedVec<u8, RegistryStrLimit> =
+				b"STKR".to_vec().try_into().map_err(|_| "BoundedConversionFailed")?;
+
+			with_transaction(|| {
+				TransactionOutcome::Commit(AssetRegistry::register_sufficient_asset(
+					None,
+					Some(asset_name.clone()),
+					AssetKind::Token,
+					1,
+					None,
+					None,
+					None,
+					None,
+				))
+			})
+		}
+	}
+
+	pub struct AssetVestingBenchmarkHelper;
+
+	impl pallet_asset_vesting::BenchmarkHelper<AssetId> for AssetVestingBenchmarkHelper {
+		fn register_asset() -> Result<AssetId, sp_runtime::DispatchError> {
+			let asset_name: BoundedVec<u8, RegistryStrLimit> =
+				b"VEST".to_vec().try_into().map_err(|_| "BoundedConversionFailed")?;
+
+			with_transaction(|| {
+				TransactionOutcome::Commit(AssetRegistry::register_sufficient_asset(
+					None,
+					Some(asset_name.clone()),
+					AssetKind::Token,
+					1,
+					None,
+					None,
+					None,
+					None,
+				))
+			})
+		}
+	}
+
 	pub struct CircuitBreakerBenchmarkHelper<T>(PhantomData<T>);
 
 	impl<T: pallet_circuit_breaker::Config> pallet_circuit_breaker::types::BenchmarkHelper<AccountId, AssetId, Balance>
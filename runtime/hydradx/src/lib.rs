@@ -120,7 +120,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 347,
+	spec_version: 371,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -201,6 +201,7 @@ construct_runtime!(
 		Liquidation: pallet_liquidation = 76,
 		HSM: pallet_hsm = 82,
 		Parameters: pallet_parameters = 83,
+		AssetVesting: pallet_asset_vesting = 84,
 
 		// ORML related modules
 		Tokens: orml_tokens = 77,
@@ -345,6 +346,7 @@ mod benches {
 		[pallet_dispatcher, Dispatcher]
 		[pallet_hsm, HSM]
 		[pallet_dynamic_fees, DynamicFees]
+		[pallet_asset_vesting, AssetVesting]
 	);
 }
 
@@ -879,8 +881,10 @@ impl_runtime_apis! {
 				return Err(pallet_evm_accounts::Error::<Runtime>::BoundAddressCannotBeUsed.into())
 			};
 
-			// the address needs to have a permission to deploy smart contract
-			if !EVMAccounts::can_deploy_contracts(from) {
+			// the address needs to have a permission to deploy smart contract or the contract code needs to be approved
+			if !EVMAccounts::can_deploy_contracts(from)
+				&& !EVMAccounts::is_approved_contract_code(H256::from(sp_io::hashing::keccak_256(&data)))
+			{
 				return Err(pallet_evm_accounts::Error::<Runtime>::AddressNotWhitelisted.into())
 			};
 
@@ -939,7 +943,7 @@ impl_runtime_apis! {
 		}
 
 		fn elasticity() -> Option<Permill> {
-			None
+			Some(<Runtime as pallet_dynamic_evm_fee::Config>::Elasticity::get())
 		}
 
 		fn gas_limit_multiplier_support() {}
@@ -980,6 +984,97 @@ impl_runtime_apis! {
 		fn account_id(evm_address: H160) -> AccountId {
 			EVMAccounts::account_id(evm_address)
 		}
+		fn binding_nonce(evm_address: H160) -> u64 {
+			EVMAccounts::binding_nonce(evm_address)
+		}
+	}
+
+	impl pallet_staking_rpc_runtime_api::StakingApi<Block, AccountId, u128, BlockNumber> for Runtime {
+		fn position_rewards(position_id: u128) -> Option<pallet_staking_rpc_runtime_api::PositionRewards> {
+			Staking::position_rewards(position_id)
+		}
+		fn account_rewards(who: AccountId) -> Option<(u128, pallet_staking_rpc_runtime_api::PositionRewards)> {
+			let position_id = Staking::get_user_position_id(&who).ok()??;
+			Staking::position_rewards(position_id).map(|rewards| (position_id, rewards))
+		}
+		fn payable_percentage_projection(
+			position_id: u128,
+			blocks: Vec<BlockNumber>,
+			additional_votes: Vec<pallet_staking_rpc_runtime_api::Vote>,
+		) -> Option<Vec<pallet_staking_rpc_runtime_api::PayablePercentageProjection<BlockNumber>>> {
+			Staking::payable_percentage_projection(position_id, blocks, additional_votes)
+		}
+	}
+
+	impl pallet_referrals_rpc_runtime_api::ReferralsApi<Block, AccountId> for Runtime {
+		fn referrer_info(who: AccountId) -> Option<pallet_referrals_rpc_runtime_api::ReferrerInfo> {
+			use hydradx_traits::router::{AssetPair, RouteProvider, RouterT};
+			let reward_asset = <Runtime as pallet_referrals::Config>::RewardAsset::get();
+			Referrals::referrer_info(&who, |asset_id, amount| {
+				let route = Router::get_route(AssetPair::new(asset_id, reward_asset));
+				<Router as RouterT<_, _, _, _, _>>::calculate_sell_trade_amounts(&route, amount)
+					.ok()?
+					.last()
+					.map(|trade_amounts| trade_amounts.amount_out)
+			})
+		}
+	}
+
+	impl pallet_bonds_rpc_runtime_api::BondsApi<Block, AssetId, Balance> for Runtime {
+		fn early_redemption_price(bond_id: AssetId) -> Option<pallet_bonds_rpc_runtime_api::Permill> {
+			Bonds::early_redemption_price(bond_id)
+		}
+		fn early_redemption_amount(bond_id: AssetId, amount: Balance) -> Option<Balance> {
+			Bonds::early_redemption_amount(bond_id, amount)
+		}
+	}
+
+	impl pallet_duster_rpc_runtime_api::DusterApi<Block, AccountId, AssetId, Balance> for Runtime {
+		fn dustable_accounts(start: Option<(AccountId, AssetId)>, limit: u32) -> Vec<(AccountId, AssetId, Balance)> {
+			Duster::dustable_accounts(start, limit)
+		}
+	}
+
+	impl pallet_transaction_multi_payment_rpc_runtime_api::MultiPaymentApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn remaining_sponsorship(account: AccountId) -> Option<(AccountId, Balance, BlockNumber)> {
+			MultiTransactionPayment::remaining_sponsorship(&account)
+		}
+	}
+
+	impl pallet_asset_registry_rpc_runtime_api::AssetRegistryApi<Block, AssetId, AccountId, pallet_asset_registry::ExtendedMetadataOf<Runtime>> for Runtime {
+		fn extended_metadata(asset_ids: Vec<AssetId>) -> Vec<(AssetId, Option<pallet_asset_registry::ExtendedMetadataOf<Runtime>>)> {
+			AssetRegistry::extended_metadata_of(asset_ids)
+		}
+
+		fn is_account_frozen(asset_id: AssetId, account: AccountId) -> bool {
+			AssetRegistry::is_account_frozen(asset_id, &account)
+		}
+
+		fn frozen_accounts(asset_id: AssetId) -> Vec<AccountId> {
+			AssetRegistry::frozen_accounts_of(asset_id)
+		}
+	}
+
+	impl pallet_asset_vesting_rpc_runtime_api::AssetVestingApi<Block, AccountId, AssetId, Balance, pallet_asset_vesting::VestingScheduleOf<Runtime>> for Runtime {
+		fn vesting_schedules(account: AccountId) -> Vec<(AssetId, Vec<pallet_asset_vesting::VestingScheduleOf<Runtime>>)> {
+			AssetVesting::schedules_of(&account)
+		}
+
+		fn locked_balance(account: AccountId, asset_id: AssetId) -> Balance {
+			AssetVesting::locked_balance(&account, asset_id)
+		}
+	}
+
+	impl pallet_hsm_rpc_runtime_api::HsmApi<Block, AssetId> for Runtime {
+		fn collaterals() -> Vec<pallet_hsm_rpc_runtime_api::CollateralState<AssetId>> {
+			HSM::collaterals_state()
+		}
+		fn collateral(asset_id: AssetId) -> Option<pallet_hsm_rpc_runtime_api::CollateralState<AssetId>> {
+			HSM::collateral_state(asset_id, HSM::get_hsm_bucket_free_capacity()).ok()
+		}
+		fn free_bucket_capacity() -> Balance {
+			HSM::get_hsm_bucket_free_capacity()
+		}
 	}
 
 	impl evm::precompiles::erc20_mapping::Erc20MappingApi<Block> for Runtime {
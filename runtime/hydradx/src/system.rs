@@ -39,8 +39,8 @@ use frame_support::{
 		FixedPointNumber, Perbill, Perquintill, RuntimeDebug,
 	},
 	traits::{
-		fungible::HoldConsideration, ConstBool, Contains, EitherOf, InstanceFilter, LinearStoragePrice, PrivilegeCmp,
-		SortedMembers,
+		fungible::HoldConsideration, ConstBool, Contains, EitherOf, InstanceFilter, LinearStoragePrice,
+		PalletInfoAccess, PrivilegeCmp, SortedMembers,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, RocksDbWeight},
@@ -50,6 +50,7 @@ use frame_support::{
 };
 use frame_system::EnsureRoot;
 use hydradx_adapters::{OraclePriceProvider, RelayChainBlockNumberProvider};
+use hydradx_traits::router::{AssetPair, RouteProvider, Trade};
 use pallet_broadcast::types::ExecutionType;
 use pallet_utility::BatchHook;
 use scale_info::TypeInfo;
@@ -283,7 +284,7 @@ impl cumulus_pallet_aura_ext::Config for Runtime {}
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
-	type EventHandler = (CollatorSelection,);
+	type EventHandler = (CollatorSelection, CollatorRewards);
 }
 
 parameter_types! {
@@ -607,6 +608,12 @@ impl pallet_transaction_payment::Config for Runtime {
 	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
 }
 
+parameter_types! {
+	pub const MultiPaymentMaxSponsoredCalls: u32 = 10;
+	pub const MultiPaymentMaxCallNameLength: u32 = 64;
+	pub const MultiPaymentMaxFallbackCurrencies: u32 = 5;
+}
+
 impl pallet_transaction_multi_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AcceptedCurrencyOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
@@ -622,6 +629,9 @@ impl pallet_transaction_multi_payment::Config for Runtime {
 	type EvmPermit = evm::permit::EvmPermitHandler<Runtime>;
 	type TryCallCurrency<'a> = pallet_transaction_multi_payment::TryCallCurrency<Runtime>;
 	type SwappablePaymentAssetSupport = assets::XykPaymentAssetSupport;
+	type MaxSponsoredCalls = MultiPaymentMaxSponsoredCalls;
+	type MaxCallNameLength = MultiPaymentMaxCallNameLength;
+	type MaxFallbackCurrencies = MultiPaymentMaxFallbackCurrencies;
 }
 
 impl pallet_relaychain_info::Config for Runtime {
@@ -631,6 +641,7 @@ impl pallet_relaychain_info::Config for Runtime {
 
 parameter_types! {
 	pub const RewardPerCollator: Balance = 455_371_584_699_000; // 83333 HDX / 183 sessions
+	pub const CollatorMinUptime: Perbill = Perbill::from_percent(50);
 	//GalacticCouncil collators
 	pub ExcludedCollators: Vec<AccountId> = vec![
 		// 5G3t6yhAonQHGUEqrByWQPgP9R8fcSSL6Vujphc89ysdTpKF
@@ -652,6 +663,7 @@ impl pallet_collator_rewards::Config for Runtime {
 	type CurrencyId = AssetId;
 	type Currency = Currencies;
 	type RewardPerCollator = RewardPerCollator;
+	type MinUptime = CollatorMinUptime;
 	type RewardCurrencyId = NativeAssetId;
 	type RewardsBag = TreasuryAccount;
 	type ExcludedCollators = ExcludedCollators;
@@ -661,9 +673,122 @@ impl pallet_collator_rewards::Config for Runtime {
 	type MaxCandidates = MaxInvulnerables;
 }
 
+/// Provides assets traded by a call, used by asset scoped transaction pauses.
+///
+/// Router trades and DCA schedules include every asset of the route, the stored route is used
+/// when the route is not provided.
+///
+/// NOTE: Only dispatched calls are checked. Trades of DCA schedules executed in `on_initialize`
+/// are not dispatched calls, so they are not stopped by pausing a trade call for an asset.
+/// Pausing `DCA::schedule` for the asset only prevents new schedules.
+pub struct TradeCallAssetIds;
+impl pallet_transaction_pause::CallAssetIds<RuntimeCall, AssetId> for TradeCallAssetIds {
+	fn asset_ids(call: &RuntimeCall) -> Vec<AssetId> {
+		let (asset_in, asset_out) = match call {
+			RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::Omnipool(pallet_omnipool::Call::buy {
+				asset_in, asset_out, ..
+			}) => (asset_in, asset_out),
+			RuntimeCall::Router(pallet_route_executor::Call::sell {
+				asset_in,
+				asset_out,
+				route,
+				..
+			})
+			| RuntimeCall::Router(pallet_route_executor::Call::buy {
+				asset_in,
+				asset_out,
+				route,
+				..
+			})
+			| RuntimeCall::Router(pallet_route_executor::Call::sell_all {
+				asset_in,
+				asset_out,
+				route,
+				..
+			}) => {
+				let route = if route.is_empty() {
+					Router::get_route(AssetPair::new(*asset_in, *asset_out))
+				} else {
+					route.clone()
+				};
+				return route_asset_ids(*asset_in, *asset_out, &route);
+			}
+			RuntimeCall::DCA(pallet_dca::Call::schedule { schedule, .. }) => {
+				let route = schedule.order.get_route_or_default::<Router>();
+				return route_asset_ids(schedule.order.get_asset_in(), schedule.order.get_asset_out(), &route);
+			}
+			RuntimeCall::Stableswap(pallet_stableswap::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::Stableswap(pallet_stableswap::Call::buy {
+				asset_in, asset_out, ..
+			}) => (asset_in, asset_out),
+			RuntimeCall::XYK(pallet_xyk::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::XYK(pallet_xyk::Call::buy {
+				asset_in, asset_out, ..
+			}) => (asset_in, asset_out),
+			RuntimeCall::LBP(pallet_lbp::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::LBP(pallet_lbp::Call::buy {
+				asset_in, asset_out, ..
+			}) => (asset_in, asset_out),
+			_ => return vec![],
+		};
+		vec![*asset_in, *asset_out]
+	}
+}
+
+/// Returns `asset_in`, `asset_out` and all assets traded in the `route` without duplicates.
+fn route_asset_ids(asset_in: AssetId, asset_out: AssetId, route: &[Trade<AssetId>]) -> Vec<AssetId> {
+	let mut asset_ids = vec![asset_in, asset_out];
+	for trade in route {
+		for asset_id in [trade.asset_in, trade.asset_out] {
+			if !asset_ids.contains(&asset_id) {
+				asset_ids.push(asset_id);
+			}
+		}
+	}
+	asset_ids
+}
+
+parameter_types! {
+	pub const MaxExpiringPausesPerBlock: u32 = 50;
+}
+
+/// Governance and system pallets which can't be paused as a whole.
+pub struct UnpausablePallets;
+impl Contains<pallet_transaction_pause::BoundedName> for UnpausablePallets {
+	fn contains(pallet_name: &pallet_transaction_pause::BoundedName) -> bool {
+		[
+			<System as PalletInfoAccess>::name(),
+			<Timestamp as PalletInfoAccess>::name(),
+			<ParachainSystem as PalletInfoAccess>::name(),
+			<Scheduler as PalletInfoAccess>::name(),
+			<Preimage as PalletInfoAccess>::name(),
+			<Democracy as PalletInfoAccess>::name(),
+			<TechnicalCommittee as PalletInfoAccess>::name(),
+			<ConvictionVoting as PalletInfoAccess>::name(),
+			<Referenda as PalletInfoAccess>::name(),
+			<Whitelist as PalletInfoAccess>::name(),
+		]
+		.iter()
+		.any(|name| name.as_bytes() == pallet_name.as_slice())
+	}
+}
+
 impl pallet_transaction_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeSuperMajority, GeneralAdmin>>;
+	type AssetId = AssetId;
+	type CallAssetIds = TradeCallAssetIds;
+	type UnpausablePallets = UnpausablePallets;
+	type MaxExpiringPausesPerBlock = MaxExpiringPausesPerBlock;
 	type WeightInfo = weights::pallet_transaction_pause::HydraWeight<Runtime>;
 }
 
@@ -4,6 +4,7 @@ pub mod frame_system;
 pub mod orml_tokens;
 pub mod orml_vesting;
 pub mod pallet_asset_registry;
+pub mod pallet_asset_vesting;
 pub mod pallet_balances;
 pub mod pallet_bonds;
 pub mod pallet_circuit_breaker;
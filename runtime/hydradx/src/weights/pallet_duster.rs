@@ -106,4 +106,11 @@ impl<T: frame_system::Config> pallet_duster::WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Duster::DustReward` (r:0 w:1)
+	/// Proof: `Duster::DustReward` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_dust_reward() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(20_702_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
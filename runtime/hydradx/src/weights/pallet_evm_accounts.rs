@@ -131,4 +131,70 @@ impl<T: frame_system::Config> pallet_evm_accounts::WeightInfo for HydraWeight<T>
 		Weight::from_parts(10_822_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `EVMAccounts::BindingNonce` (r:1 w:1)
+	/// Proof: `EVMAccounts::BindingNonce` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::AccountExtension` (r:2 w:0)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundAccount` (r:2 w:1)
+	/// Proof: `EVMAccounts::BoundAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundEvmAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::BoundEvmAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BindingDepositOf` (r:0 w:1)
+	/// Proof: `EVMAccounts::BindingDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn bind_evm_address_with_signature() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(112_389_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `EVMAccounts::BindingNonce` (r:1 w:1)
+	/// Proof: `EVMAccounts::BindingNonce` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::AccountExtension` (r:2 w:1)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundAccount` (r:2 w:1)
+	/// Proof: `EVMAccounts::BoundAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundEvmAddress` (r:1 w:2)
+	/// Proof: `EVMAccounts::BoundEvmAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BindingDepositOf` (r:1 w:2)
+	/// Proof: `EVMAccounts::BindingDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn rebind_evm_address() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(69_547_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `EVMAccounts::ApprovedContractCode` (r:0 w:1)
+	/// Proof: `EVMAccounts::ApprovedContractCode` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	fn approve_contract_code() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(11_702_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EVMAccounts::ApprovedContractCode` (r:0 w:1)
+	/// Proof: `EVMAccounts::ApprovedContractCode` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	fn disapprove_contract_code() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(10_478_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EVMAccounts::BoundEvmAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::BoundEvmAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::AccountExtension` (r:1 w:1)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BoundAccount` (r:0 w:1)
+	/// Proof: `EVMAccounts::BoundAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::BindingDepositOf` (r:1 w:1)
+	/// Proof: `EVMAccounts::BindingDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn unbind_evm_address() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(24_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
@@ -169,4 +169,64 @@ impl<T: frame_system::Config> pallet_referrals::WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:2)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Referrer` (r:2 w:2)
+	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::TransferredCodes` (r:1 w:2)
+	/// Proof: `Referrals::TransferredCodes` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeGeneration` (r:1 w:0)
+	/// Proof: `Referrals::CodeGeneration` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferrerVolumeUpdatedAt` (r:1 w:2)
+	/// Proof: `Referrals::ReferrerVolumeUpdatedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:0 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	fn transfer_code() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(32_105_000, 6070)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Referrals::CodeLifetime` (r:0 w:1)
+	/// Proof: `Referrals::CodeLifetime` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_code_lifetime() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(8_493_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:0)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeLifetime` (r:1 w:0)
+	/// Proof: `Referrals::CodeLifetime` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeExpiry` (r:1 w:1)
+	/// Proof: `Referrals::CodeExpiry` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	fn renew_code() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(62_630_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Referrals::ReferralCodes` (r:1 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeExpiry` (r:1 w:1)
+	/// Proof: `Referrals::CodeExpiry` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::CodeGeneration` (r:1 w:1)
+	/// Proof: `Referrals::CodeGeneration` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralAccounts` (r:0 w:1)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	fn reclaim_code() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(26_318_000, 3524)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Referrals::AssetTierSchedules` (r:0 w:1)
+	/// Proof: `Referrals::AssetTierSchedules` (`max_values`: None, `max_size`: Some(301), added: 2776, mode: `MaxEncodedLen`)
+	fn set_tier_schedule() -> Weight {
+		// Placeholder, not produced by a benchmark run. Needs to be regenerated.
+		Weight::from_parts(11_240_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
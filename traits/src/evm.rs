@@ -1,5 +1,5 @@
 use codec::{Decode, Encode};
-use frame_support::sp_runtime::app_crypto::sp_core::{H160, U256};
+use frame_support::sp_runtime::app_crypto::sp_core::{H160, H256, U256};
 use frame_support::sp_runtime::{DispatchResult, RuntimeDebug};
 use sp_std::vec::Vec;
 pub trait InspectEvmAccounts<AccountId> {
@@ -24,6 +24,9 @@ pub trait InspectEvmAccounts<AccountId> {
 
 	/// Returns `True` if the address is allowed to manage balances and tokens.
 	fn is_approved_contract(address: EvmAddress) -> bool;
+
+	/// Returns `True` if contracts with the given init code hash can be deployed by any address.
+	fn is_approved_contract_code(code_hash: H256) -> bool;
 }
 
 pub type EvmAddress = H160;
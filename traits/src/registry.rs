@@ -188,3 +188,15 @@ pub trait RegisterAssetHook<AssetId> {
 impl<AssetId> RegisterAssetHook<AssetId> for () {
 	fn on_register_asset(_: AssetId) {}
 }
+
+/// Account-level freeze of an asset balance.
+pub trait AccountFreeze<AssetId, AccountId> {
+	/// Returns `true` if `who` can't move its balance of `asset_id`.
+	fn is_frozen(asset_id: AssetId, who: &AccountId) -> bool;
+}
+
+impl<AssetId, AccountId> AccountFreeze<AssetId, AccountId> for () {
+	fn is_frozen(_: AssetId, _: &AccountId) -> bool {
+		false
+	}
+}